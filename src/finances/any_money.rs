@@ -0,0 +1,207 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use num_bigint::BigUint;
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::prelude::*;
+
+use super::{
+    currencies::{Currency, CurrencyCode, IsoCurrency},
+    format_decimal, parse_decimal, Error, Money,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An amount of money whose currency is only known at runtime, like a row read from a database
+///
+/// Operations between different currencies fail with `Error::CurrencyMismatch`, and it can be
+/// converted into a `Money<C>` once the currency is checked.
+pub struct AnyMoney {
+    amount: BigUint,
+    currency: CurrencyCode,
+}
+
+impl AnyMoney {
+    pub fn new(amount: impl Into<BigUint>, currency: CurrencyCode) -> Self {
+        Self {
+            amount: amount.into(),
+            currency,
+        }
+    }
+
+    /// The amount, in minor units of the currency (like cents)
+    pub fn amount(&self) -> &BigUint {
+        &self.amount
+    }
+
+    pub fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    fn check_currency(&self, other: &Self) -> Result<(), Error> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(Error::CurrencyMismatch {
+                expected: self.currency,
+                found: other.currency,
+            })
+        }
+    }
+}
+
+impl PartialOrd for AnyMoney {
+    /// Amounts in different currencies can't be compared, so this returns `None` for them
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.check_currency(other).ok()?;
+        self.amount.partial_cmp(&other.amount)
+    }
+}
+
+impl Add for AnyMoney {
+    type Output = Result<Self, Error>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_currency(&rhs)?;
+
+        Ok(Self::new(self.amount + rhs.amount, self.currency))
+    }
+}
+
+impl Sub for AnyMoney {
+    type Output = Result<Self, Error>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_currency(&rhs)?;
+
+        if self.amount >= rhs.amount {
+            Ok(Self::new(self.amount - rhs.amount, self.currency))
+        } else {
+            Err(Error::NegativeAmount)
+        }
+    }
+}
+
+impl Display for AnyMoney {
+    /// Writes the ticker and the amount, like `Money` does
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.currency,
+            format_decimal(&self.amount, self.currency.decimals())
+        )
+    }
+}
+
+impl FromStr for AnyMoney {
+    type Err = crate::Error;
+
+    /// Parses a ticker followed by a decimal amount, like `JPY 1235`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fail = || crate::Error::FailedParsing(crate::Kind::Money, s.to_string());
+        let (currency, amount) = s.split_once(' ').ok_or_else(fail)?;
+        let currency: CurrencyCode = currency.parse().map_err(|_| fail())?;
+
+        parse_decimal(amount, currency.decimals())
+            .map(|amount| Self::new(amount, currency))
+            .ok_or_else(fail)
+    }
+}
+
+impl<C: IsoCurrency> From<Money<C>> for AnyMoney {
+    fn from(money: Money<C>) -> Self {
+        Self::new(money.0, money.1.code())
+    }
+}
+
+impl<C: IsoCurrency + Default> TryFrom<AnyMoney> for Money<C> {
+    type Error = Error;
+
+    fn try_from(money: AnyMoney) -> Result<Self, Self::Error> {
+        let currency = C::default();
+
+        if money.currency == currency.code() {
+            Ok(Money(money.amount, currency))
+        } else {
+            Err(Error::CurrencyMismatch {
+                expected: currency.code(),
+                found: money.currency,
+            })
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for AnyMoney {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u128>(), any::<CurrencyCode>())
+            .prop_map(|(amount, currency)| Self::new(amount, currency))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::finances::currencies::{EUR, USD};
+
+    proptest! {
+        #[test]
+        fn adds_same_currencies(a in any::<u64>(), b in any::<u64>(), c in any::<CurrencyCode>()) {
+            prop_assert_eq!(
+                AnyMoney::new(a, c) + AnyMoney::new(b, c),
+                Ok(AnyMoney::new(a as u128 + b as u128, c))
+            );
+        }
+
+        #[test]
+        fn rejects_mixed_currencies(a in any::<AnyMoney>(), b in any::<AnyMoney>()) {
+            prop_assume!(a.currency() != b.currency());
+
+            let mismatch = Err(Error::CurrencyMismatch {
+                expected: a.currency(),
+                found: b.currency(),
+            });
+
+            prop_assert_eq!(a.clone() + b.clone(), mismatch.clone());
+            prop_assert_eq!(a.clone() - b.clone(), mismatch);
+            prop_assert_eq!(a.partial_cmp(&b), None);
+        }
+
+        #[test]
+        fn only_allows_positive_amounts_when_subtracting(a in any::<u128>(), b in any::<u128>()) {
+            let result = AnyMoney::new(a, CurrencyCode::USD) - AnyMoney::new(b, CurrencyCode::USD);
+
+            prop_assert_eq!(result.is_ok(), a >= b);
+        }
+
+        #[test]
+        fn roundtrips_through_strings(a in any::<AnyMoney>()) {
+            prop_assert_eq!(a.to_string().parse(), Ok(a));
+        }
+
+        #[test]
+        fn converts_to_and_from_money(a in any::<Money<USD>>()) {
+            let any = AnyMoney::from(a.clone());
+
+            prop_assert_eq!(any.currency(), CurrencyCode::USD);
+            prop_assert_eq!(Money::<USD>::try_from(any.clone()), Ok(a));
+            prop_assert_eq!(
+                Money::<EUR>::try_from(any),
+                Err(Error::CurrencyMismatch {
+                    expected: CurrencyCode::EUR,
+                    found: CurrencyCode::USD,
+                })
+            );
+        }
+    }
+}
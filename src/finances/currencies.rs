@@ -1,14 +1,38 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
 pub trait Currency {
     fn ticker(&self) -> &'static str;
     fn name(&self) -> &'static str;
     fn decimals(&self) -> usize;
 }
 
+/// A currency from the catalogue on this module, which can be formatted for a locale and mixed
+/// with others as `AnyMoney`
+pub trait IsoCurrency: Currency {
+    /// The ISO 4217 numeric code, like `840` for USD, which cryptocurrencies don't have
+    fn numeric(&self) -> Option<u16>;
+
+    /// The symbol usually written next to amounts, like `$` or `€`
+    ///
+    /// Symbols are ambiguous (`$` is used by dozens of currencies), so they should only be used
+    /// where the currency is clear from context.
+    fn symbol(&self) -> &'static str;
+
+    fn code(&self) -> CurrencyCode;
+}
+
 macro_rules! currency {
-    ($t:tt,$name:expr,$decimals:expr) => {
+    ($t:tt,$name:expr,$decimals:expr,$numeric:expr,$symbol:expr) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-        #[cfg_attr(any(test, feature = "testing"), derive(proptest_derive::Arbitrary))]
+        #[doc = $name]
         pub struct $t;
 
         impl Currency for $t {
@@ -24,14 +48,380 @@ macro_rules! currency {
                 $decimals
             }
         }
+
+        impl IsoCurrency for $t {
+            fn numeric(&self) -> Option<u16> {
+                $numeric
+            }
+
+            fn symbol(&self) -> &'static str {
+                $symbol
+            }
+
+            fn code(&self) -> CurrencyCode {
+                CurrencyCode::$t
+            }
+        }
+    };
+}
+
+macro_rules! currencies {
+    ($($t:tt, $numeric:expr, $decimals:expr, $symbol:expr, $name:expr;)*) => {
+        $(currency!($t, $name, $decimals, $numeric, $symbol);)*
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        /// Any of the currencies on this module, for when the currency is only known at runtime
+        pub enum CurrencyCode {
+            $(
+                #[doc = $name]
+                $t,
+            )*
+        }
+
+        impl CurrencyCode {
+            /// Every currency, sorted by ticker, with the cryptocurrencies at the end
+            pub const VARIANTS: &'static [CurrencyCode] = &[$(CurrencyCode::$t),*];
+        }
+
+        impl Currency for CurrencyCode {
+            fn ticker(&self) -> &'static str {
+                match self {
+                    $(CurrencyCode::$t => stringify!($t),)*
+                }
+            }
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $(CurrencyCode::$t => $name,)*
+                }
+            }
+
+            fn decimals(&self) -> usize {
+                match self {
+                    $(CurrencyCode::$t => $decimals,)*
+                }
+            }
+        }
+
+        impl IsoCurrency for CurrencyCode {
+            fn numeric(&self) -> Option<u16> {
+                match self {
+                    $(CurrencyCode::$t => $numeric,)*
+                }
+            }
+
+            fn symbol(&self) -> &'static str {
+                match self {
+                    $(CurrencyCode::$t => $symbol,)*
+                }
+            }
+
+            fn code(&self) -> CurrencyCode {
+                *self
+            }
+        }
     };
 }
 
-// Fiat Currencies
-currency!(USD, "United States Dollar", 2);
-currency!(EUR, "Euro", 2);
-currency!(GBP, "Sterling Pound", 2);
+// Ticker, ISO 4217 numeric code, minor units, symbol, name
+currencies! {
+    // Fiat currencies and funds, as listed by ISO 4217
+    AED, Some(784), 2, "د.إ", "UAE Dirham";
+    AFN, Some(971), 2, "؋", "Afghani";
+    ALL, Some(8), 2, "L", "Lek";
+    AMD, Some(51), 2, "֏", "Armenian Dram";
+    AOA, Some(973), 2, "Kz", "Kwanza";
+    ARS, Some(32), 2, "$", "Argentine Peso";
+    AUD, Some(36), 2, "$", "Australian Dollar";
+    AWG, Some(533), 2, "ƒ", "Aruban Florin";
+    AZN, Some(944), 2, "₼", "Azerbaijan Manat";
+    BAM, Some(977), 2, "KM", "Convertible Mark";
+    BBD, Some(52), 2, "$", "Barbados Dollar";
+    BDT, Some(50), 2, "৳", "Taka";
+    BHD, Some(48), 3, ".د.ب", "Bahraini Dinar";
+    BIF, Some(108), 0, "FBu", "Burundi Franc";
+    BMD, Some(60), 2, "$", "Bermudian Dollar";
+    BND, Some(96), 2, "$", "Brunei Dollar";
+    BOB, Some(68), 2, "Bs", "Boliviano";
+    BOV, Some(984), 2, "BOV", "Mvdol";
+    BRL, Some(986), 2, "R$", "Brazilian Real";
+    BSD, Some(44), 2, "$", "Bahamian Dollar";
+    BTN, Some(64), 2, "Nu.", "Ngultrum";
+    BWP, Some(72), 2, "P", "Pula";
+    BYN, Some(933), 2, "Br", "Belarusian Ruble";
+    BZD, Some(84), 2, "$", "Belize Dollar";
+    CAD, Some(124), 2, "$", "Canadian Dollar";
+    CDF, Some(976), 2, "FC", "Congolese Franc";
+    CHE, Some(947), 2, "CHE", "WIR Euro";
+    CHF, Some(756), 2, "CHF", "Swiss Franc";
+    CHW, Some(948), 2, "CHW", "WIR Franc";
+    CLF, Some(990), 4, "UF", "Unidad de Fomento";
+    CLP, Some(152), 0, "$", "Chilean Peso";
+    CNY, Some(156), 2, "¥", "Yuan Renminbi";
+    COP, Some(170), 2, "$", "Colombian Peso";
+    COU, Some(970), 2, "COU", "Unidad de Valor Real";
+    CRC, Some(188), 2, "₡", "Costa Rican Colon";
+    CUP, Some(192), 2, "$", "Cuban Peso";
+    CVE, Some(132), 2, "$", "Cabo Verde Escudo";
+    CZK, Some(203), 2, "Kč", "Czech Koruna";
+    DJF, Some(262), 0, "Fdj", "Djibouti Franc";
+    DKK, Some(208), 2, "kr", "Danish Krone";
+    DOP, Some(214), 2, "$", "Dominican Peso";
+    DZD, Some(12), 2, "د.ج", "Algerian Dinar";
+    EGP, Some(818), 2, "£", "Egyptian Pound";
+    ERN, Some(232), 2, "Nfk", "Nakfa";
+    ETB, Some(230), 2, "Br", "Ethiopian Birr";
+    EUR, Some(978), 2, "€", "Euro";
+    FJD, Some(242), 2, "$", "Fiji Dollar";
+    FKP, Some(238), 2, "£", "Falkland Islands Pound";
+    GBP, Some(826), 2, "£", "Sterling Pound";
+    GEL, Some(981), 2, "₾", "Lari";
+    GHS, Some(936), 2, "₵", "Ghana Cedi";
+    GIP, Some(292), 2, "£", "Gibraltar Pound";
+    GMD, Some(270), 2, "D", "Dalasi";
+    GNF, Some(324), 0, "FG", "Guinean Franc";
+    GTQ, Some(320), 2, "Q", "Quetzal";
+    GYD, Some(328), 2, "$", "Guyana Dollar";
+    HKD, Some(344), 2, "$", "Hong Kong Dollar";
+    HNL, Some(340), 2, "L", "Lempira";
+    HTG, Some(332), 2, "G", "Gourde";
+    HUF, Some(348), 2, "Ft", "Forint";
+    IDR, Some(360), 2, "Rp", "Rupiah";
+    ILS, Some(376), 2, "₪", "New Israeli Sheqel";
+    INR, Some(356), 2, "₹", "Indian Rupee";
+    IQD, Some(368), 3, "ع.د", "Iraqi Dinar";
+    IRR, Some(364), 2, "﷼", "Iranian Rial";
+    ISK, Some(352), 0, "kr", "Iceland Krona";
+    JMD, Some(388), 2, "$", "Jamaican Dollar";
+    JOD, Some(400), 3, "د.ا", "Jordanian Dinar";
+    JPY, Some(392), 0, "¥", "Yen";
+    KES, Some(404), 2, "KSh", "Kenyan Shilling";
+    KGS, Some(417), 2, "сом", "Som";
+    KHR, Some(116), 2, "៛", "Riel";
+    KMF, Some(174), 0, "CF", "Comorian Franc";
+    KPW, Some(408), 2, "₩", "North Korean Won";
+    KRW, Some(410), 0, "₩", "Won";
+    KWD, Some(414), 3, "د.ك", "Kuwaiti Dinar";
+    KYD, Some(136), 2, "$", "Cayman Islands Dollar";
+    KZT, Some(398), 2, "₸", "Tenge";
+    LAK, Some(418), 2, "₭", "Lao Kip";
+    LBP, Some(422), 2, "ل.ل", "Lebanese Pound";
+    LKR, Some(144), 2, "Rs", "Sri Lanka Rupee";
+    LRD, Some(430), 2, "$", "Liberian Dollar";
+    LSL, Some(426), 2, "L", "Loti";
+    LYD, Some(434), 3, "ل.د", "Libyan Dinar";
+    MAD, Some(504), 2, "د.م.", "Moroccan Dirham";
+    MDL, Some(498), 2, "L", "Moldovan Leu";
+    MGA, Some(969), 2, "Ar", "Malagasy Ariary";
+    MKD, Some(807), 2, "ден", "Denar";
+    MMK, Some(104), 2, "K", "Kyat";
+    MNT, Some(496), 2, "₮", "Tugrik";
+    MOP, Some(446), 2, "MOP$", "Pataca";
+    MRU, Some(929), 2, "UM", "Ouguiya";
+    MUR, Some(480), 2, "₨", "Mauritius Rupee";
+    MVR, Some(462), 2, "Rf", "Rufiyaa";
+    MWK, Some(454), 2, "MK", "Malawi Kwacha";
+    MXN, Some(484), 2, "$", "Mexican Peso";
+    MXV, Some(979), 2, "MXV", "Mexican Unidad de Inversion (UDI)";
+    MYR, Some(458), 2, "RM", "Malaysian Ringgit";
+    MZN, Some(943), 2, "MT", "Mozambique Metical";
+    NAD, Some(516), 2, "$", "Namibia Dollar";
+    NGN, Some(566), 2, "₦", "Naira";
+    NIO, Some(558), 2, "C$", "Cordoba Oro";
+    NOK, Some(578), 2, "kr", "Norwegian Krone";
+    NPR, Some(524), 2, "₨", "Nepalese Rupee";
+    NZD, Some(554), 2, "$", "New Zealand Dollar";
+    OMR, Some(512), 3, "ر.ع.", "Rial Omani";
+    PAB, Some(590), 2, "B/.", "Balboa";
+    PEN, Some(604), 2, "S/", "Sol";
+    PGK, Some(598), 2, "K", "Kina";
+    PHP, Some(608), 2, "₱", "Philippine Peso";
+    PKR, Some(586), 2, "₨", "Pakistan Rupee";
+    PLN, Some(985), 2, "zł", "Zloty";
+    PYG, Some(600), 0, "₲", "Guarani";
+    QAR, Some(634), 2, "ر.ق", "Qatari Rial";
+    RON, Some(946), 2, "lei", "Romanian Leu";
+    RSD, Some(941), 2, "дин.", "Serbian Dinar";
+    RUB, Some(643), 2, "₽", "Russian Ruble";
+    RWF, Some(646), 0, "FRw", "Rwanda Franc";
+    SAR, Some(682), 2, "ر.س", "Saudi Riyal";
+    SBD, Some(90), 2, "$", "Solomon Islands Dollar";
+    SCR, Some(690), 2, "₨", "Seychelles Rupee";
+    SDG, Some(938), 2, "ج.س.", "Sudanese Pound";
+    SEK, Some(752), 2, "kr", "Swedish Krona";
+    SGD, Some(702), 2, "$", "Singapore Dollar";
+    SHP, Some(654), 2, "£", "Saint Helena Pound";
+    SLE, Some(925), 2, "Le", "Leone";
+    SOS, Some(706), 2, "Sh", "Somali Shilling";
+    SRD, Some(968), 2, "$", "Surinam Dollar";
+    SSP, Some(728), 2, "£", "South Sudanese Pound";
+    STN, Some(930), 2, "Db", "Dobra";
+    SVC, Some(222), 2, "₡", "El Salvador Colon";
+    SYP, Some(760), 2, "£", "Syrian Pound";
+    SZL, Some(748), 2, "L", "Lilangeni";
+    THB, Some(764), 2, "฿", "Baht";
+    TJS, Some(972), 2, "SM", "Somoni";
+    TMT, Some(934), 2, "m", "Turkmenistan New Manat";
+    TND, Some(788), 3, "د.ت", "Tunisian Dinar";
+    TOP, Some(776), 2, "T$", "Pa'anga";
+    TRY, Some(949), 2, "₺", "Turkish Lira";
+    TTD, Some(780), 2, "$", "Trinidad and Tobago Dollar";
+    TWD, Some(901), 2, "$", "New Taiwan Dollar";
+    TZS, Some(834), 2, "TSh", "Tanzanian Shilling";
+    UAH, Some(980), 2, "₴", "Hryvnia";
+    UGX, Some(800), 0, "USh", "Uganda Shilling";
+    USD, Some(840), 2, "$", "United States Dollar";
+    USN, Some(997), 2, "$", "US Dollar (Next day)";
+    UYI, Some(940), 0, "UYI", "Uruguay Peso en Unidades Indexadas (UI)";
+    UYU, Some(858), 2, "$", "Peso Uruguayo";
+    UYW, Some(927), 4, "UYW", "Unidad Previsional";
+    UZS, Some(860), 2, "soʻm", "Uzbekistan Sum";
+    VED, Some(926), 2, "Bs.D", "Bolívar Soberano (digital)";
+    VES, Some(928), 2, "Bs.S", "Bolívar Soberano";
+    VND, Some(704), 0, "₫", "Dong";
+    VUV, Some(548), 0, "VT", "Vatu";
+    WST, Some(882), 2, "T", "Tala";
+    XAF, Some(950), 0, "FCFA", "CFA Franc BEAC";
+    XCD, Some(951), 2, "$", "East Caribbean Dollar";
+    XCG, Some(532), 2, "Cg", "Caribbean Guilder";
+    XOF, Some(952), 0, "CFA", "CFA Franc BCEAO";
+    XPF, Some(953), 0, "F", "CFP Franc";
+    YER, Some(886), 2, "﷼", "Yemeni Rial";
+    ZAR, Some(710), 2, "R", "Rand";
+    ZMW, Some(967), 2, "ZK", "Zambian Kwacha";
+    ZWG, Some(924), 2, "ZiG", "Zimbabwe Gold";
+
+    // Cryptocurrencies
+    BTC, None, 8, "₿", "Bitcoin";
+    ETH, None, 18, "Ξ", "Ethereum";
+}
+
+impl FromStr for CurrencyCode {
+    type Err = crate::Error;
+
+    /// Parses either the ticker or the numeric code, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let found = if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            let numeric = s.parse::<u16>().ok();
+            CurrencyCode::VARIANTS
+                .iter()
+                .find(|c| numeric.is_some() && c.numeric() == numeric)
+        } else {
+            CurrencyCode::VARIANTS
+                .iter()
+                .find(|c| c.ticker().eq_ignore_ascii_case(s))
+        };
+
+        found
+            .copied()
+            .ok_or_else(|| crate::Error::FailedParsing(crate::Kind::Currency, s.to_string()))
+    }
+}
+
+impl Display for CurrencyCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ticker())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CurrencyCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.ticker())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for CurrencyCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for CurrencyCode {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
 
-// Cryptocurrencies
-currency!(BTC, "Bitcoin", 8);
-currency!(ETH, "Ethereum", 18);
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(CurrencyCode::VARIANTS).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn has_correct_minor_units() {
+        assert_eq!(JPY.decimals(), 0);
+        assert_eq!(KWD.decimals(), 3);
+        assert_eq!(CLF.decimals(), 4);
+        assert_eq!(USD.decimals(), 2);
+        assert_eq!(CurrencyCode::BHD.decimals(), 3);
+    }
+
+    #[test]
+    fn matches_static_currencies() {
+        assert_eq!(EUR.code(), CurrencyCode::EUR);
+        assert_eq!(EUR.code().name(), EUR.name());
+        assert_eq!(BTC.code().numeric(), BTC.numeric());
+        assert_eq!(JPY.code().symbol(), JPY.symbol());
+    }
+
+    #[test]
+    fn keeps_existing_names() {
+        assert_eq!(USD.name(), "United States Dollar");
+        assert_eq!(GBP.name(), "Sterling Pound");
+        assert_eq!(CurrencyCode::EUR.name(), "Euro");
+    }
+
+    #[test]
+    fn parses_tickers_and_numeric_codes() {
+        assert_eq!("usd".parse(), Ok(CurrencyCode::USD));
+        assert_eq!("978".parse(), Ok(CurrencyCode::EUR));
+        assert_eq!("008".parse(), Ok(CurrencyCode::ALL));
+        assert_eq!(
+            "XYZ".parse::<CurrencyCode>(),
+            Err(crate::Error::FailedParsing(
+                crate::Kind::Currency,
+                "XYZ".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn codes_are_unique() {
+        for (i, a) in CurrencyCode::VARIANTS.iter().enumerate() {
+            for b in &CurrencyCode::VARIANTS[i + 1..] {
+                assert_ne!(a.ticker(), b.ticker());
+                assert!(
+                    a.numeric().is_none() || a.numeric() != b.numeric(),
+                    "{a} and {b}"
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrips_through_strings(a in any::<CurrencyCode>()) {
+            prop_assert_eq!(a.to_string().parse(), Ok(a));
+
+            if let Some(numeric) = a.numeric() {
+                prop_assert_eq!(format!("{:03}", numeric).parse(), Ok(a));
+            }
+        }
+
+    }
+}
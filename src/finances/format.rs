@@ -0,0 +1,433 @@
+use num_bigint::BigUint;
+
+use super::{
+    currencies::{Currency, CurrencyCode, IsoCurrency},
+    format_decimal, parse_decimal, AnyMoney, Money, SignedMoney,
+};
+use crate::lang::Locale;
+
+/// The space between amounts and symbols, which must not be broken across lines
+const NBSP: &str = "\u{a0}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SymbolPosition {
+    /// Like `$1.00`
+    Prefix,
+    /// Like `R$ 1,00`
+    PrefixSpaced,
+    /// Like `1,00 €`
+    SuffixSpaced,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NegativeStyle {
+    /// Like `-$1.00` or `-1,00 €`
+    Leading,
+    /// Like `€ -1,00`
+    AfterSymbol,
+}
+
+/// How amounts are written on a locale, a small subset of the CLDR number formats
+struct MoneyFormat {
+    group: &'static str,
+    decimal: &'static str,
+    /// The size of every group but the last, which is always 3 (so 2 gives `12,34,567.00`)
+    secondary_grouping: usize,
+    symbol: SymbolPosition,
+    negative: NegativeStyle,
+    /// The currency of the region, which is the only one written with an ambiguous symbol
+    local_currency: CurrencyCode,
+}
+
+macro_rules! money_formats {
+    ($($tag:expr, $group:expr, $decimal:expr, $secondary:expr, $symbol:ident, $negative:ident, $currency:ident;)*) => {
+        /// Every known format, by language tag
+        const FORMATS: &[(&str, MoneyFormat)] = &[
+            $(($tag, MoneyFormat {
+                group: $group,
+                decimal: $decimal,
+                secondary_grouping: $secondary,
+                symbol: SymbolPosition::$symbol,
+                negative: NegativeStyle::$negative,
+                local_currency: CurrencyCode::$currency,
+            }),)*
+        ];
+    };
+}
+
+// Language tag, group separator, decimal separator, secondary grouping, symbol position,
+// negative style, local currency
+money_formats! {
+    "de", ".", ",", 3, SuffixSpaced, Leading, EUR;
+    "de-CH", "’", ".", 3, PrefixSpaced, Leading, CHF;
+    "en", ",", ".", 3, Prefix, Leading, USD;
+    "en-AU", ",", ".", 3, Prefix, Leading, AUD;
+    "en-CA", ",", ".", 3, Prefix, Leading, CAD;
+    "en-GB", ",", ".", 3, Prefix, Leading, GBP;
+    "en-IN", ",", ".", 2, Prefix, Leading, INR;
+    "es", ".", ",", 3, SuffixSpaced, Leading, EUR;
+    "es-MX", ",", ".", 3, Prefix, Leading, MXN;
+    "fr", "\u{202f}", ",", 3, SuffixSpaced, Leading, EUR;
+    "fr-CH", "\u{202f}", ",", 3, SuffixSpaced, Leading, CHF;
+    "hi", ",", ".", 2, Prefix, Leading, INR;
+    "it", ".", ",", 3, SuffixSpaced, Leading, EUR;
+    "ja", ",", ".", 3, Prefix, Leading, JPY;
+    "ko", ",", ".", 3, Prefix, Leading, KRW;
+    "nl", ".", ",", 3, PrefixSpaced, AfterSymbol, EUR;
+    "pl", "\u{a0}", ",", 3, SuffixSpaced, Leading, PLN;
+    "pt", ".", ",", 3, PrefixSpaced, Leading, BRL;
+    "pt-PT", "\u{a0}", ",", 3, SuffixSpaced, Leading, EUR;
+    "ru", "\u{a0}", ",", 3, SuffixSpaced, Leading, RUB;
+    "sv", "\u{a0}", ",", 3, SuffixSpaced, Leading, SEK;
+    "tr", ".", ",", 3, Prefix, Leading, TRY;
+    "zh", ",", ".", 3, Prefix, Leading, CNY;
+}
+
+/// Finds the format for the most specific of `locale` and its fallbacks, defaulting to English
+fn format_for(locale: &Locale) -> &'static MoneyFormat {
+    locale
+        .fallbacks()
+        .find_map(|l| {
+            let tag = l.to_string();
+            FORMATS.iter().find(|(t, _)| *t == tag)
+        })
+        .or_else(|| FORMATS.iter().find(|(t, _)| *t == "en"))
+        .map(|(_, format)| format)
+        .expect("Missing the default format")
+}
+
+/// Whether the symbol of `currency` is used by other currencies too, like `$` or `kr`
+fn is_ambiguous(currency: CurrencyCode) -> bool {
+    CurrencyCode::VARIANTS
+        .iter()
+        .any(|c| *c != currency && c.symbol() == currency.symbol())
+}
+
+impl MoneyFormat {
+    /// The symbol to write for `currency`, which is its ticker when the symbol is ambiguous, so
+    /// Canadian dollars are written as `CAD 1.00` on `en-US` but as `$1.00` on `en-CA`
+    fn symbol(&self, currency: CurrencyCode) -> &'static str {
+        if currency == self.local_currency || !is_ambiguous(currency) {
+            currency.symbol()
+        } else {
+            currency.ticker()
+        }
+    }
+
+    fn group(&self, digits: &str) -> String {
+        let mut groups = vec![];
+        let (mut rest, last) = digits.split_at(digits.len().saturating_sub(3));
+
+        while !rest.is_empty() {
+            let (head, group) = rest.split_at(rest.len().saturating_sub(self.secondary_grouping));
+            groups.push(group);
+            rest = head;
+        }
+
+        groups.reverse();
+        groups.push(last);
+        groups.join(self.group)
+    }
+
+    fn format(&self, amount: &BigUint, negative: bool, currency: CurrencyCode) -> String {
+        let decimal = format_decimal(amount, currency.decimals());
+        let (major, minor) = decimal.split_once('.').unwrap_or((&decimal, ""));
+
+        let mut number = self.group(major);
+
+        if !minor.is_empty() {
+            number = format!("{number}{}{minor}", self.decimal);
+        }
+
+        let sign = if negative { "-" } else { "" };
+        let symbol = self.symbol(currency);
+
+        // Letters are always separated from the number, so it's `CAD 1.00` and not `CAD1.00`
+        let position = match self.symbol {
+            SymbolPosition::Prefix if symbol.ends_with(char::is_alphabetic) => {
+                SymbolPosition::PrefixSpaced
+            }
+            position => position,
+        };
+
+        match (position, self.negative) {
+            (SymbolPosition::Prefix, NegativeStyle::Leading) => format!("{sign}{symbol}{number}"),
+            (SymbolPosition::Prefix, NegativeStyle::AfterSymbol) => {
+                format!("{symbol}{sign}{number}")
+            }
+            (SymbolPosition::PrefixSpaced, NegativeStyle::Leading) => {
+                format!("{sign}{symbol}{NBSP}{number}")
+            }
+            (SymbolPosition::PrefixSpaced, NegativeStyle::AfterSymbol) => {
+                format!("{symbol}{NBSP}{sign}{number}")
+            }
+            (SymbolPosition::SuffixSpaced, _) => format!("{sign}{number}{NBSP}{symbol}"),
+        }
+    }
+
+    /// Reads an amount written by a person, returning it with whether it was negative
+    ///
+    /// The symbol or ticker (before or after the amount) and the group separators are optional,
+    /// and whitespace may be used to group digits too, but groups must have the sizes used on the
+    /// locale, and the decimal separator must be the one used on the locale.
+    fn parse(&self, s: &str, currency: CurrencyCode) -> Option<(BigUint, bool)> {
+        let s = s.trim();
+        let (leading, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest.trim()),
+            None => (false, s),
+        };
+        let mut s = s;
+
+        for marker in [currency.symbol(), currency.ticker()] {
+            if let Some(stripped) = s.strip_prefix(marker).or_else(|| s.strip_suffix(marker)) {
+                s = stripped.trim();
+                break;
+            }
+        }
+
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) if !leading => (true, rest.trim()),
+            Some(_) => return None,
+            None => (leading, s),
+        };
+
+        let decimal = match s.split_once(self.decimal) {
+            Some((major, minor)) => format!("{}.{minor}", self.ungroup(major)?),
+            None => self.ungroup(s)?,
+        };
+
+        parse_decimal(&decimal, currency.decimals()).map(|amount| (amount, negative))
+    }
+
+    /// Removes the group separators from the whole part of an amount, or returns `None` if they
+    /// aren't where the locale puts them, so `12.5` is never read as `125` on `de`
+    fn ungroup(&self, major: &str) -> Option<String> {
+        let groups: Vec<&str> = major
+            .split(|c: char| c.is_whitespace() || self.group.contains(c))
+            .collect();
+
+        let (first, rest) = groups.split_first()?;
+        let Some((last, middle)) = rest.split_last() else {
+            return Some(first.to_string());
+        };
+
+        let is_valid = (1..=self.secondary_grouping).contains(&first.len())
+            && middle.iter().all(|g| g.len() == self.secondary_grouping)
+            && last.len() == 3;
+
+        is_valid.then(|| groups.concat())
+    }
+}
+
+pub(crate) fn format_money(
+    amount: &BigUint,
+    negative: bool,
+    currency: CurrencyCode,
+    locale: &Locale,
+) -> String {
+    format_for(locale).format(amount, negative, currency)
+}
+
+pub(crate) fn parse_money(
+    s: &str,
+    currency: CurrencyCode,
+    locale: &Locale,
+) -> Result<(BigUint, bool), crate::Error> {
+    format_for(locale)
+        .parse(s, currency)
+        .ok_or_else(|| crate::Error::FailedParsing(crate::Kind::Money, s.to_string()))
+}
+
+impl<C: IsoCurrency> Money<C> {
+    /// Formats the amount as usually written on `locale`, like `$1,234.56` on `en-US` or
+    /// `1.234,56 €` on `de-DE`
+    pub fn format(&self, locale: &Locale) -> String {
+        format_money(&self.0, false, self.1.code(), locale)
+    }
+}
+
+impl<C: IsoCurrency + Default> Money<C> {
+    /// Parses an amount typed by a person on `locale`, accepting it with or without symbol and
+    /// group separators, like `1.234,56`, `1234,56 €` or `EUR 1234,56` on `de-DE`
+    ///
+    /// As with `FromStr`, amounts with more decimals than the currency allows are rejected.
+    pub fn parse_localized(s: &str, locale: &Locale) -> Result<Self, crate::Error> {
+        let currency = C::default();
+
+        match parse_money(s, currency.code(), locale)? {
+            (amount, false) => Ok(Self(amount, currency)),
+            (_, true) => Err(crate::Error::FailedParsing(
+                crate::Kind::Money,
+                s.to_string(),
+            )),
+        }
+    }
+}
+
+impl<C: IsoCurrency + Clone> SignedMoney<C> {
+    /// Formats the amount as usually written on `locale`, with the sign where the locale puts it,
+    /// like `-$1.00` on `en-US` or `€ -1,00` on `nl`
+    pub fn format(&self, locale: &Locale) -> String {
+        let money = self.abs();
+
+        format_money(&money.0, self.is_debit(), money.1.code(), locale)
+    }
+}
+
+impl<C: IsoCurrency + Default> SignedMoney<C> {
+    /// Parses an amount typed by a person on `locale`, like `Money::parse_localized` but
+    /// accepting a leading `-`
+    pub fn parse_localized(s: &str, locale: &Locale) -> Result<Self, crate::Error> {
+        let currency = C::default();
+        let (amount, negative) = parse_money(s, currency.code(), locale)?;
+        let amount = Self::from(Money(amount, currency));
+
+        Ok(if negative { -amount } else { amount })
+    }
+}
+
+impl AnyMoney {
+    /// Formats the amount as usually written on `locale`, like `Money::format`
+    pub fn format(&self, locale: &Locale) -> String {
+        format_money(self.amount(), false, self.currency(), locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::finances::currencies::{CHF, EUR, INR, JPY, USD};
+
+    fn locale(s: &str) -> Locale {
+        s.parse().expect("Failed parsing")
+    }
+
+    #[test]
+    fn formats_per_locale() {
+        let usd = Money::<USD>::new(123456u32);
+        let eur = Money::<EUR>::new(123456u32);
+
+        assert_eq!(usd.format(&locale("en-US")), "$1,234.56");
+        assert_eq!(eur.format(&locale("de-DE")), "1.234,56\u{a0}€");
+        assert_eq!(eur.format(&locale("nl")), "€\u{a0}1.234,56");
+        assert_eq!(eur.format(&locale("fr-FR")), "1\u{202f}234,56\u{a0}€");
+        assert_eq!(
+            Money::<JPY>::new(1235u32).format(&locale("ja-JP")),
+            "¥1,235"
+        );
+        assert_eq!(
+            Money::<INR>::new(123456789u32).format(&locale("en-IN")),
+            "₹12,34,567.89"
+        );
+        assert_eq!(
+            Money::<CHF>::new(123456u32).format(&locale("de-CH")),
+            "CHF\u{a0}1’234.56"
+        );
+        assert_eq!(Money::<USD>::new(5u32).format(&locale("xx")), "$0.05");
+    }
+
+    #[test]
+    fn writes_ambiguous_symbols_as_tickers() {
+        let cad = AnyMoney::new(100u32, CurrencyCode::CAD);
+
+        assert_eq!(cad.format(&locale("en-US")), "CAD\u{a0}1.00");
+        assert_eq!(cad.format(&locale("en-CA")), "$1.00");
+    }
+
+    #[test]
+    fn writes_negative_amounts() {
+        let amount = BigUint::from(100u32);
+
+        assert_eq!(
+            format_money(&amount, true, CurrencyCode::USD, &locale("en")),
+            "-$1.00"
+        );
+        assert_eq!(
+            format_money(&amount, true, CurrencyCode::EUR, &locale("nl")),
+            "€\u{a0}-1,00"
+        );
+        assert_eq!(
+            format_money(&amount, true, CurrencyCode::EUR, &locale("de")),
+            "-1,00\u{a0}€"
+        );
+
+        let cent = -SignedMoney::<EUR>::new(1);
+        let ja = locale("ja");
+
+        assert_eq!(
+            SignedMoney::parse_localized(&cent.format(&ja), &ja),
+            Ok(cent)
+        );
+    }
+
+    #[test]
+    fn parses_leniently() {
+        let de = locale("de-DE");
+        let expected = Ok(Money::<EUR>::new(123456u32));
+
+        for s in [
+            "1.234,56 €",
+            "1234,56",
+            "€1234,56",
+            "EUR 1.234,56",
+            " 1 234,56 ",
+        ] {
+            assert_eq!(Money::parse_localized(s, &de), expected, "{s}");
+        }
+
+        for s in [
+            "1.234,567",
+            "1,234.56",
+            "-1,00",
+            "$1,00",
+            "",
+            "12.5",
+            "1.2.3,00",
+            "1.23,00",
+            "1..234,00",
+            ".234,00",
+            "1.234.,00",
+        ] {
+            assert!(Money::<EUR>::parse_localized(s, &de).is_err(), "{s}");
+        }
+
+        assert_eq!(
+            SignedMoney::parse_localized("-1.234,56 €", &de),
+            Ok(-SignedMoney::<EUR>::new(123456))
+        );
+        assert!(SignedMoney::<EUR>::parse_localized("--1,00", &de).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parses_formatted_amounts(a in any::<Money<USD>>(), l in any::<Locale>()) {
+            prop_assert_eq!(Money::parse_localized(&a.format(&l), &l), Ok(a));
+        }
+
+        #[test]
+        fn rejects_misplaced_group_separators(
+            a in 1000..u64::MAX,
+            l in any::<Locale>(),
+            i in any::<prop::sample::Index>(),
+        ) {
+            let format = format_for(&l);
+            let s = Money::<USD>::new(a).format(&l);
+            let separators: Vec<usize> = s.match_indices(format.group).map(|(i, _)| i).collect();
+            let at = *i.get(&separators);
+
+            // Moving a separator one digit to the left always leaves a group too long
+            let digit = s[..at].chars().next_back().expect("Missing a digit");
+            let mut moved = s.clone();
+            moved.replace_range(at - digit.len_utf8()..at + format.group.len(), &format!("{}{digit}", format.group));
+
+            prop_assert!(Money::<USD>::parse_localized(&moved, &l).is_err(), "{} from {}", moved, s);
+        }
+
+        #[test]
+        fn parses_formatted_signed_amounts(a in any::<SignedMoney<EUR>>(), l in any::<Locale>()) {
+            prop_assert_eq!(SignedMoney::parse_localized(&a.format(&l), &l), Ok(a));
+        }
+    }
+}
@@ -1,15 +1,25 @@
 use std::{
-    fmt::Debug,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub},
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Sub},
+    str::FromStr,
 };
 
 use num_bigint::BigUint;
+pub use num_rational::Ratio;
 use thiserror::Error;
 
 use crate::testing::NumberExt;
 
+pub mod any_money;
 pub mod currencies;
-use currencies::Currency;
+#[cfg(feature = "lang")]
+mod format;
+pub mod rounding;
+pub mod signed_money;
+pub use any_money::AnyMoney;
+use currencies::{Currency, CurrencyCode, IsoCurrency};
+pub use rounding::RoundingMode;
+pub use signed_money::SignedMoney;
 
 #[cfg(any(test, feature = "testing"))]
 use proptest::prelude::*;
@@ -18,6 +28,15 @@ use proptest::prelude::*;
 pub enum Error {
     #[error("Negative amount is not allowed")]
     NegativeAmount,
+
+    #[error("Division by zero")]
+    DivisionByZero,
+
+    #[error("Currency mismatch: expected `{expected}`, found `{found}`")]
+    CurrencyMismatch {
+        expected: CurrencyCode,
+        found: CurrencyCode,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +62,94 @@ impl<C: Default + Currency> Money<C> {
     pub fn new(amount: impl Into<BigUint>) -> Self {
         Self(amount.into(), C::default())
     }
+
+    /// Creates an amount from minor units of the currency, like cents, which is the same as `new`
+    pub fn from_minor(amount: impl Into<BigUint>) -> Self {
+        Self::new(amount)
+    }
+
+    /// Creates an amount from whole units of the currency, so `Money::<USD>::from_major(12)` is
+    /// the same as `Money::<USD>::from_minor(1200)`
+    pub fn from_major(amount: impl Into<BigUint>) -> Self {
+        let currency = C::default();
+
+        Self(
+            amount.into() * minor_units_per_major(currency.decimals()),
+            currency,
+        )
+    }
+}
+
+impl<C> Money<C> {
+    /// The amount, in minor units of the currency
+    pub fn amount(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+fn minor_units_per_major(decimals: usize) -> BigUint {
+    BigUint::from(10u8).pow(decimals as u32)
+}
+
+/// Writes an amount of minor units as a decimal, like `12.34` for 1234 with two decimals
+pub(crate) fn format_decimal(amount: &BigUint, decimals: usize) -> String {
+    let digits = format!("{:0>width$}", amount.to_string(), width = decimals + 1);
+    let (major, minor) = digits.split_at(digits.len() - decimals);
+
+    if minor.is_empty() {
+        major.to_string()
+    } else {
+        format!("{major}.{minor}")
+    }
+}
+
+/// Reads a decimal as an amount of minor units, or `None` if it has more than `decimals`
+/// fractional digits or anything other than digits and a single `.`
+pub(crate) fn parse_decimal(s: &str, decimals: usize) -> Option<BigUint> {
+    let (major, minor) = s.split_once('.').unwrap_or((s, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    if major.is_empty()
+        || !is_digits(major)
+        || !is_digits(minor)
+        || minor.len() > decimals
+        || (s.contains('.') && minor.is_empty())
+    {
+        return None;
+    }
+
+    format!("{major}{minor:0<decimals$}").parse().ok()
+}
+
+impl<C: Currency> Display for Money<C> {
+    /// Writes the ticker and the amount with all the decimals of the currency, like `USD 12.30`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.1.ticker(),
+            format_decimal(&self.0, self.1.decimals())
+        )
+    }
+}
+
+impl<C: Currency + Default> FromStr for Money<C> {
+    type Err = crate::Error;
+
+    /// Parses a decimal amount, optionally preceded by the ticker, like `12.3` or `USD 12.30`
+    ///
+    /// Amounts with more decimals than the currency allows are rejected instead of rounded.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let currency = C::default();
+        let amount = match s.split_once(' ') {
+            Some((ticker, amount)) if ticker.eq_ignore_ascii_case(currency.ticker()) => amount,
+            _ => s,
+        };
+
+        parse_decimal(amount, currency.decimals())
+            .map(|amount| Self(amount, currency))
+            .ok_or_else(|| crate::Error::FailedParsing(crate::Kind::Money, s.to_string()))
+    }
 }
 
 impl<C> PartialOrd for Money<C> {
@@ -83,31 +190,185 @@ impl<C: Default> Sub for Money<C> {
     }
 }
 
-impl<C: Default> Mul for Money<C> {
-    type Output = Self;
+macro_rules! scalars {
+    ($($t:ty),*) => {
+        $(
+            impl<C> Mul<$t> for Money<C> {
+                type Output = Self;
+
+                fn mul(self, rhs: $t) -> Self::Output {
+                    Self(self.0 * rhs, self.1)
+                }
+            }
+
+            impl<C> MulAssign<$t> for Money<C> {
+                fn mul_assign(&mut self, rhs: $t) {
+                    self.0 *= rhs;
+                }
+            }
+        )*
+    };
+}
+
+// Money can only be multiplied by plain numbers, as multiplying two amounts has no meaning
+scalars!(u8, u16, u32, u64, u128, usize, BigUint);
+
+impl<C: Clone> Money<C> {
+    /// Multiplies the amount by `ratio`, like `3/4`, returning the result rounded toward zero
+    /// along with the fraction of a minor unit that was dropped
+    pub fn mul_ratio(&self, ratio: &Ratio<BigUint>) -> (Self, Ratio<BigUint>) {
+        let product = Ratio::from_integer(self.0.clone()) * ratio;
+        let whole = product.to_integer();
+        let dropped = product - Ratio::from_integer(whole.clone());
+
+        (Self(whole, self.1.clone()), dropped)
+    }
+}
+
+impl<C: Clone> Money<C> {
+    /// Multiplies the amount by `ratio`, rounding the result with `mode`
+    pub fn mul_rounded(&self, ratio: &Ratio<BigUint>, mode: RoundingMode) -> Self {
+        let product = Ratio::from_integer(self.0.clone()) * ratio;
+
+        Self(mode.round_unsigned(&product), self.1.clone())
+    }
+
+    /// Divides the amount into `divisor` equal parts, rounding a part with `mode`
+    pub fn div_rounded(
+        &self,
+        divisor: impl Into<BigUint>,
+        mode: RoundingMode,
+    ) -> Result<Self, Error> {
+        let divisor = divisor.into();
+
+        if NumberExt::is_zero(&divisor) {
+            return Err(Error::DivisionByZero);
+        }
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self(self.0 * rhs.0, C::default())
+        Ok(self.mul_rounded(&Ratio::new(BigUint::from(1u8), divisor), mode))
+    }
+
+    /// Divides the amount into `divisor` equal parts, rounding a part with `mode`, along with the
+    /// minor units left over, so `part * divisor + remainder` is the original amount
+    ///
+    /// The remainder is negative when the part was rounded up.
+    pub fn div_rem(
+        &self,
+        divisor: impl Into<BigUint>,
+        mode: RoundingMode,
+    ) -> Result<(Self, SignedMoney<C>), Error> {
+        let divisor = divisor.into();
+        let part = self.div_rounded(divisor.clone(), mode)?;
+        let remainder = SignedMoney::from(self.clone()) - Self(&part.0 * divisor, self.1.clone());
+
+        Ok((part, remainder))
+    }
+
+    /// Takes `percent` percent of the amount, like a tax or a discount, rounding it with `mode`
+    pub fn percent(&self, percent: &Ratio<BigUint>, mode: RoundingMode) -> Self {
+        self.mul_rounded(&(percent / BigUint::from(100u8)), mode)
     }
 }
 
-impl<C> MulAssign for Money<C> {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.0 *= rhs.0;
+impl<C: Clone> Money<C> {
+    /// Divides the amount in parts proportional to `ratios` without losing any minor unit
+    ///
+    /// Every part is rounded down, and the units left over are handed out one by one to the
+    /// parts that lost the most by rounding (the largest remainder method), with ties going to
+    /// the earliest part, so `$100` allocated `[1, 1, 1]` is `$33.34`, `$33.33` and `$33.33`.
+    pub fn allocate<R: Into<BigUint>>(
+        &self,
+        ratios: impl IntoIterator<Item = R>,
+    ) -> Result<Vec<Self>, Error> {
+        let ratios: Vec<BigUint> = ratios.into_iter().map(Into::into).collect();
+        let total: BigUint = ratios.iter().sum();
+
+        if NumberExt::is_zero(&total) {
+            return Err(Error::DivisionByZero);
+        }
+
+        let mut parts: Vec<(BigUint, BigUint)> = ratios
+            .iter()
+            .map(|ratio| {
+                let share = &self.0 * ratio;
+                (&share / &total, share % &total)
+            })
+            .collect();
+
+        let allocated: BigUint = parts.iter().map(|(part, _)| part).sum();
+        let mut leftover = &self.0 - allocated;
+
+        let mut order: Vec<usize> = (0..parts.len()).collect();
+        order.sort_by(|a, b| parts[*b].1.cmp(&parts[*a].1));
+
+        for i in order {
+            if NumberExt::is_zero(&leftover) {
+                break;
+            }
+
+            parts[i].0 += 1u8;
+            leftover -= 1u8;
+        }
+
+        Ok(parts
+            .into_iter()
+            .map(|(part, _)| Self(part, self.1.clone()))
+            .collect())
+    }
+
+    /// Divides the amount in `n` parts that differ by at most one minor unit, with the larger
+    /// ones first, so `$100` split in 3 is `$33.34`, `$33.33` and `$33.33`
+    pub fn split(&self, n: usize) -> Result<Vec<Self>, Error> {
+        self.allocate(vec![1u8; n])
     }
 }
 
-impl<C: Default> Div for Money<C> {
-    type Output = Self;
+impl<C: Currency> Money<C> {
+    /// Rounds the amount to `decimals` decimal places using `mode`, keeping it in minor units,
+    /// like rounding to whole units or to the coins in circulation
+    ///
+    /// The amount is unchanged if the currency has no more than `decimals` decimals.
+    pub fn rescale(&self, decimals: usize, mode: RoundingMode) -> Self
+    where
+        C: Clone,
+    {
+        let scale = minor_units_per_major(self.1.decimals().saturating_sub(decimals));
+        let units = mode.round_unsigned(&Ratio::new(self.0.clone(), scale.clone()));
+
+        Self(units * scale, self.1.clone())
+    }
 
-    fn div(self, rhs: Self) -> Self::Output {
-        Self(self.0 / rhs.0, C::default())
+    /// Converts the amount into another currency at `rate` units of `D` per unit of `C`,
+    /// rounding it to the minor units of `D` with `mode`
+    pub fn convert<D: Currency + Default>(
+        &self,
+        rate: &Ratio<BigUint>,
+        mode: RoundingMode,
+    ) -> Money<D> {
+        let target = D::default();
+        let amount = Ratio::new(self.0.clone(), minor_units_per_major(self.1.decimals()))
+            * rate
+            * minor_units_per_major(target.decimals());
+
+        Money(mode.round_unsigned(&amount), target)
     }
 }
 
-impl<C> DivAssign for Money<C> {
-    fn div_assign(&mut self, rhs: Self) {
-        self.0 /= rhs.0;
+impl<C: IsoCurrency> Div for Money<C> {
+    type Output = Result<Ratio<BigUint>, Error>;
+
+    /// Divides two amounts of the same currency, giving how many times `rhs` fits in `self`
+    fn div(self, rhs: Self) -> Self::Output {
+        if self.1.code() != rhs.1.code() {
+            Err(Error::CurrencyMismatch {
+                expected: self.1.code(),
+                found: rhs.1.code(),
+            })
+        } else if NumberExt::is_zero(&rhs.0) {
+            Err(Error::DivisionByZero)
+        } else {
+            Ok(Ratio::new(self.0, rhs.0))
+        }
     }
 }
 
@@ -132,10 +393,107 @@ mod tests {
 
     use crate::testing::*;
 
-    use super::{currencies::USD, *};
+    use super::{
+        currencies::{JPY, KWD, USD},
+        *,
+    };
 
     type M = Money<USD>;
 
+    #[test]
+    fn formats_with_currency_decimals() {
+        assert_eq!(M::new(1234u32).to_string(), "USD 12.34");
+        assert_eq!(M::new(5u32).to_string(), "USD 0.05");
+        assert_eq!(Money::<JPY>::new(1235u32).to_string(), "JPY 1235");
+        assert_eq!(Money::<KWD>::new(1500u32).to_string(), "KWD 1.500");
+    }
+
+    #[test]
+    fn accepts_custom_currencies() {
+        #[derive(Debug, Clone, Copy, Default)]
+        struct Points;
+
+        impl Currency for Points {
+            fn ticker(&self) -> &'static str {
+                "PTS"
+            }
+
+            fn name(&self) -> &'static str {
+                "Loyalty Points"
+            }
+
+            fn decimals(&self) -> usize {
+                0
+            }
+        }
+
+        assert_eq!(Money::<Points>::new(12u8).to_string(), "PTS 12");
+        assert_eq!("PTS 12".parse(), Ok(Money::<Points>::new(12u8)));
+    }
+
+    #[test]
+    fn allocates_without_losing_cents() {
+        let parts = |v: &[u32]| v.iter().map(|c| M::new(*c)).collect::<Vec<_>>();
+
+        assert_eq!(M::new(10000u32).split(3), Ok(parts(&[3334, 3333, 3333])));
+        assert_eq!(M::new(5u32).allocate([3u8, 7]), Ok(parts(&[2, 3])));
+        assert_eq!(
+            M::new(100u32).allocate([1u8, 0, 1]),
+            Ok(parts(&[50, 0, 50]))
+        );
+        assert_eq!(M::new(2u32).split(4), Ok(parts(&[1, 1, 0, 0])));
+    }
+
+    #[test]
+    fn divides_with_negative_remainders() {
+        assert_eq!(
+            M::new(10u32).div_rem(3u8, RoundingMode::Ceil),
+            Ok((M::new(4u32), SignedMoney::new(-2)))
+        );
+        assert_eq!(
+            M::new(10u32).div_rem(4u8, RoundingMode::HalfEven),
+            Ok((M::new(2u32), SignedMoney::new(2)))
+        );
+    }
+
+    #[test]
+    fn rejects_ratios_of_different_currencies() {
+        let usd = Money(BigUint::from(100u8), CurrencyCode::USD);
+        let eur = Money(BigUint::from(100u8), CurrencyCode::EUR);
+
+        assert_eq!(
+            usd.clone() / eur,
+            Err(Error::CurrencyMismatch {
+                expected: CurrencyCode::USD,
+                found: CurrencyCode::EUR
+            })
+        );
+        assert_eq!(
+            usd.clone() / usd,
+            Ok(Ratio::from_integer(BigUint::from(1u8)))
+        );
+    }
+
+    #[test]
+    fn parses_decimals_strictly() {
+        assert_eq!("12.34".parse(), Ok(M::new(1234u32)));
+        assert_eq!("12.3".parse(), Ok(M::new(1230u32)));
+        assert_eq!("usd 12".parse(), Ok(M::new(1200u32)));
+
+        for s in ["12.345", "-1", "", ".5", "12.", "1,000", "EUR 12", "1 2"] {
+            assert_eq!(
+                s.parse::<M>(),
+                Err(crate::Error::FailedParsing(
+                    crate::Kind::Money,
+                    s.to_string()
+                )),
+                "{s} should be invalid"
+            );
+        }
+
+        assert!("12.3".parse::<Money<JPY>>().is_err());
+    }
+
     proptest! {
         #[test]
         fn has_factory(a in any::<M>()) {
@@ -196,37 +554,182 @@ mod tests {
         }
 
         #[test]
-        fn allows_multiplication(
+        fn allows_multiplication_by_scalars(
             a in (0..u32::MAX).prop_map(|x| x as u128),
-            b in (0..u32::MAX).prop_map(|x| x as u128),
+            b in 0..u32::MAX,
         ) {
-            prop_assert_eq!(M::new(a) * M::new(b), M::new(a * b));
-            prop_assert_eq!(M::new(a) * M::new(b), M::new(b * a));
+            prop_assert_eq!(M::new(a) * b, M::new(a * b as u128));
+            prop_assert_eq!(M::new(a) * BigUint::from(b), M::new(a * b as u128));
         }
 
         #[test]
-        fn allows_mul_assign(
+        fn allows_mul_assign_by_scalars(
             a in (0..u32::MAX).prop_map(|x| x as u128),
-            b in (0..u32::MAX).prop_map(|x| x as u128),
+            b in 0..u32::MAX,
         ) {
             let mut ma = M::new(a);
-            ma *= M::new(b);
+            ma *= b;
 
-            prop_assert_eq!(ma.clone(), M::new(a * b));
-            prop_assert_eq!(ma, M::new(b * a));
+            prop_assert_eq!(ma, M::new(a * b as u128));
         }
 
         #[test]
-        fn allows_division(a in 0..u128::MAX, b in 1..u128::MAX) {
-            prop_assert_eq!(M::new(a) / M::new(b), M::new(a / b));
+        fn multiplies_by_ratios(a in any::<u64>(), n in any::<u32>(), d in 1..u32::MAX) {
+            let ratio = Ratio::new(BigUint::from(n), BigUint::from(d));
+            let (product, dropped) = M::new(a).mul_ratio(&ratio);
+
+            prop_assert_eq!(&product, &M::new(a as u128 * n as u128 / d as u128));
+            prop_assert!(dropped < Ratio::from_integer(BigUint::from(1u8)));
+            prop_assert_eq!(
+                Ratio::from_integer(product.0) + dropped,
+                Ratio::from_integer(BigUint::from(a)) * ratio
+            );
         }
 
         #[test]
-        fn allows_div_assign(a in 0..u128::MAX, b in 1..u128::MAX) {
-            let mut ma = M::new(a);
-            ma /= M::new(b);
+        fn roundtrips_through_strings(a in any::<M>()) {
+            prop_assert_eq!(a.to_string().parse(), Ok(a.clone()));
+            prop_assert_eq!(a.to_string().trim_start_matches("USD ").parse(), Ok(a));
+        }
+
+        #[test]
+        fn honours_decimals(a in any::<u64>()) {
+            prop_assert_eq!(M::from_major(a), M::from_minor(a as u128 * 100));
+            prop_assert_eq!(Money::<JPY>::from_major(a), Money::<JPY>::from_minor(a));
+            prop_assert_eq!(Money::<KWD>::from_major(a), Money::<KWD>::from_minor(a as u128 * 1000));
+        }
+
+        #[test]
+        fn divides_with_remainder(mode in any::<RoundingMode>(), a in 0..u128::MAX, b in 1..u128::MAX) {
+            let (part, remainder) = M::new(a).div_rem(b, mode).expect("Failed dividing");
+
+            prop_assert_eq!(&part, &M::new(a).div_rounded(b, mode).expect("Failed dividing"));
+            prop_assert_eq!(remainder.clone() + part * b, SignedMoney::from(M::new(a)));
+            prop_assert!(remainder.abs() < M::new(b));
 
-            prop_assert_eq!(ma, M::new(a / b));
+            let (part, remainder) = M::new(a).div_rem(b, RoundingMode::TowardZero).expect("Failed dividing");
+
+            prop_assert_eq!(part, M::new(a / b));
+            prop_assert_eq!(remainder, SignedMoney::new(a % b));
+        }
+
+        #[test]
+        fn divides_into_ratios(a in 0..u128::MAX, b in 1..u128::MAX) {
+            prop_assert_eq!(
+                M::new(a) / M::new(b),
+                Ok(Ratio::new(BigUint::from(a), BigUint::from(b)))
+            );
+        }
+
+        #[test]
+        fn divides_with_rounding(mode in any::<RoundingMode>(), a in any::<u64>(), b in 1..u64::MAX) {
+            let part = M::new(a).div_rounded(b, mode).expect("Failed dividing");
+            let (truncated, remainder) = M::new(a)
+                .div_rem(b, RoundingMode::TowardZero)
+                .expect("Failed dividing");
+
+            if remainder.is_zero() {
+                prop_assert_eq!(part, truncated);
+            } else {
+                let rounded_up = part != truncated;
+
+                prop_assert!(!rounded_up || part == truncated.clone() + M::new(1u8));
+
+                let twice_remainder = remainder.abs().0 * 2u8;
+                let up_on_tie = match mode {
+                    RoundingMode::HalfUp => true,
+                    RoundingMode::HalfDown => false,
+                    _ => truncated.0.bit(0),
+                };
+
+                match mode {
+                    RoundingMode::Floor | RoundingMode::TowardZero => prop_assert!(!rounded_up),
+                    RoundingMode::Ceil => prop_assert!(rounded_up),
+                    _ => match twice_remainder.cmp(&BigUint::from(b)) {
+                        std::cmp::Ordering::Less => prop_assert!(!rounded_up),
+                        std::cmp::Ordering::Greater => prop_assert!(rounded_up),
+                        std::cmp::Ordering::Equal => prop_assert_eq!(rounded_up, up_on_tie),
+                    },
+                }
+            }
+        }
+
+        #[test]
+        fn applies_percentages(mode in any::<RoundingMode>(), a in any::<u64>(), p in 0..=100u32) {
+            let part = M::new(a).percent(&Ratio::from_integer(BigUint::from(p)), mode);
+            let exact = Ratio::new(BigUint::from(a) * p, BigUint::from(100u8));
+
+            prop_assert!(part <= M::new(a));
+            prop_assert!(Ratio::from_integer(part.0.clone()) <= exact.ceil());
+            prop_assert!(Ratio::from_integer(part.0) >= exact.floor());
+        }
+
+        #[test]
+        fn rescales_to_fewer_decimals(mode in any::<RoundingMode>(), a in any::<u64>()) {
+            let whole = M::new(a).rescale(0, mode);
+
+            prop_assert!(whole.0.clone() % 100u8 == BigUint::from(0u8));
+            prop_assert!(whole.0.clone() + 100u8 > BigUint::from(a));
+            prop_assert!(BigUint::from(a) + 100u8 > whole.0);
+            prop_assert_eq!(M::new(a).rescale(2, mode), M::new(a));
+            prop_assert_eq!(whole.rescale(0, mode), M::new(a).rescale(0, mode));
+        }
+
+        #[test]
+        fn converts_between_currencies(mode in any::<RoundingMode>(), a in any::<u64>()) {
+            let rate = Ratio::new(BigUint::from(15u8), BigUint::from(100u8));
+            let yen = M::new(a).convert::<JPY>(&rate, mode);
+            let exact = Ratio::new(BigUint::from(a) * 15u8, BigUint::from(10_000u16));
+
+            prop_assert!(Ratio::from_integer(yen.0.clone()) <= exact.ceil());
+            prop_assert!(Ratio::from_integer(yen.0) >= exact.floor());
+            prop_assert_eq!(
+                Money::<JPY>::new(a).convert::<KWD>(&Ratio::from_integer(BigUint::from(1u8)), mode),
+                Money::<KWD>::new(a as u128 * 1000)
+            );
+        }
+
+        #[test]
+        fn allocates_every_unit(a in any::<M>(), ratios in prop::collection::vec(any::<u32>(), 1..20)) {
+            prop_assume!(ratios.iter().any(|r| *r > 0));
+
+            let parts = a.allocate(ratios.iter().copied()).expect("Failed allocating");
+            let total: u128 = ratios.iter().map(|r| *r as u128).sum();
+
+            prop_assert_eq!(parts.len(), ratios.len());
+            prop_assert_eq!(
+                parts.iter().fold(M::new(0u8), |sum, part| sum + part.clone()),
+                a.clone()
+            );
+
+            for (part, ratio) in parts.iter().zip(&ratios) {
+                let exact = Ratio::new(a.0.clone() * *ratio, BigUint::from(total));
+
+                prop_assert!(Ratio::from_integer(part.0.clone()) >= exact.floor());
+                prop_assert!(Ratio::from_integer(part.0.clone()) <= exact.floor() + BigUint::from(1u8));
+            }
+        }
+
+        #[test]
+        fn splits_evenly(a in any::<M>(), n in 1..50usize) {
+            let parts = a.split(n).expect("Failed splitting");
+
+            prop_assert_eq!(parts.len(), n);
+            prop_assert_eq!(
+                parts.iter().fold(M::new(0u8), |sum, part| sum + part.clone()),
+                a.clone()
+            );
+            prop_assert!(parts.windows(2).all(|w| w[0] >= w[1]));
+            prop_assert!(parts[0].0.clone() - &parts[n - 1].0 <= BigUint::from(1u8));
+        }
+
+        #[test]
+        fn rejects_division_by_zero(a in any::<M>()) {
+            prop_assert_eq!(a.split(0), Err(Error::DivisionByZero));
+            prop_assert_eq!(a.allocate([0u8, 0]), Err(Error::DivisionByZero));
+            prop_assert_eq!(a.div_rounded(0u8, RoundingMode::HalfEven), Err(Error::DivisionByZero));
+            prop_assert_eq!(a.div_rem(0u8, RoundingMode::HalfEven), Err(Error::DivisionByZero));
+            prop_assert_eq!(a / M::new(0u8), Err(Error::DivisionByZero));
         }
     }
 }
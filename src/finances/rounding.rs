@@ -0,0 +1,171 @@
+use std::cmp::Ordering;
+
+use num_bigint::{BigInt, BigUint};
+use num_rational::Ratio;
+use num_traits::{One, Zero};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{prelude::*, sample::select};
+
+/// How amounts that fall between two minor units are rounded
+///
+/// The `Half` modes round to the nearest unit and only differ on ties, like `2.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Ties away from zero, so `2.5` is `3` and `-2.5` is `-3`
+    HalfUp,
+    /// Ties to the even neighbour, so `2.5` is `2` and `3.5` is `4`, which doesn't bias sums
+    #[default]
+    HalfEven,
+    /// Ties toward zero, so `2.5` is `2` and `-2.5` is `-2`
+    HalfDown,
+    /// Toward negative infinity, so `2.9` is `2` and `-2.1` is `-3`
+    Floor,
+    /// Toward positive infinity, so `2.1` is `3` and `-2.9` is `-2`
+    Ceil,
+    /// Drops the fraction, so `2.9` is `2` and `-2.9` is `-2`
+    TowardZero,
+}
+
+impl RoundingMode {
+    /// Rounds `value` to an integer
+    pub fn round(self, value: &Ratio<BigInt>) -> BigInt {
+        let floor = value.floor().to_integer();
+        let fraction = value - Ratio::from_integer(floor.clone());
+
+        if fraction.is_zero() {
+            return floor;
+        }
+
+        let ceil = &floor + 1;
+        let negative = value < &Ratio::zero();
+        let away_from_zero = if negative { &floor } else { &ceil };
+        let toward_zero = if negative { &ceil } else { &floor };
+
+        let on_tie = match self {
+            Self::Floor => return floor,
+            Self::Ceil => return ceil,
+            Self::TowardZero => return toward_zero.clone(),
+            Self::HalfUp => away_from_zero,
+            Self::HalfDown => toward_zero,
+            Self::HalfEven if (&floor % 2u8).is_zero() => &floor,
+            Self::HalfEven => &ceil,
+        };
+
+        match fraction.cmp(&Ratio::new(BigInt::one(), BigInt::from(2))) {
+            Ordering::Less => floor,
+            Ordering::Greater => ceil,
+            Ordering::Equal => on_tie.clone(),
+        }
+    }
+
+    /// Rounds a value that can't be negative, like the amount of a `Money`
+    pub(crate) fn round_unsigned(self, value: &Ratio<BigUint>) -> BigUint {
+        let value = Ratio::new(value.numer().clone().into(), value.denom().clone().into());
+
+        self.round(&value)
+            .to_biguint()
+            .expect("Rounding a positive value can't be negative")
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for RoundingMode {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(
+            &[
+                Self::HalfUp,
+                Self::HalfEven,
+                Self::HalfDown,
+                Self::Floor,
+                Self::Ceil,
+                Self::TowardZero,
+            ][..],
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::Signed;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn ratio(n: i64, d: i64) -> Ratio<BigInt> {
+        Ratio::new(n.into(), d.into())
+    }
+
+    #[test]
+    fn rounds_ties() {
+        use RoundingMode::*;
+
+        let cases = [
+            (HalfUp, [3, -3, 4]),
+            (HalfEven, [2, -2, 4]),
+            (HalfDown, [2, -2, 3]),
+            (Floor, [2, -3, 3]),
+            (Ceil, [3, -2, 4]),
+            (TowardZero, [2, -2, 3]),
+        ];
+
+        for (mode, expected) in cases {
+            let rounded = [ratio(5, 2), ratio(-5, 2), ratio(7, 2)].map(|v| mode.round(&v));
+
+            assert_eq!(rounded, expected.map(BigInt::from), "{mode:?}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rounds_to_a_neighbour(mode in any::<RoundingMode>(), n in any::<i64>(), d in 1..1000i64) {
+            let value = ratio(n, d);
+            let rounded = Ratio::from_integer(mode.round(&value));
+
+            prop_assert!((rounded.clone() - &value).abs() < Ratio::one());
+
+            match mode {
+                RoundingMode::Floor => prop_assert!(rounded <= value),
+                RoundingMode::Ceil => prop_assert!(rounded >= value),
+                RoundingMode::TowardZero => prop_assert!(rounded.abs() <= value.abs()),
+                _ => prop_assert!((rounded - &value).abs() <= ratio(1, 2)),
+            }
+        }
+
+        #[test]
+        fn keeps_integers(mode in any::<RoundingMode>(), n in any::<i64>()) {
+            prop_assert_eq!(mode.round(&ratio(n, 1)), BigInt::from(n));
+        }
+
+        #[test]
+        fn breaks_ties_by_mode(mode in any::<RoundingMode>(), n in -1_000_000..1_000_000i64) {
+            let value = ratio(2 * n + 1, 2);
+            let rounded = mode.round(&value);
+
+            match mode {
+                RoundingMode::HalfEven => prop_assert!((&rounded % 2u8).is_zero()),
+                RoundingMode::HalfUp => {
+                    prop_assert!(Ratio::from_integer(rounded.abs()) > value.abs())
+                }
+                RoundingMode::HalfDown => {
+                    prop_assert!(Ratio::from_integer(rounded.abs()) < value.abs())
+                }
+                _ => {}
+            }
+        }
+
+        #[test]
+        fn rounds_unsigned_like_signed(mode in any::<RoundingMode>(), n in any::<u64>(), d in 1..1000u64) {
+            let value = Ratio::new(BigUint::from(n), BigUint::from(d));
+
+            prop_assert_eq!(
+                BigInt::from(mode.round_unsigned(&value)),
+                mode.round(&Ratio::new(BigInt::from(n), BigInt::from(d)))
+            );
+        }
+    }
+}
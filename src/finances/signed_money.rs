@@ -0,0 +1,283 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use num_bigint::{BigInt, Sign};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::prelude::*;
+
+use super::{currencies::Currency, format_decimal, parse_decimal, Error, Money};
+
+/// An amount of money that can be negative, like a balance, a refund or an adjustment
+///
+/// Positive amounts are credits and negative ones are debits, from the point of view of whoever
+/// holds the balance. Arithmetic never fails, and converting back into a `Money<C>` fails with
+/// `Error::NegativeAmount` for debits.
+#[derive(Debug, Clone)]
+pub struct SignedMoney<C>(BigInt, C);
+
+impl<C: Default + Currency> SignedMoney<C> {
+    /// Creates an amount from minor units of the currency, like cents
+    pub fn new(amount: impl Into<BigInt>) -> Self {
+        Self(amount.into(), C::default())
+    }
+
+    /// An amount added to the balance
+    pub fn credit(money: Money<C>) -> Self {
+        Self::from(money)
+    }
+
+    /// An amount taken from the balance
+    pub fn debit(money: Money<C>) -> Self {
+        -Self::from(money)
+    }
+}
+
+impl<C> SignedMoney<C> {
+    /// The amount, in minor units of the currency
+    pub fn amount(&self) -> &BigInt {
+        &self.0
+    }
+
+    pub fn is_credit(&self) -> bool {
+        self.0.sign() == Sign::Plus
+    }
+
+    pub fn is_debit(&self) -> bool {
+        self.0.sign() == Sign::Minus
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.sign() == Sign::NoSign
+    }
+}
+
+impl<C: Clone> SignedMoney<C> {
+    /// The amount without its sign, which is always a valid `Money<C>`
+    pub fn abs(&self) -> Money<C> {
+        Money(self.0.magnitude().clone(), self.1.clone())
+    }
+}
+
+impl<C> From<Money<C>> for SignedMoney<C> {
+    fn from(money: Money<C>) -> Self {
+        Self(money.0.into(), money.1)
+    }
+}
+
+impl<C> TryFrom<SignedMoney<C>> for Money<C> {
+    type Error = Error;
+
+    /// Fails with `Error::NegativeAmount` for debits
+    fn try_from(money: SignedMoney<C>) -> Result<Self, Self::Error> {
+        match money.0.into_parts() {
+            (Sign::Minus, _) => Err(Error::NegativeAmount),
+            (_, amount) => Ok(Money(amount, money.1)),
+        }
+    }
+}
+
+impl<C: Currency> Display for SignedMoney<C> {
+    /// Writes the ticker and the amount with its sign, like `USD -12.30`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}{}",
+            self.1.ticker(),
+            if self.is_debit() { "-" } else { "" },
+            format_decimal(self.0.magnitude(), self.1.decimals())
+        )
+    }
+}
+
+impl<C: Currency + Default> FromStr for SignedMoney<C> {
+    type Err = crate::Error;
+
+    /// Parses a decimal amount with an optional sign, optionally preceded by the ticker, like
+    /// `-12.3` or `USD -12.30`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let currency = C::default();
+        let amount = match s.split_once(' ') {
+            Some((ticker, amount)) if ticker.eq_ignore_ascii_case(currency.ticker()) => amount,
+            _ => s,
+        };
+        let (sign, amount) = match amount.strip_prefix('-') {
+            Some(amount) => (Sign::Minus, amount),
+            None => (Sign::Plus, amount),
+        };
+
+        parse_decimal(amount, currency.decimals())
+            .map(|amount| Self(BigInt::from_biguint(sign, amount), currency))
+            .ok_or_else(|| crate::Error::FailedParsing(crate::Kind::Money, s.to_string()))
+    }
+}
+
+impl<C> PartialEq for SignedMoney<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C> Eq for SignedMoney<C> {}
+
+impl<C> PartialOrd for SignedMoney<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for SignedMoney<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<C> Neg for SignedMoney<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0, self.1)
+    }
+}
+
+impl<C> Add for SignedMoney<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1)
+    }
+}
+
+impl<C> AddAssign for SignedMoney<C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<C> Sub for SignedMoney<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0, self.1)
+    }
+}
+
+impl<C> SubAssign for SignedMoney<C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<C> Add<Money<C>> for SignedMoney<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Money<C>) -> Self::Output {
+        Self(self.0 + BigInt::from(rhs.0), self.1)
+    }
+}
+
+impl<C> Sub<Money<C>> for SignedMoney<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Money<C>) -> Self::Output {
+        Self(self.0 - BigInt::from(rhs.0), self.1)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<C: Debug + Default> Arbitrary for SignedMoney<C> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<i128>()
+            .prop_map(|i| Self(BigInt::from(i), Default::default()))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::finances::currencies::USD;
+
+    type M = Money<USD>;
+    type S = SignedMoney<USD>;
+
+    #[test]
+    fn formats_signs() {
+        assert_eq!(S::new(-1234).to_string(), "USD -12.34");
+        assert_eq!(S::new(5).to_string(), "USD 0.05");
+        assert_eq!("USD -12.3".parse(), Ok(S::new(-1230)));
+        assert_eq!("-0".parse(), Ok(S::new(0)));
+
+        for s in ["--1", "+1", "- 1", "USD - 1", "1-"] {
+            assert!(s.parse::<S>().is_err(), "{s} should be invalid");
+        }
+    }
+
+    #[test]
+    fn records_debits_and_credits() {
+        let balance = S::credit(M::new(1000u32)) + S::debit(M::new(2500u32));
+
+        assert!(balance.is_debit());
+        assert_eq!(balance, S::new(-1500));
+        assert_eq!(balance.abs(), M::new(1500u32));
+        assert_eq!(M::try_from(balance), Err(Error::NegativeAmount));
+        assert!(S::new(0).is_zero() && !S::new(0).is_credit());
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrips_through_strings(a in any::<S>()) {
+            prop_assert_eq!(a.to_string().parse(), Ok(a.clone()));
+            prop_assert_eq!(a.to_string().trim_start_matches("USD ").parse(), Ok(a));
+        }
+
+        #[test]
+        fn preserves_signs(a in any::<i64>(), b in any::<i64>()) {
+            let (a128, b128) = (a as i128, b as i128);
+
+            prop_assert_eq!(S::new(a) + S::new(b), S::new(a128 + b128));
+            prop_assert_eq!(S::new(a) - S::new(b), S::new(a128 - b128));
+            prop_assert_eq!(-S::new(a), S::new(-a128));
+            prop_assert_eq!(S::new(a) < S::new(b), a < b);
+
+            let mut c = S::new(a);
+            c += S::new(b);
+            c -= S::new(b);
+            prop_assert_eq!(c, S::new(a));
+        }
+
+        #[test]
+        fn subtracts_below_zero(a in any::<u64>(), b in any::<u64>()) {
+            let result = S::from(M::new(a)) - M::new(b);
+
+            prop_assert_eq!(result.is_debit(), a < b);
+            prop_assert_eq!(result.abs(), M::new(a.abs_diff(b)));
+        }
+
+        #[test]
+        fn converts_to_money_only_when_not_negative(a in any::<S>()) {
+            match M::try_from(a.clone()) {
+                Ok(m) => prop_assert_eq!(S::from(m), a),
+                Err(e) => {
+                    prop_assert!(a.is_debit());
+                    prop_assert_eq!(e, Error::NegativeAmount);
+                }
+            }
+        }
+
+        #[test]
+        fn has_absolute_values(a in any::<S>()) {
+            prop_assert_eq!(a.abs(), (-a.clone()).abs());
+            prop_assert_eq!(S::from(a.abs()) >= a, true);
+        }
+    }
+}
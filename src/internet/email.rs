@@ -18,6 +18,8 @@ use fake::{faker::internet::en::SafeEmail, Fake};
 #[cfg(any(test, feature = "testing"))]
 use proptest::prelude::*;
 
+#[cfg(feature = "lang")]
+use crate::lang::{EmailMask, Masked, MaskingStrategy};
 use crate::{
     testing::{NegateArbitrary, Rng},
     *,
@@ -48,6 +50,14 @@ impl Display for Email {
     }
 }
 
+#[cfg(feature = "lang")]
+impl Masked for Email {
+    /// Shows the first character of the local part and the domain, like `j***@example.com`
+    fn mask(&self) -> String {
+        EmailMask::mask(self)
+    }
+}
+
 impl Deref for Email {
     type Target = <String as Deref>::Target;
 
@@ -0,0 +1,159 @@
+use std::{
+    panic::Location,
+    sync::{Arc, RwLock},
+};
+
+/// How a secret was accessed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Borrowed through `expose`
+    Shared,
+    /// Borrowed through `expose_mut`
+    Mutable,
+    /// Moved out of its wrapper, through `get`
+    Owned,
+    /// Partially shown through `Display` or `Debug` by a `MaskingStrategy`
+    Masked,
+    /// Serialised with `Redaction::Reveal`
+    Serialized,
+}
+
+/// A record of a secret being read, as passed to the hook set with `set_exposure_hook`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exposure {
+    /// The type of the secret, like `alloc::string::String`
+    pub type_name: &'static str,
+    pub access: Access,
+    /// Where the secret was exposed, which is the caller of `expose`, `get` or the methods of
+    /// `Encrypted`, or `None` for `Access::Masked` and `Access::Serialized`, as neither
+    /// formatting nor serde track their callers
+    pub location: Option<&'static Location<'static>>,
+}
+
+type Hook = Arc<dyn Fn(&Exposure) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Sets the function called every time a `Sensitive` or `Credential` is exposed, replacing the
+/// previous one
+///
+/// The hook runs synchronously on the thread exposing the secret, so it should be quick, like
+/// logging or incrementing a counter. Secrets it exposes are recorded too, so it must not do so
+/// unconditionally.
+pub fn set_exposure_hook(hook: impl Fn(&Exposure) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+}
+
+pub fn clear_exposure_hook() {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+#[track_caller]
+pub(crate) fn record<T>(access: Access) {
+    notify::<T>(access, Some(Location::caller()));
+}
+
+/// Records an exposure from code that can't know its caller, like formatting or serialising
+pub(crate) fn record_untracked<T>(access: Access) {
+    notify::<T>(access, None);
+}
+
+fn notify<T>(access: Access, location: Option<&'static Location<'static>>) {
+    // The hook is called without holding the lock, so it can replace itself or expose secrets
+    let hook = HOOK.read().unwrap_or_else(|e| e.into_inner()).clone();
+
+    if let Some(hook) = hook {
+        hook(&Exposure {
+            type_name: std::any::type_name::<T>(),
+            access,
+            location,
+        });
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::lang::Sensitive;
+
+    /// Held by tests that set the hook, which is shared by the whole process
+    pub(crate) static LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn records_call_sites() {
+        static EXPOSURES: Mutex<Vec<Exposure>> = Mutex::new(vec![]);
+
+        let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_exposure_hook(|e| EXPOSURES.lock().unwrap().push(*e));
+
+        let mut secret = Sensitive::new(42u128);
+        let read = (*secret.expose(), line!());
+        let write = (secret.expose_mut(), line!());
+        *write.0 += read.0;
+
+        clear_exposure_hook();
+
+        let exposures: Vec<_> = EXPOSURES
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|e| Some((e, e.location?)))
+            .filter(|(_, location)| location.file() == file!())
+            .map(|(e, location)| (e.type_name, e.access, location.line()))
+            .collect();
+
+        assert_eq!(
+            exposures,
+            [
+                ("u128", Access::Shared, read.1),
+                ("u128", Access::Mutable, write.1)
+            ]
+        );
+        assert_eq!(secret.get(), 84);
+    }
+
+    #[test]
+    fn allows_reentrant_hooks() {
+        static CALLS: Mutex<usize> = Mutex::new(0);
+
+        let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_exposure_hook(|e| {
+            if e.type_name == "u8" {
+                Sensitive::new(1usize).expose();
+                clear_exposure_hook();
+            }
+
+            *CALLS.lock().unwrap() += 1;
+        });
+
+        Sensitive::new(1u8).expose();
+        Sensitive::new(2u8).expose();
+        clear_exposure_hook();
+
+        assert_eq!(*CALLS.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn records_partially_masked_values() {
+        static EXPOSURES: Mutex<Vec<Exposure>> = Mutex::new(vec![]);
+
+        let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_exposure_hook(|e| EXPOSURES.lock().unwrap().push(*e));
+
+        let card = Sensitive::new("4111 1111 1111 1234").with_masking::<crate::lang::LastFour>();
+        let masked = (Sensitive::new(1u8).to_string(), format!("{card:?}"));
+        clear_exposure_hook();
+
+        assert_eq!(masked.1, "Sensitive(\"****1234\")");
+        assert_eq!(
+            *EXPOSURES.lock().unwrap(),
+            [Exposure {
+                type_name: "&str",
+                access: Access::Masked,
+                location: None
+            }]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    str::FromStr,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{lang::Text, Error, Kind};
+
+/// The biggest amount of units generated by `Arbitrary`, to keep huge bounds from generating huge
+/// strings
+#[cfg(any(test, feature = "testing"))]
+const MAX_ARBITRARY_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The unit used to measure the length of a `BoundedText`
+pub enum Count {
+    /// Unicode scalar values, as returned by `str::chars`
+    Chars,
+    /// Extended grapheme clusters, which is what users perceive as a single character
+    Graphemes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Which whitespace is removed from a `BoundedText` before measuring it
+pub enum Trim {
+    Keep,
+    Start,
+    End,
+    Both,
+}
+
+impl Trim {
+    fn apply(self, s: &str) -> &str {
+        match self {
+            Trim::Keep => s,
+            Trim::Start => s.trim_start(),
+            Trim::End => s.trim_end(),
+            Trim::Both => s.trim(),
+        }
+    }
+}
+
+/// Declares how a `BoundedText` is trimmed and measured
+pub trait TextPolicy {
+    const COUNT: Count;
+    const TRIM: Trim;
+}
+
+/// Counts grapheme clusters, after trimming whitespace on both ends
+pub enum Graphemes {}
+
+impl TextPolicy for Graphemes {
+    const COUNT: Count = Count::Graphemes;
+    const TRIM: Trim = Trim::Both;
+}
+
+/// Counts chars, after trimming whitespace on both ends
+pub enum Chars {}
+
+impl TextPolicy for Chars {
+    const COUNT: Count = Count::Chars;
+    const TRIM: Trim = Trim::Both;
+}
+
+/// Counts grapheme clusters, keeping the text exactly as it was given
+pub enum Verbatim {}
+
+impl TextPolicy for Verbatim {
+    const COUNT: Count = Count::Graphemes;
+    const TRIM: Trim = Trim::Keep;
+}
+
+/// A piece of UTF-8 valid text with at least `MIN` and at most `MAX` units, as measured by `P`
+pub struct BoundedText<const MIN: usize, const MAX: usize, P = Graphemes>(
+    String,
+    PhantomData<fn() -> P>,
+);
+
+/// A piece of text that is never empty (after trimming)
+pub type NonEmptyText<P = Graphemes> = BoundedText<1, { usize::MAX }, P>;
+
+impl<const MIN: usize, const MAX: usize, P: TextPolicy> BoundedText<MIN, MAX, P> {
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "MIN must not be bigger than MAX");
+
+    pub fn new(s: impl AsRef<str>) -> Result<Self, Error> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_BOUNDS;
+
+        let s = P::TRIM.apply(s.as_ref());
+        let actual = length::<P>(s);
+
+        if (MIN..=MAX).contains(&actual) {
+            Ok(Self(s.to_string(), PhantomData))
+        } else {
+            Err(Error::InvalidLength {
+                kind: Kind::Text,
+                actual,
+                min: MIN,
+                max: MAX,
+            })
+        }
+    }
+
+    /// Returns the length of this text, in the units defined by `P`
+    pub fn length(&self) -> usize {
+        length::<P>(&self.0)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+fn length<P: TextPolicy>(s: &str) -> usize {
+    match P::COUNT {
+        Count::Chars => s.chars().count(),
+        Count::Graphemes => s.graphemes(true).count(),
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> Clone for BoundedText<MIN, MAX, P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> Debug for BoundedText<MIN, MAX, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BoundedText").field(&self.0).finish()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> PartialEq for BoundedText<MIN, MAX, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> Eq for BoundedText<MIN, MAX, P> {}
+
+impl<const MIN: usize, const MAX: usize, P> PartialOrd for BoundedText<MIN, MAX, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> Ord for BoundedText<MIN, MAX, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> Hash for BoundedText<MIN, MAX, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P: TextPolicy> FromStr for BoundedText<MIN, MAX, P> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P: TextPolicy> TryFrom<String>
+    for BoundedText<MIN, MAX, P>
+{
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P: TextPolicy> TryFrom<Text> for BoundedText<MIN, MAX, P> {
+    type Error = Error;
+
+    fn try_from(text: Text) -> Result<Self, Self::Error> {
+        Self::new(&*text)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> From<BoundedText<MIN, MAX, P>> for Text {
+    fn from(text: BoundedText<MIN, MAX, P>) -> Self {
+        text.0.into()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> Display for BoundedText<MIN, MAX, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, P> Deref for BoundedText<MIN, MAX, P> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const MIN: usize, const MAX: usize, P> serde::Serialize for BoundedText<MIN, MAX, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, const MIN: usize, const MAX: usize, P: TextPolicy> serde::Deserialize<'a>
+    for BoundedText<MIN, MAX, P>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        Self::new(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<const MIN: usize, const MAX: usize, P: TextPolicy + 'static> Arbitrary
+    for BoundedText<MIN, MAX, P>
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Every unit here counts as exactly one of `P::COUNT`, no matter what surrounds it
+        let units: &'static [&'static str] = match P::COUNT {
+            Count::Chars => &["a", "Z", "7", "ç", "字", "🦀", " "],
+            Count::Graphemes => &["a", "Z", "7", "e\u{301}", "字", "🇧🇷", "👍🏽", "👩‍💻", " "],
+        };
+
+        (MIN..=MAX.min(MIN.saturating_add(MAX_ARBITRARY_LEN)))
+            .prop_flat_map(move |len| proptest::collection::vec(select(units), len))
+            .prop_map(|mut units| {
+                // Whitespace on the ends would be trimmed away, changing the length
+                let last = units.len().saturating_sub(1);
+
+                for i in [0, last] {
+                    if units.get(i) == Some(&" ") {
+                        units[i] = "a";
+                    }
+                }
+
+                units.concat()
+            })
+            .prop_map(|s| Self::new(s).expect("Generated text outside of bounds"))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    type Short = BoundedText<2, 5>;
+    type ShortChars = BoundedText<2, 5, Chars>;
+    type ShortVerbatim = BoundedText<2, 5, Verbatim>;
+
+    #[test]
+    fn counts_graphemes_or_chars() {
+        assert_eq!(Short::new("e\u{301}e\u{301}").map(|t| t.length()), Ok(2));
+        assert_eq!(
+            ShortChars::new("e\u{301}e\u{301}").map(|t| t.length()),
+            Ok(4)
+        );
+        assert!(Short::new("👩‍💻👩‍💻👩‍💻").is_ok());
+        assert!(ShortChars::new("👩‍💻👩‍💻👩‍💻").is_err());
+    }
+
+    #[test]
+    fn applies_trim_policies() {
+        assert_eq!(
+            Short::new("  abc \n").map(|t| t.into_inner()),
+            Ok("abc".into())
+        );
+        assert_eq!(
+            ShortVerbatim::new(" ab ").map(|t| t.into_inner()),
+            Ok(" ab ".into())
+        );
+        assert_eq!(
+            Short::new("  a  "),
+            Err(Error::InvalidLength {
+                kind: Kind::Text,
+                actual: 1,
+                min: 2,
+                max: 5
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_blank_non_empty_text() {
+        assert_eq!(
+            NonEmptyText::<Graphemes>::new(" \t\n"),
+            Err(Error::InvalidLength {
+                kind: Kind::Text,
+                actual: 0,
+                min: 1,
+                max: usize::MAX
+            })
+        );
+        assert_eq!(
+            NonEmptyText::<Graphemes>::new("").unwrap_err().to_string(),
+            "Invalid `text` length: expected at least 1, found 0"
+        );
+        assert_eq!(
+            Short::new("a").unwrap_err().to_string(),
+            "Invalid `text` length: expected between 2 and 5, found 1"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_text_is_always_valid(
+            a in any::<Short>(),
+            b in any::<ShortChars>(),
+            c in any::<ShortVerbatim>(),
+            d in any::<NonEmptyText>(),
+        ) {
+            prop_assert_eq!(a.to_string().parse::<Short>(), Ok(a));
+            prop_assert_eq!(b.to_string().parse::<ShortChars>(), Ok(b));
+            prop_assert_eq!(c.to_string().parse::<ShortVerbatim>(), Ok(c));
+            prop_assert_eq!(d.to_string().parse::<NonEmptyText>(), Ok(d));
+        }
+
+        #[test]
+        fn rejects_text_outside_of_bounds(s in "[a-z]{0,10}") {
+            let result = s.parse::<ShortChars>();
+
+            if (2..=5).contains(&s.len()) {
+                prop_assert!(result.is_ok());
+            } else {
+                prop_assert_eq!(
+                    result,
+                    Err(Error::InvalidLength {
+                        kind: Kind::Text,
+                        actual: s.len(),
+                        min: 2,
+                        max: 5
+                    })
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use zeroize::Zeroize;
+
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+use crate::Error;
+
+use crate::lang::{sensitive::MASK, Sensitive};
+
+/// A secret that must never be duplicated, like an API key or a private key
+///
+/// Unlike `Sensitive`, it can't be cloned, and its value is overwritten with zeroes when dropped.
+/// The value is kept behind a pointer, so moving the credential around doesn't leave copies of it
+/// on the stack.
+pub struct Credential<T: Zeroize> {
+    value: Box<T>,
+    /// The memory regions locked by `Credential::locked`, as address and length
+    #[cfg(all(feature = "mlock", target_os = "linux"))]
+    locked: Vec<(usize, usize)>,
+}
+
+impl<T: Zeroize> Credential<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Box::new(value),
+            #[cfg(all(feature = "mlock", target_os = "linux"))]
+            locked: vec![],
+        }
+    }
+
+    /// Borrows the value, which is recorded as an exposure with the `audit` feature
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn expose(&self) -> &T {
+        #[cfg(feature = "audit")]
+        crate::lang::audit::record::<T>(crate::lang::Access::Shared);
+
+        &self.value
+    }
+}
+
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+impl<T: Zeroize + AsRef<[u8]>> Credential<T> {
+    /// Same as `new`, but also locks the pages holding the value in RAM, so they are never
+    /// written to swap
+    ///
+    /// Both the value itself and the bytes it points to are locked. Pages shared with other
+    /// locked values stay locked until the last of them is dropped.
+    pub fn locked(value: T) -> Result<Self, Error> {
+        let mut credential = Self::new(value);
+        let bytes: &[u8] = (*credential.value).as_ref();
+
+        let regions = [
+            (
+                &*credential.value as *const T as usize,
+                std::mem::size_of::<T>(),
+            ),
+            (bytes.as_ptr() as usize, bytes.len()),
+        ];
+
+        for (address, len) in regions.into_iter().filter(|(_, len)| *len > 0) {
+            memory::lock(address, len)?;
+            credential.locked.push((address, len));
+        }
+
+        Ok(credential)
+    }
+}
+
+/// Locks memory a page at a time, counting how many regions use each page
+///
+/// Locks don't stack, so a page is only unlocked once no locked region uses it anymore.
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+mod memory {
+    use std::{collections::BTreeMap, sync::Mutex};
+
+    use crate::Error;
+
+    /// How many locked regions use each page, by its address
+    static PAGES: Mutex<BTreeMap<usize, usize>> = Mutex::new(BTreeMap::new());
+
+    fn page_size() -> usize {
+        // SAFETY: sysconf has no preconditions
+        unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+    }
+
+    /// The address of every page overlapping a region
+    pub(super) fn pages(address: usize, len: usize) -> impl Iterator<Item = usize> {
+        let size = page_size();
+
+        (address / size * size..address + len).step_by(size)
+    }
+
+    pub(super) fn lock(address: usize, len: usize) -> Result<(), Error> {
+        let mut pages = PAGES.lock().unwrap_or_else(|e| e.into_inner());
+
+        // SAFETY: the region is owned by the boxed value, which doesn't move nor reallocate while
+        // the credential is alive, as it is never handed out mutably
+        if unsafe { libc::mlock(address as *const libc::c_void, len) } != 0 {
+            return Err(Error::MemoryLock(
+                std::io::Error::last_os_error().to_string(),
+            ));
+        }
+
+        for page in self::pages(address, len) {
+            *pages.entry(page).or_default() += 1;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn unlock(address: usize, len: usize) {
+        let mut pages = PAGES.lock().unwrap_or_else(|e| e.into_inner());
+
+        for page in self::pages(address, len) {
+            let Some(count) = pages.get_mut(&page) else {
+                continue;
+            };
+
+            *count -= 1;
+
+            if *count == 0 {
+                pages.remove(&page);
+
+                // SAFETY: the page was locked by `lock` and no locked region uses it anymore
+                unsafe { libc::munlock(page as *const libc::c_void, page_size()) };
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn is_locked(page: usize) -> bool {
+        PAGES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains_key(&page)
+    }
+}
+
+impl<T: Zeroize> Drop for Credential<T> {
+    fn drop(&mut self) {
+        self.value.zeroize();
+
+        #[cfg(all(feature = "mlock", target_os = "linux"))]
+        for (address, len) in self.locked.drain(..) {
+            memory::unlock(address, len);
+        }
+    }
+}
+
+impl<T: Zeroize> From<T> for Credential<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Zeroize, M> From<Sensitive<T, M>> for Credential<T> {
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn from(value: Sensitive<T, M>) -> Self {
+        Self::new(value.get())
+    }
+}
+
+impl<T: Zeroize> Display for Credential<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", MASK)
+    }
+}
+
+impl<T: Zeroize> Debug for Credential<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Credential").field(&MASK).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn hides_internal_representation(a in any::<String>()) {
+            let credential = Credential::new(a.clone());
+
+            prop_assert_eq!(credential.to_string(), MASK);
+            prop_assert_eq!(format!("{:?}", credential), format!("Credential(\"{}\")", MASK));
+            prop_assert_eq!(credential.expose(), &a);
+        }
+    }
+
+    #[cfg(all(feature = "mlock", target_os = "linux"))]
+    #[test]
+    #[ignore = "needs a RLIMIT_MEMLOCK that allows locking memory, run with --ignored"]
+    fn locks_memory() {
+        let first = Credential::locked(String::from("hunter2")).expect("Failed locking");
+        let second = Credential::locked(String::from("hunter3")).expect("Failed locking");
+
+        assert_eq!(first.locked.len(), 2);
+        assert_eq!(first.expose(), "hunter2");
+
+        let pages: Vec<usize> = second
+            .locked
+            .iter()
+            .flat_map(|(address, len)| memory::pages(*address, *len))
+            .collect();
+
+        drop(first);
+
+        assert!(pages.iter().all(|page| memory::is_locked(*page)));
+    }
+}
@@ -0,0 +1,436 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter},
+    io::Write,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    XChaCha20Poly1305, XNonce,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use zeroize::Zeroizing;
+
+use crate::{
+    lang::{Credential, Redaction, Sensitive},
+    Error, Kind,
+};
+
+/// The length of the nonces used by XChaCha20-Poly1305
+const NONCE_LEN: usize = 24;
+
+/// A 256-bit key for `Encrypted` values
+pub type EncryptionKey = Credential<[u8; 32]>;
+
+/// Where the keys for `Encrypted` values come from
+///
+/// Every encrypted value records the id of the key it was sealed with, so keys can be rotated by
+/// changing the current one while keeping the old ones around until every value is resealed.
+pub trait Keyring {
+    /// The id of the key new values are sealed with
+    fn current_key_id(&self) -> &str;
+
+    /// The key with `id`, or `None` if it was retired
+    fn key(&self, id: &str) -> Option<&EncryptionKey>;
+}
+
+/// A `Keyring` holding every key in memory, for tests or keys loaded from the environment
+pub struct MemoryKeyring {
+    current: String,
+    keys: HashMap<String, EncryptionKey>,
+}
+
+/// Checks that `id` can be stored before the `:` that separates it from the ciphertext
+fn key_id(id: impl Into<String>) -> Result<String, Error> {
+    let id = id.into();
+
+    if id.is_empty() || id.contains(':') {
+        Err(Error::InvalidKeyId(id))
+    } else {
+        Ok(id)
+    }
+}
+
+impl MemoryKeyring {
+    /// Fails with `Error::InvalidKeyId` if `id` is empty or contains `:`
+    pub fn new(id: impl Into<String>, key: EncryptionKey) -> Result<Self, Error> {
+        let current = key_id(id)?;
+
+        Ok(Self {
+            keys: HashMap::from([(current.clone(), key)]),
+            current,
+        })
+    }
+
+    /// Adds a key and makes it the current one, keeping the previous keys for decryption
+    ///
+    /// Fails with `Error::InvalidKeyId` if `id` is empty or contains `:`.
+    pub fn rotate(&mut self, id: impl Into<String>, key: EncryptionKey) -> Result<(), Error> {
+        self.current = key_id(id)?;
+        self.keys.insert(self.current.clone(), key);
+
+        Ok(())
+    }
+
+    /// Removes a key, so values sealed with it can no longer be opened
+    ///
+    /// The current key can't be retired, so this returns `false` for it.
+    pub fn retire(&mut self, id: &str) -> bool {
+        id != self.current && self.keys.remove(id).is_some()
+    }
+}
+
+impl Keyring for MemoryKeyring {
+    fn current_key_id(&self) -> &str {
+        &self.current
+    }
+
+    fn key(&self, id: &str) -> Option<&EncryptionKey> {
+        self.keys.get(id)
+    }
+}
+
+/// A value encrypted at rest with XChaCha20-Poly1305, which can only be read with its `Keyring`
+///
+/// The value is serialised as JSON before being sealed. The envelope is written as
+/// `key_id:hex`, where the hex holds the nonce followed by the ciphertext, and the key id is
+/// authenticated along with the value, so it can't be swapped.
+pub struct Encrypted<T> {
+    key_id: String,
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[cfg_attr(feature = "audit", track_caller)]
+fn cipher(keyring: &impl Keyring, id: &str) -> Result<XChaCha20Poly1305, Error> {
+    let key = keyring
+        .key(id)
+        .ok_or_else(|| Error::UnknownKey(id.to_string()))?;
+
+    Ok(XChaCha20Poly1305::new(key.expose().into()))
+}
+
+/// Counts the bytes written to it, to size buffers before writing secrets into them
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Serialises `value` as JSON into a buffer that is wiped when dropped
+///
+/// The buffer is allocated with its final size, so it never grows and leaves copies behind.
+fn plaintext<T: Serialize>(value: &T) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let fail = |e: serde_json::Error| Error::Encryption(e.to_string());
+
+    let mut counter = Counter(0);
+    serde_json::to_writer(&mut counter, value).map_err(fail)?;
+
+    let mut plaintext = Zeroizing::new(Vec::with_capacity(counter.0));
+    serde_json::to_writer(&mut *plaintext, value).map_err(fail)?;
+
+    Ok(plaintext)
+}
+
+impl<T: Serialize> Encrypted<T> {
+    /// Encrypts `value` with the current key of `keyring`
+    ///
+    /// Any `Sensitive` value inside it is sealed as it is, regardless of the current `Redaction`.
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn seal(value: &T, keyring: &impl Keyring) -> Result<Self, Error> {
+        let key_id = keyring.current_key_id().to_string();
+        let plaintext = Redaction::Reveal.scope(|| plaintext(value))?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let ciphertext = cipher(keyring, &key_id)?
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: &plaintext,
+                    aad: key_id.as_bytes(),
+                },
+            )
+            .map_err(|e| Error::Encryption(e.to_string()))?;
+
+        Ok(Self {
+            key_id,
+            nonce: nonce.into(),
+            ciphertext,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T: DeserializeOwned> Encrypted<T> {
+    /// Decrypts the value with the key it was sealed with
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn open(&self, keyring: &impl Keyring) -> Result<Sensitive<T>, Error> {
+        let plaintext = Zeroizing::new(
+            cipher(keyring, &self.key_id)?
+                .decrypt(
+                    XNonce::from_slice(&self.nonce),
+                    Payload {
+                        msg: &self.ciphertext,
+                        aad: self.key_id.as_bytes(),
+                    },
+                )
+                .map_err(|e| Error::Encryption(e.to_string()))?,
+        );
+
+        serde_json::from_slice(&plaintext)
+            .map(Sensitive::new)
+            .map_err(|e| Error::Encryption(e.to_string()))
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Encrypted<T> {
+    /// Seals the value again with the current key of `keyring`, if it was sealed with another one
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn rotate(self, keyring: &impl Keyring) -> Result<Self, Error> {
+        if self.key_id == keyring.current_key_id() {
+            return Ok(self);
+        }
+
+        Self::seal(self.open(keyring)?.expose(), keyring)
+    }
+}
+
+impl<T> Encrypted<T> {
+    /// The id of the key this value was sealed with
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+}
+
+impl<T> Clone for Encrypted<T> {
+    fn clone(&self) -> Self {
+        Self {
+            key_id: self.key_id.clone(),
+            nonce: self.nonce,
+            ciphertext: self.ciphertext.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for Encrypted<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key_id == other.key_id
+            && self.nonce == other.nonce
+            && self.ciphertext == other.ciphertext
+    }
+}
+
+impl<T> Eq for Encrypted<T> {}
+
+impl<T> Debug for Encrypted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Encrypted")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for Encrypted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.key_id)?;
+
+        for byte in self.nonce.iter().chain(&self.ciphertext) {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> FromStr for Encrypted<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fail = || Error::FailedParsing(Kind::Encrypted, s.to_string());
+        let (key_id, hex) = s.rsplit_once(':').ok_or_else(fail)?;
+
+        if key_id.is_empty() || hex.len() % 2 != 0 || !hex.is_ascii() {
+            return Err(fail());
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| fail())?;
+
+        if bytes.len() < NONCE_LEN {
+            return Err(fail());
+        }
+
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+
+        Ok(Self {
+            key_id: key_id.to_string(),
+            nonce: nonce.try_into().map_err(|_| fail())?,
+            ciphertext: ciphertext.to_vec(),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T> Serialize for Encrypted<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'a, T> serde::Deserialize<'a> for Encrypted<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn keyring() -> MemoryKeyring {
+        MemoryKeyring::new("2024-01", Credential::new([1; 32])).unwrap()
+    }
+
+    #[test]
+    fn rotates_keys() {
+        let mut keyring = keyring();
+        let sealed = Encrypted::seal(&"hunter2".to_string(), &keyring).unwrap();
+
+        keyring.rotate("2024-06", Credential::new([2; 32])).unwrap();
+        assert_eq!(sealed.open(&keyring).unwrap().get(), "hunter2");
+
+        let rotated = sealed.clone().rotate(&keyring).unwrap();
+        assert_eq!(rotated.key_id(), "2024-06");
+
+        assert!(keyring.retire("2024-01"));
+        assert!(!keyring.retire("2024-06"));
+        assert_eq!(
+            sealed.open(&keyring),
+            Err(Error::UnknownKey("2024-01".to_string()))
+        );
+        assert_eq!(rotated.open(&keyring).unwrap().get(), "hunter2");
+    }
+
+    #[test]
+    fn rejects_invalid_key_ids() {
+        for id in ["", "2024:06"] {
+            assert!(matches!(
+                MemoryKeyring::new(id, Credential::new([1; 32])),
+                Err(Error::InvalidKeyId(i)) if i == id
+            ));
+        }
+
+        let mut keyring = keyring();
+        assert_eq!(
+            keyring.rotate("", Credential::new([2; 32])),
+            Err(Error::InvalidKeyId(String::new()))
+        );
+        assert_eq!(keyring.current_key_id(), "2024-01");
+    }
+
+    #[derive(Debug, PartialEq, Serialize, serde::Deserialize)]
+    struct Login {
+        user: String,
+        password: Sensitive<String>,
+    }
+
+    #[test]
+    fn seals_sensitive_values_unmasked() {
+        let keyring = keyring();
+        let login = Login {
+            user: "ada".to_string(),
+            password: Sensitive::new("hunter2".to_string()),
+        };
+
+        let sealed = Encrypted::seal(&login, &keyring).unwrap();
+        let opened = sealed.open(&keyring).unwrap().get();
+
+        assert_eq!(opened.password.get(), "hunter2");
+        assert_eq!(Redaction::current(), Redaction::Mask);
+    }
+
+    #[cfg(feature = "audit")]
+    #[test]
+    fn records_keys_exposed_by_callers() {
+        use std::sync::Mutex;
+
+        use crate::lang::audit;
+
+        static LINES: Mutex<Vec<u32>> = Mutex::new(vec![]);
+
+        let keyring = keyring();
+        let _lock = audit::tests::LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        audit::set_exposure_hook(|e| {
+            if e.type_name == "[u8; 32]" {
+                LINES.lock().unwrap().extend(e.location.map(|l| l.line()));
+            }
+        });
+
+        let sealed = (Encrypted::seal(&1u8, &keyring).unwrap(), line!());
+        let opened = (sealed.0.open(&keyring).unwrap(), line!());
+        audit::clear_exposure_hook();
+
+        assert_eq!(opened.0.get(), 1);
+        assert_eq!(*LINES.lock().unwrap(), [sealed.1, opened.1]);
+    }
+
+    #[test]
+    fn rejects_tampered_envelopes() {
+        let keyring = keyring();
+        let sealed = Encrypted::seal(&42u64, &keyring).unwrap().to_string();
+
+        let mut tampered = sealed.clone().into_bytes();
+        let last = tampered.len() - 1;
+        tampered[last] = if tampered[last] == b'0' { b'1' } else { b'0' };
+        let tampered: Encrypted<u64> = String::from_utf8(tampered).unwrap().parse().unwrap();
+
+        assert!(matches!(tampered.open(&keyring), Err(Error::Encryption(_))));
+
+        let swapped: Encrypted<u64> = sealed.replacen("2024-01", "other", 1).parse().unwrap();
+        assert!(matches!(swapped.open(&keyring), Err(Error::UnknownKey(_))));
+
+        for s in ["", "2024-01", ":00", "2024-01:zz", "2024-01:0011"] {
+            assert_eq!(
+                s.parse::<Encrypted<u64>>(),
+                Err(Error::FailedParsing(Kind::Encrypted, s.to_string()))
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrips_values(a in any::<Vec<String>>()) {
+            let keyring = keyring();
+            let sealed = Encrypted::seal(&a, &keyring).expect("Failed sealing");
+            let parsed: Encrypted<Vec<String>> =
+                sealed.to_string().parse().expect("Failed parsing");
+
+            prop_assert_eq!(&parsed, &sealed);
+            prop_assert_eq!(parsed.open(&keyring).expect("Failed opening").get(), a);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::Deref,
+    str::FromStr,
+};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+#[cfg(any(test, feature = "testing"))]
+use crate::lang::Markdown;
+use crate::{lang::Text, Error};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The tags, attributes and URL schemes allowed to survive sanitisation
+///
+/// Everything not listed here is removed. Note that `script` and `style` are always removed
+/// along with their contents, and `rel` is always set to `noopener noreferrer` on links, so they
+/// are ignored when listed here.
+pub struct HtmlAllowList {
+    pub tags: HashSet<&'static str>,
+    /// Attributes allowed on every tag
+    pub generic_attributes: HashSet<&'static str>,
+    /// Attributes allowed only on specific tags
+    pub tag_attributes: HashMap<&'static str, HashSet<&'static str>>,
+    pub url_schemes: HashSet<&'static str>,
+}
+
+impl Default for HtmlAllowList {
+    /// A conservative allow-list, with the tags produced by rendering CommonMark
+    fn default() -> Self {
+        Self {
+            tags: HashSet::from([
+                "a",
+                "blockquote",
+                "br",
+                "code",
+                "del",
+                "em",
+                "h1",
+                "h2",
+                "h3",
+                "h4",
+                "h5",
+                "h6",
+                "hr",
+                "img",
+                "li",
+                "ol",
+                "p",
+                "pre",
+                "strong",
+                "ul",
+            ]),
+            generic_attributes: HashSet::from(["title"]),
+            tag_attributes: HashMap::from([
+                ("a", HashSet::from(["href"])),
+                ("img", HashSet::from(["src", "alt"])),
+                ("ol", HashSet::from(["start"])),
+            ]),
+            url_schemes: HashSet::from(["http", "https", "mailto"]),
+        }
+    }
+}
+
+/// Tags that are always removed along with their contents
+const FORBIDDEN_TAGS: [&str; 2] = ["script", "style"];
+
+/// Attributes set by the sanitiser itself
+const FORBIDDEN_ATTRIBUTES: [&str; 1] = ["rel"];
+
+fn allowed(attributes: &HashSet<&'static str>) -> HashSet<&'static str> {
+    attributes
+        .iter()
+        .filter(|a| !FORBIDDEN_ATTRIBUTES.contains(a))
+        .copied()
+        .collect()
+}
+
+impl HtmlAllowList {
+    pub fn sanitize(&self, html: &str) -> String {
+        let tags = self
+            .tags
+            .iter()
+            .filter(|t| !FORBIDDEN_TAGS.contains(t))
+            .copied()
+            .collect();
+        let tag_attributes = self
+            .tag_attributes
+            .iter()
+            .map(|(tag, attributes)| (*tag, allowed(attributes)))
+            .collect();
+
+        ammonia::Builder::default()
+            .tags(tags)
+            .generic_attributes(allowed(&self.generic_attributes))
+            .tag_attributes(tag_attributes)
+            .url_schemes(self.url_schemes.clone())
+            .clean(html)
+            .to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+/// A fragment of HTML that was sanitised against an `HtmlAllowList`, and so is always safe to
+/// inject into a page
+pub struct SafeHtml(String);
+
+impl SafeHtml {
+    /// Parses and sanitises `html` using the default allow-list
+    pub fn new(html: impl AsRef<str>) -> Self {
+        Self::with_allow_list(html, &HtmlAllowList::default())
+    }
+
+    pub fn with_allow_list(html: impl AsRef<str>, allow_list: &HtmlAllowList) -> Self {
+        Self(allow_list.sanitize(html.as_ref()))
+    }
+
+    /// Escapes plain text, so it is displayed exactly as written instead of being parsed as HTML
+    pub fn escape(text: impl AsRef<str>) -> Self {
+        let mut out = String::with_capacity(text.as_ref().len());
+
+        for c in text.as_ref().chars() {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                '\'' => out.push_str("&#39;"),
+                c => out.push(c),
+            }
+        }
+
+        Self(out)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for SafeHtml {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<Text> for SafeHtml {
+    /// Treats the text as HTML, sanitising it with the default allow-list
+    fn from(text: Text) -> Self {
+        Self::new(&*text)
+    }
+}
+
+impl Display for SafeHtml {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for SafeHtml {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SafeHtml {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for SafeHtml {
+    /// Sanitises the value again, as it might have been tampered with while stored
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        Ok(Self::new(String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for SafeHtml {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<Markdown>().prop_map(|m| m.render()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn removes_scripts_and_handlers() {
+        assert_eq!(
+            SafeHtml::new("<p onclick=\"steal()\">hi<script>steal()</script></p>").as_str(),
+            "<p>hi</p>"
+        );
+        assert_eq!(
+            SafeHtml::new("<a href=\"javascript:steal()\">x</a>").as_str(),
+            "<a rel=\"noopener noreferrer\">x</a>"
+        );
+        assert_eq!(
+            SafeHtml::new("<iframe src=\"https://example.com\"></iframe>ok").as_str(),
+            "ok"
+        );
+    }
+
+    #[test]
+    fn uses_custom_allow_lists() {
+        let allow_list = HtmlAllowList {
+            tags: HashSet::from(["b"]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            SafeHtml::with_allow_list("<b>bold</b> <em>not</em>", &allow_list).as_str(),
+            "<b>bold</b> not"
+        );
+    }
+
+    #[test]
+    fn ignores_forbidden_tags() {
+        let mut allow_list = HtmlAllowList::default();
+        allow_list.tags.extend(["script", "style"]);
+
+        assert_eq!(
+            SafeHtml::with_allow_list(
+                "<p>hi<script>steal()</script><style>*{}</style></p>",
+                &allow_list
+            )
+            .as_str(),
+            "<p>hi</p>"
+        );
+    }
+
+    #[test]
+    fn ignores_forbidden_attributes() {
+        let mut allow_list = HtmlAllowList::default();
+        allow_list.generic_attributes.insert("rel");
+        allow_list
+            .tag_attributes
+            .entry("a")
+            .or_default()
+            .insert("rel");
+
+        assert_eq!(
+            SafeHtml::with_allow_list("<a href=\"https://a.b\" rel=\"opener\">x</a>", &allow_list)
+                .as_str(),
+            "<a href=\"https://a.b\" rel=\"noopener noreferrer\">x</a>"
+        );
+    }
+
+    #[test]
+    fn escapes_plain_text() {
+        assert_eq!(
+            SafeHtml::escape("<b>\"Tom\" & 'Jerry'</b>").as_str(),
+            "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn sanitizing_is_idempotent(a in any::<SafeHtml>()) {
+            prop_assert_eq!(SafeHtml::new(a.as_str()), a);
+        }
+
+        #[test]
+        fn escaped_text_has_no_markup(s in any::<String>()) {
+            let escaped = SafeHtml::escape(&s);
+
+            prop_assert!(!escaped.contains(['<', '>', '"', '\'']));
+        }
+    }
+}
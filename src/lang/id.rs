@@ -1,4 +1,10 @@
-use std::{fmt::Display, marker::PhantomData, str::FromStr};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
 
 use uuid::Uuid;
 
@@ -10,16 +16,113 @@ use proptest::{
 
 use crate::{Error, Kind};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Crockford's base32 alphabet, which is sortable and avoids ambiguous characters
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The length of an id encoded in base32, excluding the prefix
+const ENCODED_LEN: usize = 26;
+
+/// A marker type that declares the human-readable prefix used by `Id<Self>`, like `usr` for users
+pub trait IdPrefix {
+    const PREFIX: &'static str;
+}
+
+/// A marker type that declares the namespace used to derive deterministic ids for `Id<Self>`
+pub trait IdNamespace {
+    const NAMESPACE: Uuid;
+}
+
 /// An unique id pointing to a resource
+///
+/// `T` is only used as a marker, so it can be any type (including uninhabited ones, like `enum
+/// User {}`) and none of the trait implementations here depend on it. That's why ids are always
+/// displayed and parsed as plain UUIDs, and the `usr_01H...` form of a `T: IdPrefix` is written
+/// and read through `Id::prefixed` and `PrefixedId` instead.
 pub struct Id<T> {
     inner: Uuid,
-    _marker: PhantomData<T>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    /// Generates a new random id
+    pub fn generate() -> Self {
+        Uuid::new_v4().into()
+    }
+
+    /// Derives an id from a name inside a namespace, always returning the same id (UUIDv5) for
+    /// the same inputs
+    pub fn from_name(namespace: &Uuid, name: impl AsRef<[u8]>) -> Self {
+        Uuid::new_v5(namespace, name.as_ref()).into()
+    }
+
+    /// Same as `from_name`, but derives a MD5-based UUIDv3, for compatibility with systems that
+    /// already use them
+    pub fn from_name_v3(namespace: &Uuid, name: impl AsRef<[u8]>) -> Self {
+        Uuid::new_v3(namespace, name.as_ref()).into()
+    }
+
+    /// Returns the underlying UUID
+    pub fn uuid(&self) -> Uuid {
+        self.inner
+    }
+
+    /// Retypes this id to point to another kind of resource, keeping the same UUID
+    pub fn cast<U>(self) -> Id<U> {
+        self.inner.into()
+    }
+}
+
+impl<T: IdNamespace> Id<T> {
+    /// Derives an id from a name inside the namespace declared by `T`
+    pub fn named(name: impl AsRef<[u8]>) -> Self {
+        Self::from_name(&T::NAMESPACE, name)
+    }
+}
+
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> Debug for Id<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Id").field(&self.inner).finish()
+    }
+}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
 }
 
 impl<T> FromStr for Id<T> {
     type Err = Error;
 
+    /// Parses a plain UUID, use `PrefixedId` to parse prefixed ids
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(s.parse::<Uuid>()
             .map_err(|_| Error::FailedParsing(Kind::Id, s.to_string()))?
@@ -31,19 +134,158 @@ impl<T> From<Uuid> for Id<T> {
     fn from(inner: Uuid) -> Id<T> {
         Self {
             inner,
-            _marker: Default::default(),
+            _marker: PhantomData,
         }
     }
 }
 
+impl<T> From<Id<T>> for Uuid {
+    fn from(id: Id<T>) -> Uuid {
+        id.inner
+    }
+}
+
 impl<T> Display for Id<T> {
+    /// Writes the plain UUID, use `Id::prefixed` to write prefixed ids
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", &self.inner)
     }
 }
 
+impl<T: IdPrefix> Id<T> {
+    /// The human-readable form of this id, like `usr_01H...`
+    pub fn prefixed(self) -> PrefixedId<T> {
+        PrefixedId(self)
+    }
+}
+
+/// An `Id<T>` written with the prefix declared by `T` followed by the id in Crockford's base32,
+/// like `usr_01H...`
+///
+/// It is displayed, parsed and serialised in that form, while `Id<T>` always uses the plain
+/// UUID. Parsing ignores case, reads `I` and `L` as `1` and `O` as `0`, and rejects ids with
+/// another prefix with `Error::InvalidPrefix`.
+pub struct PrefixedId<T>(Id<T>);
+
+impl<T> PrefixedId<T> {
+    pub fn id(self) -> Id<T> {
+        self.0
+    }
+}
+
+impl<T> Clone for PrefixedId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PrefixedId<T> {}
+
+impl<T> Debug for PrefixedId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PrefixedId").field(&self.0.inner).finish()
+    }
+}
+
+impl<T> PartialEq for PrefixedId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for PrefixedId<T> {}
+
+impl<T> PartialOrd for PrefixedId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for PrefixedId<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> Hash for PrefixedId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T> From<Id<T>> for PrefixedId<T> {
+    fn from(id: Id<T>) -> Self {
+        Self(id)
+    }
+}
+
+impl<T> From<PrefixedId<T>> for Id<T> {
+    fn from(id: PrefixedId<T>) -> Self {
+        id.0
+    }
+}
+
+impl<T: IdPrefix> FromStr for PrefixedId<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (prefix, encoded) = s.rsplit_once('_').unwrap_or(("", s));
+
+        if prefix != T::PREFIX {
+            return Err(Error::InvalidPrefix {
+                kind: Kind::Id,
+                expected: T::PREFIX,
+                found: prefix.to_string(),
+            });
+        }
+
+        decode(encoded)
+            .map(|i| Self(Uuid::from_u128(i).into()))
+            .ok_or_else(|| Error::FailedParsing(Kind::Id, s.to_string()))
+    }
+}
+
+impl<T: IdPrefix> Display for PrefixedId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", T::PREFIX, encode(self.0.inner.as_u128()))
+    }
+}
+
+fn encode(mut value: u128) -> String {
+    let mut out = [0u8; ENCODED_LEN];
+
+    for c in out.iter_mut().rev() {
+        *c = ALPHABET[(value & 0x1f) as usize];
+        value >>= 5;
+    }
+
+    out.iter().map(|&c| c as char).collect()
+}
+
+fn decode(s: &str) -> Option<u128> {
+    if s.len() != ENCODED_LEN {
+        return None;
+    }
+
+    s.bytes().enumerate().try_fold(0u128, |acc, (i, c)| {
+        // Letters that look like digits are read as them, as Crockford's base32 specifies
+        let digit = match c.to_ascii_uppercase() {
+            b'O' => 0,
+            b'I' | b'L' => 1,
+            c => ALPHABET.iter().position(|&a| a == c)? as u128,
+        };
+
+        // The first character only carries the 3 most significant bits
+        if i == 0 && digit > 7 {
+            return None;
+        }
+
+        Some((acc << 5) | digit)
+    })
+}
+
 #[cfg(feature = "serde")]
-impl<T: serde::Serialize> serde::Serialize for Id<T> {
+impl<T> serde::Serialize for Id<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -53,7 +295,7 @@ impl<T: serde::Serialize> serde::Serialize for Id<T> {
 }
 
 #[cfg(feature = "serde")]
-impl<'a, T: serde::Deserialize<'a>> serde::Deserialize<'a> for Id<T> {
+impl<'a, T> serde::Deserialize<'a> for Id<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'a>,
@@ -62,11 +304,40 @@ impl<'a, T: serde::Deserialize<'a>> serde::Deserialize<'a> for Id<T> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: IdPrefix> serde::Serialize for PrefixedId<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: IdPrefix> serde::Deserialize<'a> for PrefixedId<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<T: 'static> Arbitrary for PrefixedId<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<Id<T>>().prop_map(Self).boxed()
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
-impl<T> Arbitrary for Id<T>
-where
-    T: Arbitrary + std::fmt::Debug + Into<Self> + 'static,
-{
+impl<T: 'static> Arbitrary for Id<T> {
     type Parameters = ();
     type Strategy = BoxedStrategy<Self>;
 
@@ -77,3 +348,404 @@ where
             .boxed()
     }
 }
+
+/// The separator between the components of a `CompositeId` in its string representation
+const COMPOSITE_SEPARATOR: char = ':';
+
+/// A key made of two ids, like `(Id<Org>, Id<User>)` for a membership
+///
+/// Both the string (`org_...:usr_...`) and byte representations sort in the same order as the
+/// ids themselves, so they can be used directly as keys on key-value stores.
+pub struct CompositeId<A, B> {
+    first: Id<A>,
+    second: Id<B>,
+}
+
+impl<A, B> CompositeId<A, B> {
+    /// The length of the byte representation of a composite id
+    pub const BYTES_LEN: usize = 32;
+
+    pub fn new(first: Id<A>, second: Id<B>) -> Self {
+        Self { first, second }
+    }
+
+    pub fn first(&self) -> Id<A> {
+        self.first
+    }
+
+    pub fn second(&self) -> Id<B> {
+        self.second
+    }
+
+    /// Returns the big-endian bytes of both ids, which sort lexicographically like the ids do
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+
+        bytes[..16].copy_from_slice(self.first.inner.as_bytes());
+        bytes[16..].copy_from_slice(self.second.inner.as_bytes());
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::BYTES_LEN {
+            return Err(Error::FailedParsing(
+                Kind::CompositeId,
+                format!("expected {} bytes, found {}", Self::BYTES_LEN, bytes.len()),
+            ));
+        }
+
+        let (first, second) = bytes.split_at(16);
+
+        // Both halves are exactly 16 bytes long, so this can never fail
+        Ok(Self::new(
+            Uuid::from_slice(first).expect("Invalid UUID").into(),
+            Uuid::from_slice(second).expect("Invalid UUID").into(),
+        ))
+    }
+}
+
+impl<A, B> Clone for CompositeId<A, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, B> Copy for CompositeId<A, B> {}
+
+impl<A, B> Debug for CompositeId<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CompositeId")
+            .field(&self.first)
+            .field(&self.second)
+            .finish()
+    }
+}
+
+impl<A, B> PartialEq for CompositeId<A, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.first == other.first && self.second == other.second
+    }
+}
+
+impl<A, B> Eq for CompositeId<A, B> {}
+
+impl<A, B> PartialOrd for CompositeId<A, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, B> Ord for CompositeId<A, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.first
+            .cmp(&other.first)
+            .then_with(|| self.second.cmp(&other.second))
+    }
+}
+
+impl<A, B> Hash for CompositeId<A, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.first.hash(state);
+        self.second.hash(state);
+    }
+}
+
+impl<A, B> From<(Id<A>, Id<B>)> for CompositeId<A, B> {
+    fn from((first, second): (Id<A>, Id<B>)) -> Self {
+        Self::new(first, second)
+    }
+}
+
+impl<A, B> From<CompositeId<A, B>> for (Id<A>, Id<B>) {
+    fn from(id: CompositeId<A, B>) -> Self {
+        (id.first, id.second)
+    }
+}
+
+impl<A: IdPrefix, B: IdPrefix> FromStr for CompositeId<A, B> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .split_once(COMPOSITE_SEPARATOR)
+            .ok_or_else(|| Error::FailedParsing(Kind::CompositeId, s.to_string()))?;
+
+        Ok(Self::new(
+            first.parse::<PrefixedId<A>>()?.id(),
+            second.parse::<PrefixedId<B>>()?.id(),
+        ))
+    }
+}
+
+impl<A: IdPrefix, B: IdPrefix> Display for CompositeId<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.first.prefixed(),
+            COMPOSITE_SEPARATOR,
+            self.second.prefixed()
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A: IdPrefix, B: IdPrefix> serde::Serialize for CompositeId<A, B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, A: IdPrefix, B: IdPrefix> serde::Deserialize<'a> for CompositeId<A, B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<A: 'static, B: 'static> Arbitrary for CompositeId<A, B> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<(Id<A>, Id<B>)>().prop_map(Self::from).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    enum User {}
+
+    impl IdPrefix for User {
+        const PREFIX: &'static str = "usr";
+    }
+
+    struct Org;
+
+    impl IdPrefix for Org {
+        const PREFIX: &'static str = "org";
+    }
+
+    impl IdNamespace for Org {
+        const NAMESPACE: Uuid = Uuid::from_u128(0x0f4d2c8e_5b1a_4e7f_9c3d_8a2b6e1f7c90);
+    }
+
+    fn id<T>(i: u128) -> Id<T> {
+        Uuid::from_u128(i).into()
+    }
+
+    #[test]
+    fn displays_with_prefix() {
+        assert_eq!(
+            id::<User>(0).prefixed().to_string(),
+            "usr_00000000000000000000000000"
+        );
+        assert_eq!(
+            id::<User>(u128::MAX).prefixed().to_string(),
+            "usr_7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+    }
+
+    #[test]
+    fn displays_plain_uuids() {
+        enum Unprefixed {}
+
+        let uuid = "886313e1-3b8a-5372-9b90-0c9aee199e5d";
+        let a: Id<Unprefixed> = uuid.parse().unwrap();
+
+        assert_eq!(a.to_string(), uuid);
+        assert_eq!(id::<User>(0).to_string(), Uuid::nil().to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_like_it_displays() {
+        let a = id::<User>(42);
+
+        assert_eq!(serde_json::to_string(&a).unwrap(), format!("\"{a}\""));
+        assert_eq!(
+            serde_json::to_string(&a.prefixed()).unwrap(),
+            format!("\"{}\"", a.prefixed())
+        );
+        assert_eq!(
+            serde_json::from_str::<PrefixedId<User>>(&format!("\"{}\"", a.prefixed())).unwrap(),
+            a.prefixed()
+        );
+    }
+
+    #[test]
+    fn parses_ambiguous_characters() {
+        assert_eq!(
+            "usr_0000000000000000000000000l".parse(),
+            Ok(id::<User>(1).prefixed())
+        );
+        assert_eq!(
+            "usr_oooooooooooooooooooooooooI".parse(),
+            Ok(id::<User>(1).prefixed())
+        );
+        assert_eq!(
+            "usr_0000000000000000000000001L".parse(),
+            Ok(id::<User>(33).prefixed())
+        );
+        assert!("usr_0000000000000000000000000U"
+            .parse::<PrefixedId<User>>()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_overflowing_ids() {
+        assert_eq!(
+            "usr_8ZZZZZZZZZZZZZZZZZZZZZZZZZ".parse::<PrefixedId<User>>(),
+            Err(Error::FailedParsing(
+                Kind::Id,
+                "usr_8ZZZZZZZZZZZZZZZZZZZZZZZZZ".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn derives_ids_from_names() {
+        assert_eq!(
+            Id::<User>::from_name(&Uuid::NAMESPACE_DNS, "python.org").uuid(),
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d"
+                .parse::<Uuid>()
+                .unwrap()
+        );
+        assert_eq!(
+            Id::<User>::from_name_v3(&Uuid::NAMESPACE_DNS, "python.org").uuid(),
+            "6fa459ea-ee8a-3ca4-894e-db77e160355e"
+                .parse::<Uuid>()
+                .unwrap()
+        );
+        assert_eq!(
+            Id::<Org>::named([]).uuid(),
+            Uuid::new_v5(&Org::NAMESPACE, &[])
+        );
+        assert_ne!(Id::<Org>::named([]), Id::<Org>::named([0]));
+    }
+
+    proptest! {
+        #[test]
+        fn named_ids_are_deterministic(name in any::<Vec<u8>>()) {
+            prop_assert_eq!(Id::<Org>::named(&name), Id::<Org>::named(&name));
+            prop_assert_eq!(
+                Id::<Org>::named(&name),
+                Id::<Org>::from_name(&Org::NAMESPACE, &name)
+            );
+            prop_assert_eq!(Id::<Org>::named(&name).uuid().get_version_num(), 5);
+        }
+
+        #[test]
+        fn named_ids_depend_on_the_namespace(name in any::<Vec<u8>>()) {
+            prop_assert_ne!(
+                Id::<Org>::named(&name),
+                Id::<Org>::from_name(&Uuid::NAMESPACE_DNS, &name)
+            );
+        }
+
+        #[test]
+        fn roundtrips_through_strings(a in any::<Id<User>>()) {
+            prop_assert_eq!(a.to_string().parse::<Id<User>>(), Ok(a));
+        }
+
+        #[test]
+        fn roundtrips_through_prefixed_strings(i in any::<u128>()) {
+            let a = id::<User>(i).prefixed();
+            prop_assert_eq!(a.to_string().parse::<PrefixedId<User>>(), Ok(a));
+            prop_assert_eq!(a.to_string().to_lowercase().parse::<PrefixedId<User>>(), Ok(a));
+        }
+
+        #[test]
+        fn preserves_order_when_prefixed(a in any::<u128>(), b in any::<u128>()) {
+            prop_assert_eq!(
+                id::<User>(a).prefixed().to_string().cmp(&id::<User>(b).prefixed().to_string()),
+                a.cmp(&b)
+            );
+        }
+
+        #[test]
+        fn rejects_wrong_prefixes(i in any::<u128>()) {
+            let s = id::<Org>(i).prefixed().to_string();
+
+            prop_assert_eq!(
+                s.parse::<PrefixedId<User>>(),
+                Err(Error::InvalidPrefix {
+                    kind: Kind::Id,
+                    expected: "usr",
+                    found: "org".to_string(),
+                })
+            );
+        }
+
+        #[test]
+        fn works_with_any_marker(a in any::<Id<User>>(), b in any::<Id<Org>>()) {
+            let c = a;
+
+            prop_assert_eq!(a, c);
+            prop_assert_eq!(a.cmp(&c), Ordering::Equal);
+            prop_assert_eq!(format!("{:?}", b), format!("Id({:?})", b.uuid()));
+        }
+
+        #[test]
+        fn preserves_order(a in any::<u128>(), b in any::<u128>()) {
+            prop_assert_eq!(id::<User>(a).cmp(&id::<User>(b)), a.cmp(&b));
+        }
+
+        #[test]
+        fn casts_between_markers(a in any::<Id<User>>()) {
+            prop_assert_eq!(a.cast::<Org>().uuid(), a.uuid());
+            prop_assert_eq!(a.cast::<Org>().cast::<User>(), a);
+        }
+
+        #[test]
+        fn composite_roundtrips_through_strings(a in any::<CompositeId<Org, User>>()) {
+            prop_assert_eq!(a.to_string().parse::<CompositeId<Org, User>>(), Ok(a));
+        }
+
+        #[test]
+        fn composite_roundtrips_through_bytes(a in any::<CompositeId<Org, User>>()) {
+            prop_assert_eq!(CompositeId::<Org, User>::from_bytes(&a.to_bytes()), Ok(a));
+        }
+
+        #[test]
+        fn composite_representations_preserve_order(
+            a in any::<CompositeId<Org, User>>(),
+            b in any::<CompositeId<Org, User>>(),
+        ) {
+            prop_assert_eq!(a.to_bytes().cmp(&b.to_bytes()), a.cmp(&b));
+            prop_assert_eq!(a.to_string().cmp(&b.to_string()), a.cmp(&b));
+        }
+
+        #[test]
+        fn composite_rejects_swapped_components(a in any::<CompositeId<Org, User>>()) {
+            let swapped = format!("{}:{}", a.second().prefixed(), a.first().prefixed());
+
+            prop_assert!(swapped.parse::<CompositeId<Org, User>>().is_err());
+        }
+
+        #[test]
+        fn composite_rejects_wrong_byte_lengths(bytes in any::<Vec<u8>>()) {
+            prop_assume!(bytes.len() != 32);
+            prop_assert!(CompositeId::<Org, User>::from_bytes(&bytes).is_err());
+        }
+
+        #[test]
+        fn prefixed_ids_reject_plain_uuids(i in any::<u128>()) {
+            prop_assert!(Uuid::from_u128(i).to_string().parse::<PrefixedId<User>>().is_err());
+        }
+    }
+}
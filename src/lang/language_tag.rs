@@ -0,0 +1,446 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{Error, Kind};
+
+/// Deprecated language subtags and their preferred replacements
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("in", "id"),
+    ("iw", "he"),
+    ("ji", "yi"),
+    ("jw", "jv"),
+    ("mo", "ro"),
+];
+
+/// Grandfathered tags, which don't follow the subtag grammar, and their preferred values, if any
+const GRANDFATHERED: &[(&str, Option<&str>)] = &[
+    ("art-lojban", Some("jbo")),
+    ("cel-gaulish", None),
+    ("en-GB-oed", Some("en-GB-oxendict")),
+    ("i-ami", Some("ami")),
+    ("i-bnn", Some("bnn")),
+    ("i-default", None),
+    ("i-enochian", None),
+    ("i-hak", Some("hak")),
+    ("i-klingon", Some("tlh")),
+    ("i-lux", Some("lb")),
+    ("i-mingo", None),
+    ("i-navajo", Some("nv")),
+    ("i-pwn", Some("pwn")),
+    ("i-tao", Some("tao")),
+    ("i-tay", Some("tay")),
+    ("i-tsu", Some("tsu")),
+    ("no-bok", Some("nb")),
+    ("no-nyn", Some("nn")),
+    ("sgn-BE-FR", Some("sfb")),
+    ("sgn-BE-NL", Some("vgt")),
+    ("sgn-CH-DE", Some("sgg")),
+    ("zh-guoyu", Some("cmn")),
+    ("zh-hakka", Some("hak")),
+    ("zh-min", None),
+    ("zh-min-nan", Some("nan")),
+    ("zh-xiang", Some("hsn")),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// A BCP 47 language tag, like `pt-BR` or `zh-Hant-TW-u-ca-chinese`, stored in canonical form
+///
+/// Canonicalisation normalises the case of every subtag (`EN-latn-us` becomes `en-Latn-US`),
+/// replaces deprecated languages and extended language subtags (`zh-yue` becomes `yue`) and sorts
+/// extensions by their singleton. Both `-` and `_` are accepted as separators when parsing.
+/// Grandfathered tags are replaced by their preferred value (`i-klingon` becomes `tlh`), and the
+/// ones without a preferred value, like `i-default`, are rejected.
+pub struct LanguageTag {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+    extensions: Vec<(char, Vec<String>)>,
+    private_use: Vec<String>,
+}
+
+impl LanguageTag {
+    /// The primary language subtag, like `pt`
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The script subtag, like `Hant`
+    pub fn script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    /// The region subtag, like `BR` or `419`
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    pub fn variants(&self) -> &[String] {
+        &self.variants
+    }
+
+    /// The subtags of the extension introduced by `singleton`, like `u` for Unicode extensions
+    pub fn extension(&self, singleton: char) -> Option<&[String]> {
+        let singleton = singleton.to_ascii_lowercase();
+
+        self.extensions
+            .iter()
+            .find(|(s, _)| *s == singleton)
+            .map(|(_, subtags)| subtags.as_slice())
+    }
+
+    pub fn private_use(&self) -> &[String] {
+        &self.private_use
+    }
+
+    /// Returns this tag without its extensions and private use subtags
+    pub fn without_extensions(&self) -> Self {
+        Self {
+            extensions: vec![],
+            private_use: vec![],
+            ..self.clone()
+        }
+    }
+
+    /// Returns this tag without its most specific subtag, or `None` if only the language is left
+    ///
+    /// Extensions go first, then variants, the region and finally the script, so `zh-Hant-TW`
+    /// becomes `zh-Hant` and then `zh`.
+    pub fn parent(&self) -> Option<Self> {
+        let mut parent = self.clone();
+
+        if !parent.extensions.is_empty() || !parent.private_use.is_empty() {
+            parent = parent.without_extensions();
+        } else if !parent.variants.is_empty() {
+            parent.variants.clear();
+        } else if parent.region.is_some() {
+            parent.region = None;
+        } else if parent.script.is_some() {
+            parent.script = None;
+        } else {
+            return None;
+        }
+
+        Some(parent)
+    }
+}
+
+fn is_alpha(s: &str, len: impl std::ops::RangeBounds<usize>) -> bool {
+    len.contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alphanumeric(s: &str, len: impl std::ops::RangeBounds<usize>) -> bool {
+    len.contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_variant(s: &str) -> bool {
+    is_alphanumeric(s, 5..=8) || (is_alphanumeric(s, 4..=4) && s.as_bytes()[0].is_ascii_digit())
+}
+
+fn title_case(s: &str) -> String {
+    let (first, rest) = s.split_at(1);
+
+    first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+}
+
+impl FromStr for LanguageTag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fail = || Error::FailedParsing(Kind::LanguageTag, s.to_string());
+
+        if let Some((_, preferred)) = GRANDFATHERED
+            .iter()
+            .find(|(t, _)| t.eq_ignore_ascii_case(&s.replace('_', "-")))
+        {
+            return preferred.ok_or_else(fail)?.parse();
+        }
+
+        let mut subtags = s.split(['-', '_']).peekable();
+
+        let mut language = subtags
+            .next()
+            .filter(|l| is_alpha(l, 2..=3) || is_alpha(l, 5..=8))
+            .ok_or_else(fail)?
+            .to_ascii_lowercase();
+
+        if language.len() <= 3 {
+            let mut extlangs = 0;
+
+            while let Some(extlang) = subtags.next_if(|e| is_alpha(e, 3..=3)) {
+                // The extended language subtag is the canonical primary language
+                if extlangs == 0 {
+                    language = extlang.to_ascii_lowercase();
+                }
+
+                extlangs += 1;
+            }
+
+            if extlangs > 3 {
+                return Err(fail());
+            }
+        }
+
+        if let Some((_, preferred)) = LANGUAGE_ALIASES.iter().find(|(l, _)| *l == language) {
+            language = preferred.to_string();
+        }
+
+        let script = subtags.next_if(|s| is_alpha(s, 4..=4)).map(title_case);
+
+        let region = subtags
+            .next_if(|r| {
+                is_alpha(r, 2..=2) || (r.len() == 3 && r.bytes().all(|b| b.is_ascii_digit()))
+            })
+            .map(|r| r.to_ascii_uppercase());
+
+        let mut variants = vec![];
+
+        while let Some(variant) = subtags.next_if(|v| is_variant(v)) {
+            let variant = variant.to_ascii_lowercase();
+
+            if variants.contains(&variant) {
+                return Err(fail());
+            }
+
+            variants.push(variant);
+        }
+
+        let mut extensions: Vec<(char, Vec<String>)> = vec![];
+
+        while let Some(singleton) =
+            subtags.next_if(|s| is_alphanumeric(s, 1..=1) && !s.eq_ignore_ascii_case("x"))
+        {
+            let singleton = singleton.as_bytes()[0].to_ascii_lowercase() as char;
+            let mut values = vec![];
+
+            while let Some(value) = subtags.next_if(|v| is_alphanumeric(v, 2..=8)) {
+                values.push(value.to_ascii_lowercase());
+            }
+
+            if values.is_empty() || extensions.iter().any(|(s, _)| *s == singleton) {
+                return Err(fail());
+            }
+
+            extensions.push((singleton, values));
+        }
+
+        extensions.sort();
+
+        let mut private_use = vec![];
+
+        if subtags.next_if(|x| x.eq_ignore_ascii_case("x")).is_some() {
+            while let Some(value) = subtags.next_if(|v| is_alphanumeric(v, 1..=8)) {
+                private_use.push(value.to_ascii_lowercase());
+            }
+
+            if private_use.is_empty() {
+                return Err(fail());
+            }
+        }
+
+        if subtags.next().is_some() {
+            return Err(fail());
+        }
+
+        Ok(Self {
+            language,
+            script,
+            region,
+            variants,
+            extensions,
+            private_use,
+        })
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.language)?;
+
+        for subtag in self.script.iter().chain(&self.region).chain(&self.variants) {
+            write!(f, "-{}", subtag)?;
+        }
+
+        for (singleton, values) in &self.extensions {
+            write!(f, "-{}-{}", singleton, values.join("-"))?;
+        }
+
+        if !self.private_use.is_empty() {
+            write!(f, "-x-{}", self.private_use.join("-"))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LanguageTag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for LanguageTag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for LanguageTag {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            select(
+                &[
+                    "en", "pt", "de", "fr", "es", "ja", "zh", "ar", "ru", "hi", "yue",
+                ][..],
+            ),
+            select(&[None, Some("Latn"), Some("Hans"), Some("Hant"), Some("Cyrl")][..]),
+            select(
+                &[
+                    None,
+                    Some("US"),
+                    Some("BR"),
+                    Some("PT"),
+                    Some("DE"),
+                    Some("TW"),
+                    Some("419"),
+                ][..],
+            ),
+            select(&[None, Some("1996"), Some("valencia")][..]),
+            select(&[None, Some("u-ca-gregory"), Some("t-en"), Some("u-nu-latn")][..]),
+            select(&[None, Some("x-test")][..]),
+        )
+            .prop_map(
+                |(language, script, region, variant, extension, private_use)| {
+                    [
+                        Some(language),
+                        script,
+                        region,
+                        variant,
+                        extension,
+                        private_use,
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join("-")
+                },
+            )
+            .prop_map(|s| s.parse().expect("Generated an invalid language tag"))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn tag(s: &str) -> LanguageTag {
+        s.parse().expect("Failed parsing")
+    }
+
+    #[test]
+    fn parses_all_subtags() {
+        let t = tag("sl-Latn-IT-rozaj-1994-u-ca-gregory-a-foo-x-private");
+
+        assert_eq!(t.language(), "sl");
+        assert_eq!(t.script(), Some("Latn"));
+        assert_eq!(t.region(), Some("IT"));
+        assert_eq!(t.variants(), ["rozaj", "1994"]);
+        assert_eq!(
+            t.extension('u'),
+            Some(&["ca".to_string(), "gregory".to_string()][..])
+        );
+        assert_eq!(t.extension('a'), Some(&["foo".to_string()][..]));
+        assert_eq!(t.private_use(), ["private"]);
+    }
+
+    #[test]
+    fn canonicalizes_tags() {
+        assert_eq!(tag("EN-latn-us").to_string(), "en-Latn-US");
+        assert_eq!(tag("pt_br").to_string(), "pt-BR");
+        assert_eq!(tag("zh-yue-HK").to_string(), "yue-HK");
+        assert_eq!(tag("iw-IL").to_string(), "he-IL");
+        assert_eq!(tag("es-419").to_string(), "es-419");
+        assert_eq!(tag("i-klingon").to_string(), "tlh");
+        assert_eq!(tag("en-gb-oed").to_string(), "en-GB-oxendict");
+        assert_eq!(tag("zh_min_nan").to_string(), "nan");
+        assert_eq!(tag("art-lojban").to_string(), "jbo");
+        assert_eq!(
+            tag("en-u-ca-gregory-a-foo").to_string(),
+            "en-a-foo-u-ca-gregory"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_tags() {
+        for s in [
+            "",
+            "e",
+            "en-",
+            "en--US",
+            "abcd",
+            "en-US-US",
+            "en-a",
+            "en-u-ca-u-nu",
+            "en-x",
+            "en-1996-1996",
+            "toolonglanguage",
+            "i-default",
+            "zh-min",
+        ] {
+            assert_eq!(
+                s.parse::<LanguageTag>(),
+                Err(Error::FailedParsing(Kind::LanguageTag, s.to_string())),
+                "{s} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn walks_up_to_parents() {
+        let parents: Vec<String> =
+            std::iter::successors(Some(tag("zh-Hant-TW-u-nu-hanidec")), |t| t.parent())
+                .map(|t| t.to_string())
+                .collect();
+
+        assert_eq!(
+            parents,
+            ["zh-Hant-TW-u-nu-hanidec", "zh-Hant-TW", "zh-Hant", "zh"]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_tags_are_always_valid(a in any::<LanguageTag>()) {
+            prop_assert_eq!(a.to_string().parse::<LanguageTag>(), Ok(a));
+        }
+
+        #[test]
+        fn parsing_is_case_insensitive(a in any::<LanguageTag>()) {
+            prop_assert_eq!(a.to_string().to_uppercase().parse::<LanguageTag>(), Ok(a.clone()));
+            prop_assert_eq!(a.to_string().to_lowercase().parse::<LanguageTag>(), Ok(a));
+        }
+    }
+}
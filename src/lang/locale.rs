@@ -0,0 +1,186 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{lang::LanguageTag, Error, Kind};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+/// A locale for i18n, made of the language, script, region and variants of a `LanguageTag`
+///
+/// Extensions and private use subtags don't identify a locale by themselves, so both parsing and
+/// deserialising reject them, and keys like `en` and `en-x-foo` can't collide. Converting from a
+/// `LanguageTag` drops them instead.
+pub struct Locale(LanguageTag);
+
+impl Locale {
+    pub fn language(&self) -> &str {
+        self.0.language()
+    }
+
+    pub fn script(&self) -> Option<&str> {
+        self.0.script()
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.0.region()
+    }
+
+    pub fn as_tag(&self) -> &LanguageTag {
+        &self.0
+    }
+
+    /// Returns the next, less specific, locale to try when this one is not available
+    pub fn parent(&self) -> Option<Self> {
+        self.0.parent().map(Self)
+    }
+
+    /// Returns this locale followed by all of its parents, like `pt-BR` and then `pt`
+    pub fn fallbacks(&self) -> impl Iterator<Item = Locale> {
+        std::iter::successors(Some(self.clone()), |l| l.parent())
+    }
+
+    /// Returns the locales to try, in order, ending with `default` if it is not already there
+    pub fn fallback_chain(&self, default: &Locale) -> Vec<Locale> {
+        let mut chain: Vec<_> = self.fallbacks().collect();
+
+        if !chain.contains(default) {
+            chain.push(default.clone());
+        }
+
+        chain
+    }
+}
+
+impl From<LanguageTag> for Locale {
+    fn from(tag: LanguageTag) -> Self {
+        Self(tag.without_extensions())
+    }
+}
+
+impl From<Locale> for LanguageTag {
+    fn from(locale: Locale) -> Self {
+        locale.0
+    }
+}
+
+impl FromStr for Locale {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag: LanguageTag = s.parse()?;
+
+        if tag.without_extensions() == tag {
+            Ok(Self(tag))
+        } else {
+            Err(Error::FailedParsing(Kind::LanguageTag, s.to_string()))
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for Locale {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<LanguageTag>().prop_map(Self::from).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn locale(s: &str) -> Locale {
+        s.parse().expect("Failed parsing")
+    }
+
+    #[test]
+    fn rejects_extensions() {
+        for s in ["de-DE-u-co-phonebk", "en-x-foo"] {
+            assert_eq!(
+                s.parse::<Locale>(),
+                Err(Error::FailedParsing(Kind::LanguageTag, s.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn drops_extensions_from_tags() {
+        let tag: LanguageTag = "de-DE-u-co-phonebk-x-foo".parse().expect("Failed parsing");
+
+        assert_eq!(Locale::from(tag), locale("de-DE"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn rejects_extensions_when_deserializing() {
+        assert_eq!(
+            serde_json::from_str::<Locale>(r#""de-DE""#).unwrap(),
+            locale("de-DE")
+        );
+        assert!(serde_json::from_str::<Locale>(r#""de-DE-u-co-phonebk""#).is_err());
+        assert!(serde_json::from_str::<Locale>(r#""en-x-foo""#).is_err());
+    }
+
+    #[test]
+    fn builds_fallback_chains() {
+        assert_eq!(
+            locale("pt-BR").fallback_chain(&locale("en")),
+            [locale("pt-BR"), locale("pt"), locale("en")]
+        );
+        assert_eq!(
+            locale("en-Latn-US").fallback_chain(&locale("en")),
+            [locale("en-Latn-US"), locale("en-Latn"), locale("en")]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_locales_are_always_valid(a in any::<Locale>()) {
+            prop_assert_eq!(a.to_string().parse::<Locale>(), Ok(a));
+        }
+
+        #[test]
+        fn fallbacks_end_with_the_language(a in any::<Locale>()) {
+            let last = a.fallbacks().last().expect("Empty fallbacks");
+
+            prop_assert_eq!(last.to_string(), a.language());
+        }
+    }
+}
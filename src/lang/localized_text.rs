@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "arrow")]
+use arrow2::datatypes::DataType;
+#[cfg(feature = "arrow")]
+use arrow2_convert::{
+    deserialize::ArrowDeserialize, field::ArrowField, serialize::ArrowSerialize, ArrowDeserialize,
+    ArrowField, ArrowSerialize,
+};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::lang::{Locale, Text};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+/// A piece of text translated into several locales, serialised as an object keyed by language
+/// tag (like `{"en": "Shoes", "pt-BR": "Sapatos"}`)
+pub struct LocalizedText(BTreeMap<Locale, Text>);
+
+impl LocalizedText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the translation for `locale`, returning the previous one
+    pub fn insert(&mut self, locale: Locale, text: Text) -> Option<Text> {
+        self.0.insert(locale, text)
+    }
+
+    pub fn remove(&mut self, locale: &Locale) -> Option<Text> {
+        self.0.remove(locale)
+    }
+
+    /// Returns the translation for exactly `locale`, without any fallback
+    pub fn get(&self, locale: &Locale) -> Option<&Text> {
+        self.0.get(locale)
+    }
+
+    /// Returns the translation for the most specific locale available among `locale` and its
+    /// fallbacks, so a `pt-BR` lookup returns the `pt` translation if there is no `pt-BR` one
+    pub fn lookup(&self, locale: &Locale) -> Option<&Text> {
+        locale.fallbacks().find_map(|l| self.0.get(&l))
+    }
+
+    /// Same as `lookup`, but tries `default` when none of the fallbacks are available
+    pub fn lookup_or(&self, locale: &Locale, default: &Locale) -> Option<&Text> {
+        self.lookup(locale).or_else(|| self.0.get(default))
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.0.keys()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Locale, &Text)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(Locale, Text)> for LocalizedText {
+    fn from_iter<I: IntoIterator<Item = (Locale, Text)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for LocalizedText {
+    type Item = (Locale, Text);
+    type IntoIter = std::collections::btree_map::IntoIter<Locale, Text>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// A single translation, stored as an element of the list of structs that `LocalizedText` is
+/// represented as on arrow, sorted by locale
+#[cfg(feature = "arrow")]
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+pub struct LocalizedEntry {
+    pub locale: String,
+    pub text: String,
+}
+
+#[cfg(feature = "arrow")]
+impl ArrowField for LocalizedText {
+    type Type = Self;
+
+    fn data_type() -> DataType {
+        <Vec<LocalizedEntry> as ArrowField>::data_type()
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl ArrowSerialize for LocalizedText {
+    type MutableArrayType = <Vec<LocalizedEntry> as ArrowSerialize>::MutableArrayType;
+
+    fn new_array() -> Self::MutableArrayType {
+        <Vec<LocalizedEntry> as ArrowSerialize>::new_array()
+    }
+
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        let entries: Vec<_> = v
+            .iter()
+            .map(|(locale, text)| LocalizedEntry {
+                locale: locale.to_string(),
+                text: text.to_string(),
+            })
+            .collect();
+
+        <Vec<LocalizedEntry> as ArrowSerialize>::arrow_serialize(&entries, array)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl ArrowDeserialize for LocalizedText {
+    type ArrayType = <Vec<LocalizedEntry> as ArrowDeserialize>::ArrayType;
+
+    /// Returns `None` if any of the stored locales is not a valid language tag or has extensions,
+    /// like deserializing with serde does
+    fn arrow_deserialize(
+        v: <&Self::ArrayType as IntoIterator>::Item,
+    ) -> Option<<Self as ArrowField>::Type> {
+        <Vec<LocalizedEntry> as ArrowDeserialize>::arrow_deserialize(v)?
+            .into_iter()
+            .map(|e| Some((e.locale.parse().ok()?, e.text.into())))
+            .collect()
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for LocalizedText {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(any::<Locale>(), any::<Text>(), 1..5)
+            .prop_map(Self)
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn locale(s: &str) -> Locale {
+        s.parse().expect("Failed parsing")
+    }
+
+    fn text(s: &str) -> Text {
+        s.parse().expect("Failed parsing")
+    }
+
+    fn shoes() -> LocalizedText {
+        [
+            (locale("en"), text("Shoes")),
+            (locale("pt"), text("Sapatos")),
+            (locale("pt-PT"), text("Sapatos (PT)")),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn looks_up_with_fallbacks() {
+        let t = shoes();
+
+        assert_eq!(t.get(&locale("pt-BR")), None);
+        assert_eq!(t.lookup(&locale("pt-BR")), Some(&text("Sapatos")));
+        assert_eq!(t.lookup(&locale("pt-PT")), Some(&text("Sapatos (PT)")));
+        assert_eq!(t.lookup(&locale("de-DE")), None);
+        assert_eq!(
+            t.lookup_or(&locale("de-DE"), &locale("en")),
+            Some(&text("Shoes"))
+        );
+
+        // Variants are dropped all at once, so a variant is never the fallback of another one
+        let t: LocalizedText = [(locale("en-1996"), text(""))].into_iter().collect();
+
+        assert_eq!(t.lookup(&locale("en-1996")), Some(&text("")));
+        assert_eq!(t.lookup(&locale("en-1996-fonipa")), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_as_an_object() {
+        let json = r#"{"en":"Shoes","pt":"Sapatos","pt-PT":"Sapatos (PT)"}"#;
+
+        assert_eq!(serde_json::to_string(&shoes()).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<LocalizedText>(json).unwrap(),
+            shoes()
+        );
+        assert!(serde_json::from_str::<LocalizedText>(r#"{"en":"a","en-x-foo":"b"}"#).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn finds_every_stored_locale(a in any::<LocalizedText>()) {
+            for (l, t) in a.iter() {
+                prop_assert_eq!(a.lookup(l), Some(t));
+            }
+        }
+
+        #[test]
+        fn finds_parents_of_stored_locales(a in any::<LocalizedText>()) {
+            // Variants are all dropped at once, so only locales without them are parents
+            for l in a.locales().filter(|l| l.as_tag().variants().is_empty()) {
+                let child: Locale = format!("{}-fonipa", l).parse().unwrap();
+
+                prop_assert!(a.lookup(&child).is_some());
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    proptest! {
+        #[test]
+        fn roundtrips_through_json(a in any::<LocalizedText>()) {
+            let json = serde_json::to_string(&a).expect("Failed serializing");
+
+            prop_assert!(json.starts_with('{'), "{}", json);
+            prop_assert_eq!(serde_json::from_str::<LocalizedText>(&json).ok(), Some(a));
+        }
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn rejects_extensions_from_arrow() {
+        use arrow2::array::Array;
+        use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+        let entries = |locale: &str| {
+            vec![vec![LocalizedEntry {
+                locale: locale.to_string(),
+                text: "Hallo".to_string(),
+            }]]
+        };
+
+        let array: Box<dyn Array> = entries("de-DE")
+            .try_into_arrow()
+            .expect("Failed serializing");
+        let texts: Vec<LocalizedText> = array.try_into_collection().expect("Failed deserializing");
+        assert_eq!(texts[0].get(&locale("de-DE")), Some(&text("Hallo")));
+
+        let array: Box<dyn Array> = entries("de-DE-u-co-phonebk")
+            .try_into_arrow()
+            .expect("Failed serializing");
+        let texts: Vec<Option<LocalizedText>> =
+            array.try_into_collection().expect("Failed deserializing");
+        assert_eq!(texts, [None]);
+    }
+
+    #[cfg(feature = "arrow")]
+    proptest! {
+        #[test]
+        fn roundtrips_through_arrow(a in proptest::collection::vec(any::<LocalizedText>(), 0..5)) {
+            use arrow2::array::Array;
+            use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+            let array: Box<dyn Array> = a.try_into_arrow().expect("Failed serializing");
+            let b: Vec<LocalizedText> = array.try_into_collection().expect("Failed deserializing");
+
+            prop_assert_eq!(a, b);
+        }
+    }
+}
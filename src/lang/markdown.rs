@@ -0,0 +1,197 @@
+use std::{fmt::Display, str::FromStr};
+
+use pulldown_cmark::{html, Options, Parser};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+#[cfg(any(test, feature = "testing"))]
+use crate::testing::Rng;
+
+use crate::{
+    lang::{SafeHtml, Sanitizer, Text},
+    Error, Kind,
+};
+
+/// The default maximum length of a `Markdown` document, in chars
+pub const DEFAULT_MARKDOWN_LEN: usize = 65_536;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+/// A CommonMark document with at most `MAX` chars
+///
+/// The source is sanitised (without collapsing whitespace, which is meaningful in Markdown) when
+/// created. `Display` renders it to `SafeHtml`, so it is always safe to inject into a page; use
+/// `as_str` to get the source back.
+pub struct Markdown<const MAX: usize = DEFAULT_MARKDOWN_LEN>(String);
+
+impl<const MAX: usize> Markdown<MAX> {
+    pub fn new(source: impl AsRef<str>) -> Result<Self, Error> {
+        let sanitizer = Sanitizer {
+            collapse_whitespace: false,
+            ..Default::default()
+        };
+
+        let source = sanitizer.sanitize(source.as_ref());
+        let actual = source.chars().count();
+
+        if actual <= MAX {
+            Ok(Self(source))
+        } else {
+            Err(Error::InvalidLength {
+                kind: Kind::Markdown,
+                actual,
+                min: 0,
+                max: MAX,
+            })
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Renders this document into HTML, sanitising any raw HTML it contains
+    pub fn render(&self) -> SafeHtml {
+        let parser = Parser::new_ext(&self.0, Options::ENABLE_STRIKETHROUGH);
+        let mut out = String::with_capacity(self.0.len());
+
+        html::push_html(&mut out, parser);
+
+        SafeHtml::new(out)
+    }
+}
+
+impl<const MAX: usize> FromStr for Markdown<MAX> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<const MAX: usize> TryFrom<Text> for Markdown<MAX> {
+    type Error = Error;
+
+    fn try_from(text: Text) -> Result<Self, Self::Error> {
+        Self::new(&*text)
+    }
+}
+
+impl<const MAX: usize> Display for Markdown<MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const MAX: usize> serde::Serialize for Markdown<MAX> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, const MAX: usize> serde::Deserialize<'a> for Markdown<MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        Self::new(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<const MAX: usize> Arbitrary for Markdown<MAX> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::vec((0..6u8, any::<Rng>(), 1..12usize), 1..6)
+            .prop_map(|blocks| {
+                blocks
+                    .into_iter()
+                    .map(|(kind, rng, size)| {
+                        let words = lipsum::lipsum_words_with_rng(rng.0, size);
+
+                        match kind {
+                            0 => format!("# {}", words),
+                            1 => format!("**{}** and _{}_", words, words),
+                            2 => format!("- {}\n- {}", words, words),
+                            3 => format!("> {}", words),
+                            4 => format!("`{}` ~~{}~~", words, words),
+                            _ => words,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            })
+            .prop_map(|s| s.chars().take(MAX).collect::<String>())
+            .prop_map(|s| Self::new(s).expect("Generated markdown is too long"))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn renders_to_html() {
+        let m: Markdown = "# Title\n\nSome **bold** text".parse().unwrap();
+
+        assert_eq!(
+            m.to_string(),
+            "<h1>Title</h1>\n<p>Some <strong>bold</strong> text</p>\n"
+        );
+    }
+
+    #[test]
+    fn sanitizes_raw_html() {
+        let m: Markdown = "Hi <script>alert(1)</script><b onclick=\"x()\">there</b>"
+            .parse()
+            .unwrap();
+
+        assert_eq!(m.to_string(), "<p>Hi there</p>\n");
+    }
+
+    #[test]
+    fn rejects_long_documents() {
+        assert_eq!(
+            "123456".parse::<Markdown<5>>(),
+            Err(Error::InvalidLength {
+                kind: Kind::Markdown,
+                actual: 6,
+                min: 0,
+                max: 5
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_markdown_is_always_valid(a in any::<Markdown>()) {
+            prop_assert_eq!(a.as_str().parse::<Markdown>(), Ok(a));
+        }
+
+        #[test]
+        fn arbitrary_short_markdown_respects_limit(a in any::<Markdown<20>>()) {
+            prop_assert!(a.as_str().chars().count() <= 20);
+        }
+
+        #[test]
+        fn rendered_markdown_is_sanitized(a in any::<Markdown>()) {
+            let rendered = a.render();
+
+            prop_assert_eq!(SafeHtml::new(rendered.as_str()), rendered);
+        }
+    }
+}
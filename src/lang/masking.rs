@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+use crate::lang::sensitive::MASK;
+
+/// How a `Sensitive` value is displayed, revealing as much of it as is safe for its type
+pub trait MaskingStrategy<T: ?Sized> {
+    fn mask(value: &T) -> String;
+}
+
+/// How a type masks itself inside a `Sensitive<T, ByType>`, which is fully masked unless the type
+/// overrides `mask`, like `Email`
+pub trait Masked {
+    fn mask(&self) -> String {
+        MASK.to_string()
+    }
+}
+
+impl<T: Masked + ?Sized> Masked for Box<T> {
+    fn mask(&self) -> String {
+        (**self).mask()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: Masked + zeroize::Zeroize> Masked for zeroize::Zeroizing<T> {
+    fn mask(&self) -> String {
+        (**self).mask()
+    }
+}
+
+/// Masks values the way their type does through `Masked`, like `Sensitive<Email, ByType>`
+pub enum ByType {}
+
+impl<T: Masked + ?Sized> MaskingStrategy<T> for ByType {
+    fn mask(value: &T) -> String {
+        value.mask()
+    }
+}
+
+/// Hides the whole value behind `MASK`, which is the default for every type
+pub enum FullMask {}
+
+impl<T: ?Sized> MaskingStrategy<T> for FullMask {
+    fn mask(_: &T) -> String {
+        MASK.to_string()
+    }
+}
+
+/// Shows only the last four letters or digits, like `****1234` for card or account numbers
+///
+/// Values with four or fewer letters and digits are fully masked, as they would be shown whole.
+pub enum LastFour {}
+
+impl<T: Display + ?Sized> MaskingStrategy<T> for LastFour {
+    fn mask(value: &T) -> String {
+        let value = value.to_string();
+        let visible: Vec<char> = value.chars().filter(|c| c.is_alphanumeric()).collect();
+
+        match visible.len() {
+            0..=4 => MASK.to_string(),
+            n => format!("****{}", visible[n - 4..].iter().collect::<String>()),
+        }
+    }
+}
+
+/// Shows the first character of the local part and the whole domain of an email address, like
+/// `j***@example.com`
+///
+/// Local parts of a single character are hidden entirely, like `***@example.com`, and values that
+/// aren't email addresses are fully masked.
+pub enum EmailMask {}
+
+impl<T: Display + ?Sized> MaskingStrategy<T> for EmailMask {
+    fn mask(value: &T) -> String {
+        let value = value.to_string();
+
+        match value.rsplit_once('@') {
+            Some((local, domain)) if !domain.is_empty() => {
+                let mut chars = local.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(first), Some(_)) => format!("{first}***@{domain}"),
+                    (Some(_), None) => format!("***@{domain}"),
+                    (None, _) => MASK.to_string(),
+                }
+            }
+            _ => MASK.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    #[cfg(feature = "internet")]
+    use crate::internet::Email;
+    use crate::lang::Sensitive;
+
+    #[test]
+    fn masks_all_but_the_last_four() {
+        let card = Sensitive::new("4111 1111 1111 1234").with_masking::<LastFour>();
+
+        assert_eq!(card.to_string(), "****1234");
+        assert_eq!(format!("{:?}", card), "Sensitive(\"****1234\")");
+        assert_eq!(LastFour::mask("12-34"), MASK);
+    }
+
+    #[test]
+    fn masks_email_local_parts() {
+        let email = Sensitive::new("john.doe@example.com").with_masking::<EmailMask>();
+
+        assert_eq!(email.to_string(), "j***@example.com");
+        assert_eq!(EmailMask::mask("not an email"), MASK);
+        assert_eq!(EmailMask::mask("@example.com"), MASK);
+        assert_eq!(EmailMask::mask("j@example.com"), "***@example.com");
+    }
+
+    #[test]
+    #[cfg(feature = "internet")]
+    fn masks_by_type() {
+        let email: Email = "john.doe@example.com".parse().unwrap();
+
+        let masked = Sensitive::new(Box::new(email.clone())).with_masking::<ByType>();
+
+        assert_eq!(masked.to_string(), "j***@example.com");
+        assert_eq!(Sensitive::new(email).to_string(), MASK);
+    }
+
+    proptest! {
+        #[test]
+        fn reveals_at_most_four_characters(a in "[0-9]{8,19}") {
+            let masked = Sensitive::new(a.clone()).with_masking::<LastFour>().to_string();
+
+            prop_assert_eq!(masked, format!("****{}", &a[a.len() - 4..]));
+        }
+    }
+}
@@ -1,7 +1,51 @@
+#[cfg(feature = "audit")]
+pub mod audit;
+pub mod bounded_text;
+pub mod country;
+#[cfg(feature = "zeroize")]
+pub mod credential;
+#[cfg(feature = "encryption")]
+pub mod encrypted;
+#[cfg(feature = "rich-text")]
+pub mod html;
 pub mod id;
+pub mod language_tag;
+pub mod locale;
+pub mod localized_text;
+#[cfg(feature = "rich-text")]
+pub mod markdown;
+pub mod masking;
+pub mod person_name;
+pub mod postal_address;
+#[cfg(feature = "serde")]
+pub mod redaction;
+pub mod sanitizer;
 pub mod sensitive;
+pub mod subdivision;
 pub mod text;
 
+#[cfg(feature = "audit")]
+pub use audit::*;
+pub use bounded_text::*;
+pub use country::*;
+#[cfg(feature = "zeroize")]
+pub use credential::*;
+#[cfg(feature = "encryption")]
+pub use encrypted::*;
+#[cfg(feature = "rich-text")]
+pub use html::*;
 pub use id::*;
+pub use language_tag::*;
+pub use locale::*;
+pub use localized_text::*;
+#[cfg(feature = "rich-text")]
+pub use markdown::*;
+pub use masking::*;
+pub use person_name::*;
+pub use postal_address::*;
+#[cfg(feature = "serde")]
+pub use redaction::*;
+pub use sanitizer::*;
 pub use sensitive::*;
+pub use subdivision::*;
 pub use text::*;
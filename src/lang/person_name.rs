@@ -0,0 +1,414 @@
+use std::fmt::Display;
+
+#[cfg(any(test, feature = "testing"))]
+use fake::{
+    faker::name::{en, zh_cn},
+    Fake,
+};
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    strategy::{BoxedStrategy, Strategy},
+};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(any(test, feature = "testing"))]
+use crate::testing::Rng;
+
+use crate::{
+    lang::{Locale, Newlines, Sanitizer},
+    Error, Kind,
+};
+
+/// The maximum length, in graphemes, of each component of a name
+const MAX_COMPONENT_LEN: usize = 64;
+
+/// Languages where the family name is usually written before the given name
+const FAMILY_FIRST_LANGUAGES: &[&str] = &["hu", "ja", "ko", "mn", "vi", "yue", "zh"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The order in which the given and family names are written
+pub enum NameOrder {
+    /// Like `John Quincy Adams`
+    GivenFirst,
+    /// Like `Yamada Taro` or `山田太郎`
+    FamilyFirst,
+}
+
+impl NameOrder {
+    /// The order names are usually written in on `locale`
+    pub fn for_locale(locale: &Locale) -> Self {
+        if FAMILY_FIRST_LANGUAGES.contains(&locale.language()) {
+            Self::FamilyFirst
+        } else {
+            Self::GivenFirst
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawPersonName"))]
+/// The name of a person, split into its components
+///
+/// Every component is sanitised like `Text`, without line breaks. Either the given or the family
+/// name must be present, so people with a single name (a mononym) can be represented too.
+pub struct PersonName {
+    prefix: Option<String>,
+    given: Option<String>,
+    middle: Vec<String>,
+    family: Option<String>,
+    suffix: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawPersonName {
+    prefix: Option<String>,
+    given: Option<String>,
+    middle: Vec<String>,
+    family: Option<String>,
+    suffix: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPersonName> for PersonName {
+    type Error = Error;
+
+    fn try_from(raw: RawPersonName) -> Result<Self, Self::Error> {
+        Self::new(raw.prefix, raw.given, raw.middle, raw.family, raw.suffix)
+    }
+}
+
+/// Whether `s` is written in a script that doesn't separate the family and given names
+fn is_unspaced_script(s: &str) -> bool {
+    s.chars().all(|c| {
+        matches!(c,
+            '\u{1100}'..='\u{11FF}' // Hangul Jamo
+            | '\u{3040}'..='\u{30FF}' // Hiragana and Katakana
+            | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+            | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+            | '\u{AC00}'..='\u{D7AF}' // Hangul Syllables
+            | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+            | '\u{20000}'..='\u{2FA1F}' // CJK Unified Ideographs Extensions B and later
+        )
+    })
+}
+
+/// Lowercases `s` and strips its diacritics, so `Ångström` becomes `angstrom`
+fn folded(s: &str) -> String {
+    s.nfkd()
+        .filter(|&c| !is_combining_mark(c))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+impl PersonName {
+    pub fn new(
+        prefix: Option<impl AsRef<str>>,
+        given: Option<impl AsRef<str>>,
+        middle: impl IntoIterator<Item = impl AsRef<str>>,
+        family: Option<impl AsRef<str>>,
+        suffix: Option<impl AsRef<str>>,
+    ) -> Result<Self, Error> {
+        let sanitizer = Sanitizer {
+            newlines: Newlines::Remove,
+            ..Default::default()
+        };
+
+        let clean = |s: &str| {
+            let s = sanitizer.sanitize(s);
+            let length = s.graphemes(true).count();
+
+            match length {
+                0 => Ok(None),
+                1..=MAX_COMPONENT_LEN => Ok(Some(s)),
+                _ => Err(Error::InvalidLength {
+                    kind: Kind::PersonName,
+                    actual: length,
+                    min: 1,
+                    max: MAX_COMPONENT_LEN,
+                }),
+            }
+        };
+
+        let given = given.map(|g| clean(g.as_ref())).transpose()?.flatten();
+        let family = family.map(|f| clean(f.as_ref())).transpose()?.flatten();
+
+        if given.is_none() && family.is_none() {
+            return Err(Error::MissingField(Kind::PersonName, "given"));
+        }
+
+        Ok(Self {
+            prefix: prefix.map(|p| clean(p.as_ref())).transpose()?.flatten(),
+            given,
+            middle: middle
+                .into_iter()
+                .filter_map(|m| clean(m.as_ref()).transpose())
+                .collect::<Result<_, _>>()?,
+            family,
+            suffix: suffix.map(|s| clean(s.as_ref())).transpose()?.flatten(),
+        })
+    }
+
+    /// The honorific written before the name, like `Dr.`
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// The personal name, like `John`
+    pub fn given(&self) -> Option<&str> {
+        self.given.as_deref()
+    }
+
+    pub fn middle(&self) -> &[String] {
+        &self.middle
+    }
+
+    /// The surname, like `Adams`
+    pub fn family(&self) -> Option<&str> {
+        self.family.as_deref()
+    }
+
+    /// The honorific or generational suffix written after the name, like `Jr.` or `PhD`
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
+    /// Formats the full name, with honorifics, in the given order
+    ///
+    /// Family-first names written in CJK scripts have no space between the family and given
+    /// names, so `山田` and `太郎` become `山田太郎`.
+    pub fn format(&self, order: NameOrder) -> String {
+        let mut parts: Vec<String> = self.prefix.iter().cloned().collect();
+
+        match order {
+            NameOrder::GivenFirst => {
+                parts.extend(
+                    self.given
+                        .iter()
+                        .chain(&self.middle)
+                        .chain(&self.family)
+                        .cloned(),
+                );
+            }
+            NameOrder::FamilyFirst => {
+                let given = self
+                    .given
+                    .iter()
+                    .chain(&self.middle)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                match &self.family {
+                    Some(family)
+                        if !given.is_empty()
+                            && is_unspaced_script(family)
+                            && is_unspaced_script(&given) =>
+                    {
+                        parts.push(format!("{family}{given}"));
+                    }
+                    family => {
+                        parts.extend(family.iter().cloned());
+                        parts.extend(Some(given).filter(|g| !g.is_empty()));
+                    }
+                }
+            }
+        }
+
+        parts.extend(self.suffix.iter().cloned());
+        parts.join(" ")
+    }
+
+    /// Formats the full name in the order usually used on `locale`
+    pub fn format_for(&self, locale: &Locale) -> String {
+        self.format(NameOrder::for_locale(locale))
+    }
+
+    /// The uppercased first letters of the given, middle and family names, like `JQA`
+    pub fn initials(&self) -> String {
+        self.given
+            .iter()
+            .chain(&self.middle)
+            .chain(&self.family)
+            .filter_map(|name| name.graphemes(true).next())
+            .map(|initial| initial.to_uppercase())
+            .collect()
+    }
+
+    /// A key to sort names by, as done on directories: by family name and then by given and
+    /// middle names, ignoring case and diacritics
+    pub fn sort_key(&self) -> Vec<String> {
+        self.family
+            .iter()
+            .chain(&self.given)
+            .chain(&self.middle)
+            .map(|name| folded(name))
+            .collect()
+    }
+}
+
+impl Display for PersonName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(NameOrder::GivenFirst))
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for PersonName {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let english = (any::<Rng>(), any::<[bool; 2]>(), 0..3usize).prop_map(
+            |(mut rng, [prefixed, suffixed], middle)| {
+                let prefix: Option<String> =
+                    prefixed.then(|| en::Title().fake_with_rng(&mut rng.0));
+                let given: String = en::FirstName().fake_with_rng(&mut rng.0);
+                let middle: Vec<String> = (0..middle)
+                    .map(|_| en::FirstName().fake_with_rng(&mut rng.0))
+                    .collect();
+                let family: String = en::LastName().fake_with_rng(&mut rng.0);
+                let suffix: Option<String> =
+                    suffixed.then(|| en::Suffix().fake_with_rng(&mut rng.0));
+
+                Self::new(prefix, Some(given), middle, Some(family), suffix)
+                    .expect("Generated an invalid name")
+            },
+        );
+
+        let chinese = any::<Rng>().prop_map(|mut rng| {
+            let given: String = zh_cn::FirstName().fake_with_rng(&mut rng.0);
+            let family: String = zh_cn::LastName().fake_with_rng(&mut rng.0);
+
+            Self::new(
+                None::<&str>,
+                Some(given),
+                [""; 0],
+                Some(family),
+                None::<&str>,
+            )
+            .expect("Generated an invalid name")
+        });
+
+        prop_oneof![3 => english, 1 => chinese].boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const NONE: Option<&str> = None;
+
+    fn locale(s: &str) -> Locale {
+        s.parse().expect("Failed parsing")
+    }
+
+    fn adams() -> PersonName {
+        PersonName::new(
+            Some("Mr."),
+            Some("John"),
+            ["Quincy"],
+            Some("Adams"),
+            Some("Jr."),
+        )
+        .expect("Failed creating name")
+    }
+
+    #[test]
+    fn formats_in_both_orders() {
+        let name = adams();
+
+        assert_eq!(name.to_string(), "Mr. John Quincy Adams Jr.");
+        assert_eq!(
+            name.format(NameOrder::FamilyFirst),
+            "Mr. Adams John Quincy Jr."
+        );
+        assert_eq!(
+            name.format_for(&locale("hu-HU")),
+            "Mr. Adams John Quincy Jr."
+        );
+        assert_eq!(
+            name.format_for(&locale("en-US")),
+            "Mr. John Quincy Adams Jr."
+        );
+    }
+
+    #[test]
+    fn joins_cjk_names_without_spaces() {
+        let name = PersonName::new(NONE, Some("太郎"), [""; 0], Some("山田"), NONE).unwrap();
+
+        assert_eq!(name.format_for(&locale("ja")), "山田太郎");
+        assert_eq!(name.format_for(&locale("en")), "太郎 山田");
+
+        let romanized = PersonName::new(NONE, Some("Taro"), [""; 0], Some("Yamada"), NONE).unwrap();
+
+        assert_eq!(romanized.format_for(&locale("ja")), "Yamada Taro");
+    }
+
+    #[test]
+    fn builds_initials_and_sort_keys() {
+        let name = PersonName::new(NONE, Some("émile"), [""; 0], Some("Zola"), NONE).unwrap();
+
+        assert_eq!(adams().initials(), "JQA");
+        assert_eq!(name.initials(), "ÉZ");
+        assert_eq!(name.sort_key(), ["zola", "emile"]);
+    }
+
+    #[test]
+    fn sanitizes_components() {
+        let name = PersonName::new(
+            NONE,
+            Some("  Ada\u{200B}\n"),
+            ["", " "],
+            Some("Love\u{202E}lace "),
+            Some(""),
+        )
+        .unwrap();
+
+        assert_eq!(name.given(), Some("Ada"));
+        assert_eq!(name.family(), Some("Lovelace"));
+        assert!(name.middle().is_empty());
+        assert_eq!(name.suffix(), None);
+    }
+
+    #[test]
+    fn validates_components() {
+        assert_eq!(
+            PersonName::new(Some("Dr."), Some(" "), [""; 0], NONE, NONE),
+            Err(Error::MissingField(Kind::PersonName, "given"))
+        );
+        assert!(PersonName::new(NONE, NONE, [""; 0], Some("Sukarno"), NONE).is_ok());
+        assert_eq!(
+            PersonName::new(NONE, Some("a".repeat(65)), [""; 0], NONE, NONE),
+            Err(Error::InvalidLength {
+                kind: Kind::PersonName,
+                actual: 65,
+                min: 1,
+                max: MAX_COMPONENT_LEN
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_names_are_always_valid(a in any::<PersonName>()) {
+            prop_assert_eq!(
+                PersonName::new(a.prefix(), a.given(), a.middle(), a.family(), a.suffix()),
+                Ok(a)
+            );
+        }
+
+        #[test]
+        fn sort_keys_start_with_the_family_name(a in any::<PersonName>()) {
+            if let Some(family) = a.family() {
+                prop_assert_eq!(&a.sort_key()[0], &folded(family));
+            }
+        }
+    }
+}
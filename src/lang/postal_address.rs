@@ -0,0 +1,873 @@
+use std::fmt::Display;
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    sample::select,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+#[cfg(any(test, feature = "testing"))]
+use crate::testing::Rng;
+
+use crate::{
+    lang::{Country, Newlines, Sanitizer},
+    Error, Kind,
+};
+
+/// The maximum amount of street lines on an address
+const MAX_LINES: usize = 3;
+
+/// The maximum length of a postal code for countries without a known pattern
+const MAX_POSTAL_CODE_LEN: usize = 16;
+
+/// How addresses are validated and formatted on a country
+///
+/// Postal code patterns are a list of alternatives separated by `|`, where `9` matches a digit,
+/// `A` matches a letter, `?` matches either and anything else matches itself. Templates use `%A`
+/// for the street lines, `%C` for the locality, `%S` for the region, `%Z` for the postal code and
+/// `%n` for line breaks.
+struct AddressFormat {
+    postal_code: Option<&'static str>,
+    requires_postal_code: bool,
+    requires_region: bool,
+    requires_locality: bool,
+    template: &'static str,
+}
+
+macro_rules! address_formats {
+    ($($country:ident, $postal_code:expr, $postal:expr, $region:expr, $locality:expr, $template:expr;)*) => {
+        fn format_for(country: Country) -> &'static AddressFormat {
+            match country {
+                $(Country::$country => &AddressFormat {
+                    postal_code: $postal_code,
+                    requires_postal_code: $postal,
+                    requires_region: $region,
+                    requires_locality: $locality,
+                    template: $template,
+                },)*
+            }
+        }
+    };
+}
+
+// Country, postal code pattern, requires postal code, requires region, requires locality, template
+address_formats! {
+    AU, Some("9999"), true, true, true, "%A%n%C %S %Z";
+    BR, Some("99999-999"), true, true, true, "%A%n%C-%S%n%Z";
+    CA, Some("A9A 9A9"), true, true, true, "%A%n%C %S %Z";
+    CN, Some("999999"), true, true, true, "%A%n%C, %S%n%Z";
+    DE, Some("99999"), true, false, true, "%A%n%Z %C";
+    ES, Some("99999"), true, false, true, "%A%n%Z %C %S";
+    FR, Some("99999"), true, false, true, "%A%n%Z %C";
+    GB, Some("A9 9AA|A99 9AA|AA9 9AA|AA99 9AA|A9A 9AA|AA9A 9AA"), true, false, true, "%A%n%C%n%Z";
+    HK, None, false, false, false, "%A%n%C";
+    IE, Some("A99 ????|A9A ????"), false, false, true, "%A%n%C%n%S%n%Z";
+    IN, Some("999999"), true, true, true, "%A%n%C %Z%n%S";
+    IT, Some("99999"), true, false, true, "%A%n%Z %C %S";
+    JP, Some("999-9999"), true, true, true, "%A%n%C, %S%n%Z";
+    MX, Some("99999"), true, true, true, "%A%n%Z %C, %S";
+    NL, Some("9999 AA"), true, false, true, "%A%n%Z %C";
+    PT, Some("9999-999"), true, false, true, "%A%n%Z %C";
+    SG, Some("999999"), true, false, false, "%A%n%Z";
+    US, Some("99999|99999-9999"), true, true, true, "%A%n%C, %S %Z";
+
+    // Layouts from OpenCage's address-formatting templates (MIT), which have no validation rules
+    AD, None, false, false, true, "%A%n%Z %C";
+    AE, None, false, false, true, "%A%n%C%n%S%n%Z";
+    AF, None, false, false, true, "%A%n%C%n%Z";
+    AG, None, false, false, true, "%A%n%C%n%Z";
+    AI, None, false, false, true, "%A%n%C%n%Z";
+    AL, None, false, false, true, "%A%n%Z %C";
+    AM, None, false, false, true, "%A%n%Z%n%C%n%S";
+    AO, None, false, false, true, "%A%n%C, %Z";
+    AQ, None, false, false, true, "%A%n%C%n%Z";
+    AR, None, false, false, true, "%A%n%Z %C";
+    AS, None, false, false, true, "%A%n%C, %S %Z";
+    AT, None, false, false, true, "%A%n%Z %C";
+    AW, None, false, false, true, "%A%n%C%n%Z";
+    AX, None, false, false, true, "%A%n%Z %C";
+    AZ, None, false, false, true, "%A%n%Z %C";
+    BA, None, false, false, true, "%A%n%Z %C";
+    BB, None, false, false, true, "%A%n%C%n%Z";
+    BD, None, false, false, true, "%A%n%C - %Z";
+    BE, None, false, false, true, "%A%n%Z %C";
+    BF, None, false, false, true, "%A%n%C%n%Z";
+    BG, None, false, false, true, "%A%n%Z %C";
+    BH, None, false, false, true, "%A%n%C %Z";
+    BI, None, false, false, true, "%A%n%C%n%Z";
+    BJ, None, false, false, true, "%A%n%C%n%Z";
+    BL, None, false, false, true, "%A%n%Z %C";
+    BM, None, false, false, true, "%A%n%C %Z";
+    BN, None, false, false, true, "%A%n%C%n%S %Z";
+    BO, None, false, false, true, "%A%n%C%n%Z";
+    BQ, None, false, false, true, "%A%n%Z %C";
+    BS, None, false, false, true, "%A%n%C%n%Z";
+    BT, None, false, false, true, "%A%n%C %Z";
+    BV, None, false, false, true, "%A%n%Z %C";
+    BW, None, false, false, true, "%A%n%C%n%Z";
+    BY, None, false, false, true, "%S%n%Z %C%n%A";
+    BZ, None, false, false, true, "%A%n%C%n%Z";
+    CC, None, false, false, true, "%A%n%C %S %Z";
+    CD, None, false, false, true, "%A%n%C%n%Z";
+    CF, None, false, false, true, "%A%n%C%n%Z";
+    CG, None, false, false, true, "%A%n%C%n%Z";
+    CH, None, false, false, true, "%A%n%Z %C";
+    CI, None, false, false, true, "%A%n%C%n%Z";
+    CK, None, false, false, true, "%A%n%C%n%Z";
+    CL, None, false, false, true, "%A%n%Z %C";
+    CM, None, false, false, true, "%A%n%C%n%Z";
+    CO, None, false, false, true, "%A%n%Z %C, %S";
+    CR, None, false, false, true, "%A%n%S, %C%n%Z";
+    CU, None, false, false, true, "%A%n%C, %Z";
+    CV, None, false, false, true, "%A%n%Z %C";
+    CW, None, false, false, true, "%A%n%C%n%Z";
+    CX, None, false, false, true, "%A%n%C %S %Z";
+    CY, None, false, false, true, "%A%n%Z %C";
+    CZ, None, false, false, true, "%A%n%Z %C";
+    DJ, None, false, false, true, "%A%n%C%n%Z";
+    DK, None, false, false, true, "%A%n%Z %C";
+    DM, None, false, false, true, "%A%n%C%n%Z";
+    DO, None, false, false, true, "%A%n%C, %S%n%Z";
+    DZ, None, false, false, true, "%A%n%Z %C";
+    EC, None, false, false, true, "%A%n%Z%n%C";
+    EE, None, false, false, true, "%A%n%Z %C";
+    EG, None, false, false, true, "%A%n%C%n%Z";
+    EH, None, false, false, true, "%A%n%C%n%Z";
+    ER, None, false, false, true, "%A%n%C%n%Z";
+    ET, None, false, false, true, "%A%n%Z %C";
+    FI, None, false, false, true, "%A%n%Z %C";
+    FJ, None, false, false, true, "%A%n%C%n%Z";
+    FK, None, false, false, true, "%A%n%C %Z";
+    FM, None, false, false, true, "%A%n%C, %S %Z";
+    FO, None, false, false, true, "%A%n%Z %C";
+    GA, None, false, false, true, "%A%n%C%n%Z";
+    GD, None, false, false, true, "%A%n%C%n%Z";
+    GE, None, false, false, true, "%A%n%Z %C";
+    GF, None, false, false, true, "%A%n%Z %C";
+    GG, None, false, false, true, "%A%n%C %Z";
+    GH, None, false, false, true, "%A%n%C%n%Z";
+    GI, None, false, false, true, "%A%n%C%n%Z";
+    GL, None, false, false, true, "%A%n%Z %C";
+    GM, None, false, false, true, "%A%n%C%n%Z";
+    GN, None, false, false, true, "%A%n%Z %C%n%S";
+    GP, None, false, false, true, "%A%n%Z %C";
+    GQ, None, false, false, true, "%A%n%C%n%Z";
+    GR, None, false, false, true, "%A%n%Z %C";
+    GS, None, false, false, true, "%A%n%C %Z";
+    GT, None, false, false, true, "%A%n%Z-%C";
+    GU, None, false, false, true, "%A%n%C, %S %Z";
+    GW, None, false, false, true, "%A%n%Z %C";
+    GY, None, false, false, true, "%A%n%C%n%Z";
+    HM, None, false, false, true, "%A%n%C %S %Z";
+    HN, None, false, false, true, "%A%n%Z %C";
+    HR, None, false, false, true, "%A%n%Z %C";
+    HT, None, false, false, true, "%A%n%Z %C";
+    HU, None, false, false, true, "%A%n%C%n%Z";
+    ID, None, false, false, true, "%A%n%C %Z%n%S";
+    IL, None, false, false, true, "%A%n%Z %C";
+    IM, None, false, false, true, "%A%n%C %Z";
+    IO, None, false, false, true, "%A%n%C %Z";
+    IQ, None, false, false, true, "%A%n%C%n%Z";
+    IR, None, false, false, true, "%A%n%C%n%S%n%Z";
+    IS, None, false, false, true, "%A%n%Z %C";
+    JE, None, false, false, true, "%A%n%C %Z";
+    JM, None, false, false, true, "%A%n%C %Z";
+    JO, None, false, false, true, "%A%n%Z %C";
+    KE, None, false, false, true, "%A%n%C%n%Z";
+    KG, None, false, false, true, "%S%n%Z %C%n%A";
+    KH, None, false, false, true, "%A%n%C %Z";
+    KI, None, false, false, true, "%A%n%C%n%Z";
+    KM, None, false, false, true, "%A%n%C%n%Z";
+    KN, None, false, false, true, "%A%n%C, %S%n%Z";
+    KP, None, false, false, true, "%A%n%C%n%Z";
+    KR, None, false, false, true, "%A%n%C, %S %Z";
+    KW, None, false, false, true, "%A%n%Z %C";
+    KY, None, false, false, true, "%A%n%C %Z";
+    KZ, None, false, false, true, "%S%n%Z %C%n%A";
+    LA, None, false, false, true, "%A%n%Z %C";
+    LB, None, false, false, true, "%A%n%C %Z";
+    LC, None, false, false, true, "%A%n%C%n%Z";
+    LI, None, false, false, true, "%A%n%Z %C";
+    LK, None, false, false, true, "%A%n%C %Z";
+    LR, None, false, false, true, "%A%n%Z %C";
+    LS, None, false, false, true, "%A%n%C %Z";
+    LT, None, false, false, true, "%A%n%Z %C";
+    LU, None, false, false, true, "%A%n%Z %C";
+    LV, None, false, false, true, "%A%n%C, %Z";
+    LY, None, false, false, true, "%A%n%C%n%Z";
+    MA, None, false, false, true, "%A%n%Z %C";
+    MC, None, false, false, true, "%A%n%Z %C";
+    MD, None, false, false, true, "%A%n%Z %C";
+    ME, None, false, false, true, "%A%n%Z %C";
+    MF, None, false, false, true, "%A%n%Z %C";
+    MG, None, false, false, true, "%A%n%Z %C";
+    MH, None, false, false, true, "%A%n%C, %S %Z";
+    MK, None, false, false, true, "%A%n%Z %C";
+    ML, None, false, false, true, "%A%n%C%n%Z";
+    MM, None, false, false, true, "%A%n%C, %Z";
+    MN, None, false, false, true, "%A%n%Z%n%C";
+    MO, None, false, false, true, "%A%n%C%n%Z";
+    MP, None, false, false, true, "%A%n%C, %S %Z";
+    MQ, None, false, false, true, "%A%n%Z %C";
+    MR, None, false, false, true, "%A%n%C%n%Z";
+    MS, None, false, false, true, "%A%n%C%n%Z";
+    MT, None, false, false, true, "%A%n%C%n%Z";
+    MU, None, false, false, true, "%A%n%C%n%Z";
+    MV, None, false, false, true, "%A%n%C %Z";
+    MW, None, false, false, true, "%A%n%C%n%Z";
+    MY, None, false, false, true, "%A%n%Z %C%n%S";
+    MZ, None, false, false, true, "%A%n%Z %C";
+    NA, None, false, false, true, "%A%n%C %Z";
+    NC, None, false, false, true, "%A%n%Z %C";
+    NE, None, false, false, true, "%A%n%C%n%Z";
+    NF, None, false, false, true, "%A%n%C %S %Z";
+    NG, None, false, false, true, "%A%n%C %Z%n%S";
+    NI, None, false, false, true, "%A%n%C%n%Z";
+    NO, None, false, false, true, "%A%n%Z %C";
+    NP, None, false, false, true, "%A%n%C%n%S %Z";
+    NR, None, false, false, true, "%A%n%C%n%Z";
+    NU, None, false, false, true, "%A%n%C%n%Z";
+    NZ, None, false, false, true, "%A%n%C %Z";
+    OM, None, false, false, true, "%A%n%Z%n%C%n%S";
+    PA, None, false, false, true, "%A%n%Z%n%C%n%S";
+    PE, None, false, false, true, "%A%n%C %Z";
+    PF, None, false, false, true, "%A%n%Z %C";
+    PG, None, false, false, true, "%A%n%C %Z %S";
+    PH, None, false, false, true, "%A%n%C%n%Z %S";
+    PK, None, false, false, true, "%A%n%C %Z";
+    PL, None, false, false, true, "%A%n%Z %C";
+    PM, None, false, false, true, "%A%n%Z %C";
+    PN, None, false, false, true, "%A%n%C%n%Z";
+    PR, None, false, false, true, "%A%n%C, %S %Z";
+    PS, None, false, false, true, "%A%n%Z %C";
+    PW, None, false, false, true, "%A%n%Z %C";
+    PY, None, false, false, true, "%A%n%Z %C";
+    QA, None, false, false, true, "%A%n%C%n%Z";
+    RE, None, false, false, true, "%A%n%Z %C";
+    RO, None, false, false, true, "%A%n%Z %C";
+    RS, None, false, false, true, "%A%n%Z %C";
+    RU, None, false, false, true, "%A%n%C%n%S%n%Z";
+    RW, None, false, false, true, "%A%n%C%n%Z";
+    SA, None, false, false, true, "%A%n%C %Z";
+    SB, None, false, false, true, "%A%n%C%n%Z";
+    SC, None, false, false, true, "%A%n%C%n%Z";
+    SD, None, false, false, true, "%A%n%Z %C";
+    SE, None, false, false, true, "%A%n%Z %C";
+    SH, None, false, false, true, "%A%n%C %Z";
+    SI, None, false, false, true, "%A%n%Z %C";
+    SJ, None, false, false, true, "%A%n%Z %C";
+    SK, None, false, false, true, "%A%n%Z %C";
+    SL, None, false, false, true, "%A%n%C%n%Z";
+    SM, None, false, false, true, "%A%n%Z %C";
+    SN, None, false, false, true, "%A%n%Z %C";
+    SO, None, false, false, true, "%A%n%C%n%Z";
+    SR, None, false, false, true, "%A%n%C%n%Z";
+    SS, None, false, false, true, "%A%n%C%n%Z";
+    ST, None, false, false, true, "%A%n%C%n%Z";
+    SV, None, false, false, true, "%A%n%Z - %C%n%S";
+    SX, None, false, false, true, "%A%n%C%n%Z";
+    SY, None, false, false, true, "%A%n%C%n%Z";
+    SZ, None, false, false, true, "%A%n%C%n%Z";
+    TC, None, false, false, true, "%A%n%C %Z";
+    TD, None, false, false, true, "%A%n%C%n%Z";
+    TF, None, false, false, true, "%A%n%Z %C";
+    TG, None, false, false, true, "%A%n%C%n%Z";
+    TH, None, false, false, true, "%A%n%C%n%S %Z";
+    TJ, None, false, false, true, "%A%n%Z %C";
+    TK, None, false, false, true, "%A%n%C %Z";
+    TL, None, false, false, true, "%A%n%C%n%Z";
+    TM, None, false, false, true, "%A%n%Z %C";
+    TN, None, false, false, true, "%A%n%Z %C";
+    TO, None, false, false, true, "%A%n%C%n%Z";
+    TR, None, false, false, true, "%A%n%Z %C";
+    TT, None, false, false, true, "%A%n%C, %Z";
+    TV, None, false, false, true, "%A%n%C%n%S%n%Z";
+    TW, None, false, false, true, "%A%n%C %Z";
+    TZ, None, false, false, true, "%A%n%Z %C%n%S";
+    UA, None, false, false, true, "%A%n%C%n%Z";
+    UG, None, false, false, true, "%A%n%C%n%Z";
+    UM, None, false, false, true, "%A%n%C, %S %Z";
+    UY, None, false, false, true, "%A%n%Z %C";
+    UZ, None, false, false, true, "%A%n%C%n%S%n%Z";
+    VA, None, false, false, true, "%A%n%Z %C";
+    VC, None, false, false, true, "%A%n%C%n%Z";
+    VE, None, false, false, true, "%A%n%C %Z, %S";
+    VG, None, false, false, true, "%A%n%C%n%Z";
+    VI, None, false, false, true, "%A%n%C, %S %Z";
+    VN, None, false, false, true, "%A%n%C%n%S %Z";
+    VU, None, false, false, true, "%A%n%C%n%Z";
+    WF, None, false, false, true, "%A%n%Z %C";
+    WS, None, false, false, true, "%A%n%C%n%Z";
+    YE, None, false, false, true, "%A%n%C%n%Z";
+    YT, None, false, false, true, "%A%n%Z %C";
+    ZA, None, false, false, true, "%A%n%C%n%Z";
+    ZM, None, false, false, true, "%A%n%Z %C";
+    ZW, None, false, false, true, "%A%n%C%n%Z";
+}
+
+fn matches_pattern(value: &str, pattern: &str) -> bool {
+    pattern.split('|').any(|alternative| {
+        value.len() == alternative.len()
+            && value
+                .bytes()
+                .zip(alternative.bytes())
+                .all(|(v, p)| matches_byte(v, p))
+    })
+}
+
+fn matches_byte(value: u8, pattern: u8) -> bool {
+    match pattern {
+        b'9' => value.is_ascii_digit(),
+        b'A' => value.is_ascii_alphabetic(),
+        b'?' => value.is_ascii_alphanumeric(),
+        p => value == p,
+    }
+}
+
+/// Matches a postal code written without the spaces or dashes of the pattern, like `K1A0B1` for
+/// `A9A 9A9`, returning it as the pattern writes it
+fn normalize_postal_code(value: &str, pattern: &str) -> Option<String> {
+    let compact: Vec<u8> = value
+        .bytes()
+        .filter(|b| !matches!(b, b' ' | b'-'))
+        .collect();
+
+    pattern.split('|').find_map(|alternative| {
+        let mut compact = compact.iter().copied();
+        let normalized = alternative
+            .bytes()
+            .map(|p| match p {
+                b' ' | b'-' => Some(char::from(p)),
+                p => compact
+                    .next()
+                    .filter(|v| matches_byte(*v, p))
+                    .map(char::from),
+            })
+            .collect::<Option<String>>()?;
+
+        compact.next().is_none().then_some(normalized)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawPostalAddress"))]
+/// A postal address, validated against the rules of its country
+///
+/// Every country is formatted with its own layout, but postal code patterns and required fields
+/// are only known for some of them, like the United States or Germany. The others accept any
+/// postal code of up to 16 characters, an optional region and a required locality. Postal codes
+/// are stored as their country writes them, so `k1a0b1` is `K1A 0B1` in Canada.
+///
+/// Regions matching a known subdivision (by code or name) are stored as the code used on
+/// envelopes, like `CA` for California, and others are kept as written, like `Greater London`,
+/// except on countries that require one of their subdivisions, like the United States.
+pub struct PostalAddress {
+    lines: Vec<String>,
+    locality: Option<String>,
+    region: Option<String>,
+    postal_code: Option<String>,
+    country: Country,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawPostalAddress {
+    lines: Vec<String>,
+    locality: Option<String>,
+    region: Option<String>,
+    postal_code: Option<String>,
+    country: Country,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPostalAddress> for PostalAddress {
+    type Error = Error;
+
+    fn try_from(raw: RawPostalAddress) -> Result<Self, Self::Error> {
+        Self::new(
+            raw.country,
+            raw.lines,
+            raw.locality,
+            raw.region,
+            raw.postal_code,
+        )
+    }
+}
+
+impl PostalAddress {
+    pub fn new(
+        country: Country,
+        lines: impl IntoIterator<Item = impl AsRef<str>>,
+        locality: Option<impl AsRef<str>>,
+        region: Option<impl AsRef<str>>,
+        postal_code: Option<impl AsRef<str>>,
+    ) -> Result<Self, Error> {
+        let format = format_for(country);
+        let sanitizer = Sanitizer {
+            newlines: Newlines::Remove,
+            ..Default::default()
+        };
+
+        let clean = |s: &str| Some(sanitizer.sanitize(s)).filter(|s| !s.is_empty());
+
+        let lines: Vec<String> = lines
+            .into_iter()
+            .filter_map(|l| clean(l.as_ref()))
+            .collect();
+
+        if lines.is_empty() || lines.len() > MAX_LINES {
+            return Err(Error::InvalidLength {
+                kind: Kind::PostalAddress,
+                actual: lines.len(),
+                min: 1,
+                max: MAX_LINES,
+            });
+        }
+
+        let locality = locality.and_then(|l| clean(l.as_ref()));
+
+        if format.requires_locality && locality.is_none() {
+            return Err(Error::MissingField(Kind::PostalAddress, "locality"));
+        }
+
+        let region = region
+            .and_then(|r| clean(r.as_ref()))
+            .map(|r| canonical_region(country, r, format.requires_region))
+            .transpose()?;
+
+        if format.requires_region && region.is_none() {
+            return Err(Error::MissingField(Kind::PostalAddress, "region"));
+        }
+
+        let postal_code = postal_code
+            .and_then(|p| clean(p.as_ref()))
+            .map(|p| p.to_uppercase());
+
+        if format.requires_postal_code && postal_code.is_none() {
+            return Err(Error::MissingField(Kind::PostalAddress, "postal_code"));
+        }
+
+        let postal_code = match (postal_code, format.postal_code) {
+            (Some(code), Some(pattern)) if !matches_pattern(&code, pattern) => {
+                match normalize_postal_code(&code, pattern) {
+                    Some(code) => Some(code),
+                    None => return Err(Error::FailedParsing(Kind::PostalCode, code)),
+                }
+            }
+            (Some(code), None) if code.chars().count() > MAX_POSTAL_CODE_LEN => {
+                return Err(Error::FailedParsing(Kind::PostalCode, code));
+            }
+            (postal_code, _) => postal_code,
+        };
+
+        Ok(Self {
+            lines,
+            locality,
+            region,
+            postal_code,
+            country,
+        })
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// The city, town or village
+    pub fn locality(&self) -> Option<&str> {
+        self.locality.as_deref()
+    }
+
+    /// The state, province or prefecture
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    pub fn postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+
+    pub fn country(&self) -> Country {
+        self.country
+    }
+
+    /// Formats the address as it should be written for mail sent inside its own country
+    ///
+    /// The separators of the template around missing fields are dropped, like the comma in
+    /// `%C, %S` without a region, and separators are never repeated when a field already ends or
+    /// starts with one, like a `Springfield,` locality. Street lines are written as they are.
+    pub fn format_domestic(&self) -> String {
+        let lines = self.lines.join("\n");
+        let field = |placeholder: &str| match placeholder {
+            "A" => Some(lines.as_str()),
+            "C" => self.locality.as_deref(),
+            "S" => self.region.as_deref(),
+            "Z" => self.postal_code.as_deref(),
+            _ => None,
+        };
+
+        format_for(self.country)
+            .template
+            .split("%n")
+            .map(|line| {
+                let mut formatted = String::new();
+                let mut separator = "";
+
+                // Every placeholder is preceded by the separator it shares with the previous one
+                for (i, part) in line.split('%').enumerate() {
+                    let (placeholder, literal) = match i {
+                        0 => ("", part),
+                        _ => part.split_at(part.chars().next().map_or(0, char::len_utf8)),
+                    };
+
+                    if let Some(mut value) = field(placeholder) {
+                        if !formatted.is_empty() {
+                            // Fields that already end or start with the separator, like
+                            // `Springfield,`, are written with a single one
+                            let mark = separator.trim();
+
+                            if !mark.is_empty() {
+                                if let Some(rest) = formatted.trim_end().strip_suffix(mark) {
+                                    formatted.truncate(rest.trim_end().len());
+                                }
+
+                                value = value
+                                    .trim_start()
+                                    .strip_prefix(mark)
+                                    .map_or(value, str::trim_start);
+                            }
+
+                            formatted.push_str(separator);
+                        }
+
+                        formatted.push_str(value);
+                    }
+
+                    separator = literal;
+                }
+
+                formatted
+            })
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Formats the address for international mail, with the country name on the last line
+    pub fn format(&self) -> String {
+        format!(
+            "{}\n{}",
+            self.format_domestic(),
+            self.country.name().to_uppercase()
+        )
+    }
+}
+
+/// Matches the region against the subdivisions of the country, if there are any known
+///
+/// Regions that don't match any are kept as written, like `Greater London`, unless `strict`, for
+/// countries where the region is part of the address and must be a known one.
+fn canonical_region(country: Country, region: String, strict: bool) -> Result<String, Error> {
+    let found = country.subdivisions().find(|s| {
+        s.local_code().eq_ignore_ascii_case(&region) || s.name().eq_ignore_ascii_case(&region)
+    });
+
+    match found {
+        // Numeric codes (like Japanese prefectures) are never written on addresses
+        Some(s) if s.local_code().bytes().all(|b| b.is_ascii_digit()) => Ok(s.name().to_string()),
+        Some(s) => Ok(s.local_code().to_string()),
+        None if strict && country.subdivisions().next().is_some() => {
+            Err(Error::FailedParsing(Kind::Subdivision, region))
+        }
+        None => Ok(region),
+    }
+}
+
+impl Display for PostalAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format())
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl PostalAddress {
+    /// Generates valid addresses for `country`
+    pub fn arbitrary_for(country: Country) -> BoxedStrategy<Self> {
+        let format = format_for(country);
+
+        let postal_code = match format.postal_code {
+            Some(pattern) => select(pattern.split('|').collect::<Vec<_>>())
+                .prop_flat_map(|alternative| {
+                    alternative
+                        .bytes()
+                        .map(|p| match p {
+                            b'9' => (b'0'..=b'9').prop_map(char::from).boxed(),
+                            b'A' => (b'A'..=b'Z').prop_map(char::from).boxed(),
+                            b'?' => select(&b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"[..])
+                                .prop_map(char::from)
+                                .boxed(),
+                            p => Just(char::from(p)).boxed(),
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .prop_map(|chars| Some(chars.into_iter().collect::<String>()))
+                .boxed(),
+            None => proptest::option::of("[0-9]{4,6}").boxed(),
+        };
+
+        let regions: Vec<Option<String>> = match country.subdivisions().next() {
+            None if format.requires_region => vec![Some("Lorem".to_string())],
+            None => vec![None, Some("Lorem".to_string())],
+            Some(_) => country
+                .subdivisions()
+                .map(|s| Some(s.name().to_string()))
+                .collect(),
+        };
+
+        (
+            proptest::collection::vec((1..9999u16, any::<Rng>()), 1..=MAX_LINES),
+            any::<Rng>(),
+            select(regions),
+            postal_code,
+        )
+            .prop_map(move |(lines, locality, region, postal_code)| {
+                let lines: Vec<String> = lines
+                    .into_iter()
+                    .map(|(number, rng)| {
+                        format!("{} {}", number, lipsum::lipsum_words_with_rng(rng.0, 2))
+                    })
+                    .collect();
+
+                let locality = lipsum::lipsum_words_with_rng(locality.0, 1);
+
+                Self::new(country, lines, Some(locality), region, postal_code)
+                    .expect("Generated an invalid address")
+            })
+            .boxed()
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for PostalAddress {
+    /// The country to generate addresses for, or any country if `None`
+    type Parameters = Option<Country>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(country: Self::Parameters) -> Self::Strategy {
+        match country {
+            Some(country) => Self::arbitrary_for(country),
+            None => any::<Country>().prop_flat_map(Self::arbitrary_for).boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const NONE: Option<&str> = None;
+
+    #[test]
+    fn validates_postal_codes_per_country() {
+        let address = |country, code| {
+            PostalAddress::new(country, ["1 Main St"], Some("Town"), NONE, Some(code))
+        };
+
+        assert!(address(Country::DE, "10115").is_ok());
+        assert!(address(Country::GB, "sw1a 1aa").is_ok());
+        assert_eq!(
+            PostalAddress::new(
+                Country::CA,
+                ["1 Main St"],
+                Some("Ottawa"),
+                Some("ON"),
+                Some("k1a0b1")
+            )
+            .map(|a| a.postal_code().map(str::to_string)),
+            Ok(Some("K1A 0B1".to_string()))
+        );
+        assert_eq!(
+            address(Country::GB, "SW1A1AA").map(|a| a.postal_code().map(str::to_string)),
+            Ok(Some("SW1A 1AA".to_string()))
+        );
+        assert_eq!(
+            address(Country::NL, "1234A"),
+            Err(Error::FailedParsing(Kind::PostalCode, "1234A".to_string()))
+        );
+        assert_eq!(
+            address(Country::DE, "1011"),
+            Err(Error::FailedParsing(Kind::PostalCode, "1011".to_string()))
+        );
+        assert_eq!(
+            address(Country::NL, "1234 A1"),
+            Err(Error::FailedParsing(
+                Kind::PostalCode,
+                "1234 A1".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn requires_fields_per_country() {
+        assert_eq!(
+            PostalAddress::new(
+                Country::US,
+                ["1 Main St"],
+                Some("Springfield"),
+                NONE,
+                Some("12345")
+            ),
+            Err(Error::MissingField(Kind::PostalAddress, "region"))
+        );
+        assert_eq!(
+            PostalAddress::new(Country::FR, ["1 rue de Rivoli"], Some("Paris"), NONE, NONE),
+            Err(Error::MissingField(Kind::PostalAddress, "postal_code"))
+        );
+        assert!(PostalAddress::new(Country::HK, ["1 Queen's Road"], NONE, NONE, NONE).is_ok());
+    }
+
+    #[test]
+    fn canonicalizes_regions() {
+        let us = PostalAddress::new(
+            Country::US,
+            ["1600 Amphitheatre Pkwy"],
+            Some("Mountain View"),
+            Some("california"),
+            Some("94043"),
+        )
+        .unwrap();
+
+        assert_eq!(us.region(), Some("CA"));
+        assert_eq!(
+            PostalAddress::new(
+                Country::US,
+                ["1 Main St"],
+                Some("Town"),
+                Some("XX"),
+                Some("12345")
+            ),
+            Err(Error::FailedParsing(Kind::Subdivision, "XX".to_string()))
+        );
+
+        let gb = |region| {
+            PostalAddress::new(
+                Country::GB,
+                ["10 Downing St"],
+                Some("London"),
+                Some(region),
+                Some("SW1A 2AA"),
+            )
+            .map(|a| a.region().map(str::to_string))
+        };
+
+        assert_eq!(gb("Greater London"), Ok(Some("Greater London".to_string())));
+        assert_eq!(gb("eng"), Ok(Some("ENG".to_string())));
+    }
+
+    #[test]
+    fn formats_per_country() {
+        let us = PostalAddress::new(
+            Country::US,
+            ["1600 Amphitheatre Pkwy"],
+            Some("Mountain View"),
+            Some("CA"),
+            Some("94043"),
+        )
+        .unwrap();
+
+        let br = PostalAddress::new(
+            Country::BR,
+            ["Av. Paulista, 1578", "Bela Vista"],
+            Some("São Paulo"),
+            Some("SP"),
+            Some("01310-200"),
+        )
+        .unwrap();
+
+        let ie =
+            PostalAddress::new(Country::IE, ["1 Grafton St"], Some("Dublin"), NONE, NONE).unwrap();
+
+        assert_eq!(
+            us.to_string(),
+            "1600 Amphitheatre Pkwy\nMountain View, CA 94043\nUNITED STATES OF AMERICA"
+        );
+        assert_eq!(
+            br.format_domestic(),
+            "Av. Paulista, 1578\nBela Vista\nSão Paulo-SP\n01310-200"
+        );
+        assert_eq!(ie.format_domestic(), "1 Grafton St\nDublin");
+    }
+
+    #[test]
+    fn collapses_repeated_separators() {
+        let br = PostalAddress::new(
+            Country::BR,
+            ["Av. Paulista, 1578 -", "- Bela Vista"],
+            Some("São Paulo -"),
+            Some("SP"),
+            Some("01310200"),
+        )
+        .unwrap();
+
+        let us = PostalAddress::new(
+            Country::US,
+            ["1 Main St,"],
+            Some("Mountain View,"),
+            Some("CA"),
+            Some("94043"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            br.format_domestic(),
+            "Av. Paulista, 1578 -\n- Bela Vista\nSão Paulo-SP\n01310-200"
+        );
+        assert_eq!(us.format_domestic(), "1 Main St,\nMountain View, CA 94043");
+    }
+
+    #[test]
+    fn formats_countries_without_rules() {
+        let nz = PostalAddress::new(
+            Country::NZ,
+            ["1 Queen St"],
+            Some("Auckland"),
+            NONE,
+            Some("1010"),
+        )
+        .unwrap();
+        let ar = PostalAddress::new(
+            Country::AR,
+            ["Av. Corrientes 1234"],
+            Some("Buenos Aires"),
+            Some("C"),
+            Some("C1043AAZ"),
+        )
+        .unwrap();
+
+        assert_eq!(nz.format_domestic(), "1 Queen St\nAuckland 1010");
+        assert_eq!(
+            ar.format_domestic(),
+            "Av. Corrientes 1234\nC1043AAZ Buenos Aires"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_addresses_are_always_valid(a in any::<PostalAddress>()) {
+            prop_assert_eq!(
+                PostalAddress::new(a.country(), a.lines(), a.locality(), a.region(), a.postal_code()),
+                Ok(a)
+            );
+        }
+
+        #[test]
+        fn generates_addresses_per_country(a in any_with::<PostalAddress>(Some(Country::JP))) {
+            prop_assert_eq!(a.country(), Country::JP);
+            prop_assert!(a.region().is_some());
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use std::cell::Cell;
+
+use serde::{Serialize, Serializer};
+
+use crate::lang::{sensitive::MASK, Sensitive};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// How `Sensitive` values are serialised
+///
+/// The redaction is chosen for a whole serialisation with `Redaction::scope`, or for a single
+/// value with `Sensitive::redacted` and the functions on `redact`. Values are masked by default.
+pub enum Redaction {
+    /// As `MASK`, the same way they are displayed
+    #[default]
+    Mask,
+    /// As `None`, which removes the field when it is marked with
+    /// `#[serde(skip_serializing_if = "Sensitive::is_omitted")]`
+    Omit,
+    /// As a keyed BLAKE3 hash of the value, so equal secrets can be matched without being revealed
+    #[cfg(feature = "fingerprint")]
+    Hash(FingerprintKey),
+    /// As the value itself
+    Reveal,
+}
+
+thread_local! {
+    static CURRENT: Cell<Redaction> = const { Cell::new(Redaction::Mask) };
+}
+
+/// Restores the previous redaction when a scope ends, even if it panics
+struct ScopeGuard(Redaction);
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|c| c.set(self.0));
+    }
+}
+
+impl Redaction {
+    /// The redaction used for `Sensitive` values serialised on this thread
+    pub fn current() -> Self {
+        CURRENT.with(|c| c.get())
+    }
+
+    /// Runs `f` with `Sensitive` values serialised on this thread following this redaction, like
+    /// `Redaction::Reveal.scope(|| serde_json::to_string(&user))`
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        let _guard = ScopeGuard(CURRENT.with(|c| c.replace(self)));
+
+        f()
+    }
+
+    pub fn serialize<T, S>(self, value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + ?Sized,
+        S: Serializer,
+    {
+        match self {
+            Redaction::Mask => serializer.serialize_str(MASK),
+            Redaction::Omit => serializer.serialize_none(),
+            #[cfg(feature = "fingerprint")]
+            Redaction::Hash(key) => serializer.serialize_str(
+                &key.fingerprint(value)
+                    .map_err(<S::Error as serde::ser::Error>::custom)?,
+            ),
+            Redaction::Reveal => value.serialize(serializer),
+        }
+    }
+}
+
+/// A `Sensitive` value serialised with a fixed redaction, ignoring the current scope
+pub struct Redacted<'a, T, M> {
+    pub(crate) value: &'a Sensitive<T, M>,
+    pub(crate) redaction: Redaction,
+}
+
+impl<T: Serialize, M> Serialize for Redacted<'_, T, M> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize_with(self.redaction, serializer)
+    }
+}
+
+/// Functions to choose how a single field is serialised, with
+/// `#[serde(serialize_with = "typescape::lang::redact::reveal")]`
+pub mod redact {
+    use serde::{Serialize, Serializer};
+
+    use super::Redaction;
+    use crate::lang::Sensitive;
+
+    pub fn mask<T: Serialize, M, S: Serializer>(
+        value: &Sensitive<T, M>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.redacted(Redaction::Mask).serialize(serializer)
+    }
+
+    pub fn omit<T: Serialize, M, S: Serializer>(
+        value: &Sensitive<T, M>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.redacted(Redaction::Omit).serialize(serializer)
+    }
+
+    pub fn reveal<T: Serialize, M, S: Serializer>(
+        value: &Sensitive<T, M>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.redacted(Redaction::Reveal).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+pub use fingerprint::{FingerprintError, FingerprintKey};
+
+#[cfg(feature = "fingerprint")]
+mod fingerprint {
+    use std::fmt::{Debug, Display, Formatter};
+
+    use serde::Serialize;
+    use serde_json::Value;
+
+    use crate::lang::sensitive::MASK;
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    /// The key for `Redaction::Hash`, which must be kept secret too, as anyone holding it can
+    /// brute-force fingerprints of guessable values like PINs
+    pub struct FingerprintKey([u8; 32]);
+
+    impl FingerprintKey {
+        pub fn new(key: [u8; 32]) -> Self {
+            Self(key)
+        }
+
+        /// Hashes the JSON representation of `value` with the keys of every object sorted,
+        /// returning it as hex
+        ///
+        /// The hash is independent of the format the fingerprint is serialised to and of the
+        /// order of map entries, so equal values have the same fingerprint under the same key.
+        /// Unordered sequences, like a `HashSet`, are hashed in iteration order, so they only
+        /// match when iterated in the same order.
+        pub fn fingerprint<T: Serialize + ?Sized>(
+            &self,
+            value: &T,
+        ) -> Result<String, FingerprintError> {
+            let value = serde_json::to_value(value).map_err(|e| FingerprintError(e.to_string()))?;
+
+            let mut hasher = blake3::Hasher::new_keyed(&self.0);
+            serde_json::to_writer(&mut hasher, &sort_keys(value))
+                .map_err(|e| FingerprintError(e.to_string()))?;
+
+            Ok(hasher.finalize().to_hex().to_string())
+        }
+    }
+
+    /// Sorts the keys of every object, which `serde_json` only does without `preserve_order`
+    fn sort_keys(value: Value) -> Value {
+        match value {
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(k, v)| (k, sort_keys(v)))
+                        .collect(),
+                )
+            }
+            Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+            value => value,
+        }
+    }
+
+    impl Debug for FingerprintKey {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_tuple("FingerprintKey").field(&MASK).finish()
+        }
+    }
+
+    #[derive(Debug)]
+    /// An error raised by the value being fingerprinted while serialising itself
+    pub struct FingerprintError(String);
+
+    impl Display for FingerprintError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::error::Error for FingerprintError {}
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Login {
+        user: String,
+        password: Sensitive<String>,
+        #[serde(skip_serializing_if = "Sensitive::is_omitted")]
+        token: Sensitive<String>,
+        #[serde(serialize_with = "redact::reveal")]
+        hint: Sensitive<String>,
+    }
+
+    fn login() -> Login {
+        Login {
+            user: "ada".to_string(),
+            password: Sensitive::new("hunter2".to_string()),
+            token: Sensitive::new("abc".to_string()),
+            hint: Sensitive::new("cats".to_string()),
+        }
+    }
+
+    fn json<T: Serialize>(value: &T) -> String {
+        serde_json::to_string(value).expect("Failed serializing")
+    }
+
+    #[test]
+    fn masks_by_default() {
+        assert_eq!(
+            json(&login()),
+            r#"{"user":"ada","password":"******","token":"******","hint":"cats"}"#
+        );
+    }
+
+    #[test]
+    fn follows_the_current_scope() {
+        assert_eq!(
+            Redaction::Omit.scope(|| json(&login())),
+            r#"{"user":"ada","password":null,"hint":"cats"}"#
+        );
+        assert_eq!(
+            Redaction::Reveal.scope(|| json(&login())),
+            r#"{"user":"ada","password":"hunter2","token":"abc","hint":"cats"}"#
+        );
+        assert_eq!(Redaction::current(), Redaction::Mask);
+    }
+
+    #[test]
+    fn redacts_single_values() {
+        let secret = Sensitive::new(42);
+
+        assert_eq!(json(&secret.redacted(Redaction::Reveal)), "42");
+        assert_eq!(
+            Redaction::Reveal.scope(|| json(&secret.redacted(Redaction::Mask))),
+            r#""******""#
+        );
+    }
+
+    #[cfg(feature = "audit")]
+    #[test]
+    fn only_records_revealed_values() {
+        use std::sync::Mutex;
+
+        use crate::lang::{audit, Access};
+
+        #[derive(Serialize)]
+        struct Pin(u16);
+
+        static EXPOSURES: Mutex<Vec<Access>> = Mutex::new(vec![]);
+
+        let _lock = audit::tests::LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        audit::set_exposure_hook(|e| {
+            if e.type_name.ends_with("Pin") {
+                EXPOSURES.lock().unwrap().push(e.access);
+            }
+        });
+
+        let pin = Sensitive::new(Pin(1234));
+        json(&pin);
+        Redaction::Omit.scope(|| json(&pin));
+        json(&pin.redacted(Redaction::Mask));
+        assert!(EXPOSURES.lock().unwrap().is_empty());
+
+        Redaction::Reveal.scope(|| json(&pin));
+        json(&pin.redacted(Redaction::Reveal));
+        audit::clear_exposure_hook();
+
+        assert_eq!(
+            *EXPOSURES.lock().unwrap(),
+            [Access::Serialized, Access::Serialized]
+        );
+    }
+
+    #[cfg(feature = "fingerprint")]
+    #[test]
+    fn hashes_with_a_key() {
+        let key = FingerprintKey::new([7; 32]);
+        let other = FingerprintKey::new([8; 32]);
+
+        let hash = |key, value: &str| {
+            Redaction::Hash(key).scope(|| json(&Sensitive::new(value.to_string())))
+        };
+
+        assert_eq!(hash(key, "hunter2"), hash(key, "hunter2"));
+        assert_ne!(hash(key, "hunter2"), hash(key, "hunter3"));
+        assert_ne!(hash(key, "hunter2"), hash(other, "hunter2"));
+        assert_eq!(hash(key, "hunter2").len(), 66);
+        assert_eq!(format!("{:?}", key), r#"FingerprintKey("******")"#);
+
+        // Hash maps serialise their entries in a random order, while B-tree maps sort them
+        let entries = (0..32u8).map(|i| (i.to_string(), i));
+        let unordered: std::collections::HashMap<_, _> = entries.clone().collect();
+        let sorted: std::collections::BTreeMap<_, _> = entries.collect();
+
+        assert_eq!(
+            key.fingerprint(&unordered).unwrap(),
+            key.fingerprint(&sorted).unwrap()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn deserializes_raw_values(a in any::<String>()) {
+            let serialized = Redaction::Reveal.scope(|| json(&Sensitive::new(a.clone())));
+            let deserialized: Sensitive<String> =
+                serde_json::from_str(&serialized).expect("Failed deserializing");
+
+            prop_assert_eq!(deserialized.get(), a);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The Unicode normalisation form applied by a `Sanitizer`
+pub enum Normalization {
+    /// Canonical composition, which keeps the meaning of the text intact
+    Nfc,
+    /// Compatibility composition, which also folds look-alikes (like `ﬁ` into `fi`)
+    Nfkc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// What a `Sanitizer` does with line breaks
+pub enum Newlines {
+    /// Leave line breaks as they are
+    Keep,
+    /// Convert every kind of line break (`\r\n`, `\r`, U+2028, ...) into `\n`
+    Normalize,
+    /// Replace line breaks with spaces, for text that must fit on a single line
+    Remove,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A configurable pipeline that turns user-submitted text into a canonical form that is safe to
+/// display
+///
+/// The steps run in a fixed order: line breaks are handled first, then unwanted characters are
+/// stripped, the result is normalised and whitespace is collapsed last.
+pub struct Sanitizer {
+    pub normalization: Option<Normalization>,
+    pub newlines: Newlines,
+    /// Strips control characters, except for line breaks kept by `newlines`
+    pub strip_control: bool,
+    /// Strips bidirectional overrides and isolates, which can make text render out of order
+    pub strip_bidi: bool,
+    /// Strips zero-width characters. Note that this also splits emoji joined by U+200D.
+    pub strip_zero_width: bool,
+    /// Collapses runs of whitespace into a single space (or up to two line breaks, if the run had
+    /// any) and trims both ends
+    pub collapse_whitespace: bool,
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Self {
+            normalization: Some(Normalization::Nfc),
+            newlines: Newlines::Normalize,
+            strip_control: true,
+            strip_bidi: true,
+            strip_zero_width: true,
+            collapse_whitespace: true,
+        }
+    }
+}
+
+impl Sanitizer {
+    /// A sanitizer that does nothing, to be used as a starting point for custom pipelines
+    pub fn none() -> Self {
+        Self {
+            normalization: None,
+            newlines: Newlines::Keep,
+            strip_control: false,
+            strip_bidi: false,
+            strip_zero_width: false,
+            collapse_whitespace: false,
+        }
+    }
+
+    pub fn sanitize(&self, s: &str) -> String {
+        let s = self.handle_newlines(s);
+        let s: String = s.chars().filter(|&c| !self.strips(c)).collect();
+
+        let s = match self.normalization {
+            None => s,
+            Some(Normalization::Nfc) => s.nfc().collect(),
+            Some(Normalization::Nfkc) => s.nfkc().collect(),
+        };
+
+        if self.collapse_whitespace {
+            collapse_whitespace(&s)
+        } else {
+            s
+        }
+    }
+
+    fn handle_newlines(&self, s: &str) -> String {
+        let replacement = match self.newlines {
+            Newlines::Keep => return s.to_string(),
+            Newlines::Normalize => "\n",
+            Newlines::Remove => " ",
+        };
+
+        s.replace("\r\n", "\n")
+            .split(is_line_break)
+            .collect::<Vec<_>>()
+            .join(replacement)
+    }
+
+    fn strips(&self, c: char) -> bool {
+        let kept_newline = c == '\n' || (self.newlines == Newlines::Keep && is_line_break(c));
+
+        (self.strip_control && c.is_control() && c != '\t' && !kept_newline)
+            || (self.strip_bidi && is_bidi_control(c))
+            || (self.strip_zero_width && is_zero_width(c))
+    }
+}
+
+fn is_line_break(c: char) -> bool {
+    matches!(
+        c,
+        '\n' | '\r' | '\u{0B}' | '\u{0C}' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}')
+}
+
+/// Collapses whitespace runs into a single space, or into line breaks if the run contained any,
+/// keeping at most one blank line between paragraphs
+fn collapse_whitespace(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut pending: Option<usize> = None;
+
+    for c in s.trim().chars() {
+        if c.is_whitespace() {
+            let newlines = pending.unwrap_or(0) + usize::from(c == '\n');
+            pending = Some(newlines.min(2));
+        } else {
+            match pending.take() {
+                None => {}
+                Some(0) => out.push(' '),
+                Some(n) => out.push_str(&"\n".repeat(n)),
+            }
+
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn strips_unsafe_characters() {
+        let s = Sanitizer::default();
+
+        assert_eq!(s.sanitize("a\u{202E}b\u{2066}c\u{200B}d\u{0}e"), "abcde");
+        assert_eq!(s.sanitize("  hello \t  world  "), "hello world");
+        assert_eq!(
+            s.sanitize("one\r\n \r\n\ntwo \u{2028} three"),
+            "one\n\ntwo\nthree"
+        );
+    }
+
+    #[test]
+    fn normalizes_text() {
+        let nfc = Sanitizer::default();
+        let nfkc = Sanitizer {
+            normalization: Some(Normalization::Nfkc),
+            ..Default::default()
+        };
+
+        assert_eq!(nfc.sanitize("e\u{301}"), "\u{e9}");
+        assert_eq!(nfc.sanitize("\u{FB01}"), "\u{FB01}");
+        assert_eq!(nfkc.sanitize("\u{FB01}"), "fi");
+    }
+
+    #[test]
+    fn applies_newline_policies() {
+        let keep = Sanitizer::none();
+        let remove = Sanitizer {
+            newlines: Newlines::Remove,
+            ..Default::default()
+        };
+
+        assert_eq!(keep.sanitize("a\r\nb"), "a\r\nb");
+        assert_eq!(remove.sanitize("a\r\n\r\nb\nc"), "a b c");
+    }
+
+    proptest! {
+        #[test]
+        fn sanitizing_is_idempotent(a in any::<String>()) {
+            let s = Sanitizer::default();
+            let once = s.sanitize(&a);
+
+            prop_assert_eq!(s.sanitize(&once), once);
+        }
+
+        #[test]
+        fn sanitized_text_has_no_unsafe_characters(a in any::<String>()) {
+            let sanitized = Sanitizer::default().sanitize(&a);
+
+            prop_assert!(sanitized
+                .chars()
+                .all(|c| c == '\n' || !(c.is_control() || is_bidi_control(c) || is_zero_width(c))));
+            prop_assert_eq!(sanitized.trim(), sanitized.as_str());
+        }
+
+        #[test]
+        fn empty_sanitizer_keeps_text_intact(a in any::<String>()) {
+            prop_assert_eq!(Sanitizer::none().sanitize(&a), a);
+        }
+    }
+}
@@ -1,101 +1,244 @@
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
+use std::marker::PhantomData;
 use std::str::FromStr;
 
 #[cfg(any(test, feature = "testing"))]
 use proptest::prelude::*;
 
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::lang::{FullMask, MaskingStrategy};
+#[cfg(feature = "serde")]
+use crate::lang::{Redacted, Redaction};
+
 #[cfg(any(test, feature = "testing"))]
 use crate::testing::NegateArbitrary;
 
-const MASK: &str = "******";
+pub(crate) const MASK: &str = "******";
 
-#[derive(Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
 /// A container for sensitive data, such as passwords or credentials, blocking them from being
 /// printed.
-pub struct Sensitive<T>(T);
+///
+/// Values are displayed as `MASK` by default, or partially masked by choosing a
+/// `MaskingStrategy`, like `Sensitive<String, LastFour>` for card numbers or
+/// `Sensitive<Email, ByType>` for types that know how to mask themselves. Partially masked values
+/// are recorded as exposures with the `audit` feature.
+///
+/// Serialising it follows the current `Redaction`, masking the value by default, while
+/// deserialising always accepts the raw value.
+///
+/// With the `zeroize` feature, `Sensitive::zeroizing` builds a value that is wiped from memory
+/// when dropped. Use `Credential` instead for secrets that must not be copied at all.
+pub struct Sensitive<T, M = FullMask>(T, PhantomData<fn() -> M>);
 
 impl<T> Sensitive<T> {
     pub fn new(value: T) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
+}
 
+impl<T, M> Sensitive<T, M> {
+    /// Unwraps the value, which is recorded as an exposure with the `audit` feature
+    #[cfg_attr(feature = "audit", track_caller)]
     pub fn get(self) -> T {
+        #[cfg(feature = "audit")]
+        crate::lang::audit::record::<T>(crate::lang::Access::Owned);
+
         self.0
     }
+
+    /// Borrows the value, which is recorded as an exposure with the `audit` feature
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn expose(&self) -> &T {
+        #[cfg(feature = "audit")]
+        crate::lang::audit::record::<T>(crate::lang::Access::Shared);
+
+        &self.0
+    }
+
+    /// Borrows the value mutably, which is recorded as an exposure with the `audit` feature
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn expose_mut(&mut self) -> &mut T {
+        #[cfg(feature = "audit")]
+        crate::lang::audit::record::<T>(crate::lang::Access::Mutable);
+
+        &mut self.0
+    }
+
+    /// Changes how this value is displayed, like `Sensitive::new(card).with_masking::<LastFour>()`
+    pub fn with_masking<N: MaskingStrategy<T>>(self) -> Sensitive<T, N> {
+        Sensitive(self.0, PhantomData)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, M> Sensitive<T, M> {
+    /// Wraps this value so it is serialised with `redaction`, regardless of the current one
+    pub fn redacted(&self, redaction: Redaction) -> Redacted<'_, T, M> {
+        Redacted {
+            value: self,
+            redaction,
+        }
+    }
+
+    /// Whether this value is omitted by the current `Redaction`, for use with
+    /// `#[serde(skip_serializing_if = "Sensitive::is_omitted")]`
+    pub fn is_omitted(&self) -> bool {
+        Redaction::current() == Redaction::Omit
+    }
+
+    /// Serialises the value with `redaction`, which is recorded as an exposure with the `audit`
+    /// feature only when the value is revealed
+    pub(crate) fn serialize_with<S>(
+        &self,
+        redaction: Redaction,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: serde::Serialize,
+        S: serde::Serializer,
+    {
+        match redaction {
+            Redaction::Reveal => {
+                #[cfg(feature = "audit")]
+                crate::lang::audit::record_untracked::<T>(crate::lang::Access::Serialized);
+
+                self.0.serialize(serializer)
+            }
+            redaction => redaction.serialize(&self.0, serializer),
+        }
+    }
 }
 
-impl<T> Display for Sensitive<T> {
+#[cfg(feature = "zeroize")]
+impl<T: Zeroize> Sensitive<Zeroizing<T>> {
+    /// Wraps `value` so it is overwritten with zeroes when dropped, along with every clone of it
+    pub fn zeroizing(value: T) -> Self {
+        Self::new(Zeroizing::new(value))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: Zeroize, M> Zeroize for Sensitive<T, M> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Clone, M> Clone for Sensitive<T, M> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T, M: MaskingStrategy<T>> Sensitive<T, M> {
+    /// Masks the value, which is recorded as an exposure with the `audit` feature when any of it
+    /// is shown
+    fn masked(&self) -> String {
+        let masked = M::mask(&self.0);
+
+        #[cfg(feature = "audit")]
+        if masked != MASK {
+            crate::lang::audit::record_untracked::<T>(crate::lang::Access::Masked);
+        }
+
+        masked
+    }
+}
+
+impl<T, M: MaskingStrategy<T>> Display for Sensitive<T, M> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", MASK)
+        write!(f, "{}", self.masked())
     }
 }
 
-impl<T> Debug for Sensitive<T> {
+impl<T, M: MaskingStrategy<T>> Debug for Sensitive<T, M> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("Sensitive").field(&MASK).finish()
+        f.debug_tuple("Sensitive").field(&self.masked()).finish()
     }
 }
 
-impl<T> From<T> for Sensitive<T> {
+impl<T, M> From<T> for Sensitive<T, M> {
     fn from(t: T) -> Self {
-        Self(t)
+        Self(t, PhantomData)
     }
 }
 
-impl<T: FromStr> FromStr for Sensitive<T> {
+impl<T: FromStr, M> FromStr for Sensitive<T, M> {
     type Err = T::Err;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match T::from_str(s) {
-            Ok(t) => Ok(Sensitive(t)),
+            Ok(t) => Ok(Sensitive(t, PhantomData)),
             Err(e) => Err(e),
         }
     }
 }
 
-impl<T: PartialEq> PartialEq<Self> for Sensitive<T> {
+impl<T: PartialEq, M> PartialEq<Self> for Sensitive<T, M> {
     fn eq(&self, other: &Self) -> bool {
         self.0.eq(&other.0)
     }
 }
 
-impl<T> Eq for Sensitive<T> where T: Eq {}
+impl<T, M> Eq for Sensitive<T, M> where T: Eq {}
 
-impl<T: PartialOrd> PartialOrd for Sensitive<T> {
+impl<T: PartialOrd, M> PartialOrd for Sensitive<T, M> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.0.partial_cmp(&other.0)
     }
 }
 
-impl<T: Ord> Ord for Sensitive<T> {
+impl<T: Ord, M> Ord for Sensitive<T, M> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.0.cmp(&other.0)
     }
 }
 
-impl<T: Hash> Hash for Sensitive<T> {
+impl<T: Hash, M> Hash for Sensitive<T, M> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state);
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, M> serde::Serialize for Sensitive<T, M> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.serialize_with(Redaction::current(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: serde::Deserialize<'a>, M> serde::Deserialize<'a> for Sensitive<T, M> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        T::deserialize(deserializer).map(Self::from)
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
-impl<T: Arbitrary + 'static> Arbitrary for Sensitive<T> {
+impl<T: Arbitrary + 'static, M: MaskingStrategy<T> + 'static> Arbitrary for Sensitive<T, M> {
     type Parameters = T::Parameters;
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-        T::arbitrary_with(args).prop_map(Self).boxed()
+        T::arbitrary_with(args).prop_map(Self::from).boxed()
     }
 }
 
 #[cfg(any(test, feature = "testing"))]
-impl<T: NegateArbitrary + 'static> NegateArbitrary for Sensitive<T> {
+impl<T: NegateArbitrary + 'static, M: MaskingStrategy<T> + 'static> NegateArbitrary
+    for Sensitive<T, M>
+{
     fn negate_arbitrary() -> <Self as Arbitrary>::Strategy {
-        T::negate_arbitrary().prop_map(Self).boxed()
+        T::negate_arbitrary().prop_map(Self::from).boxed()
     }
 }
 
@@ -105,6 +248,27 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn hides_any_type() {
+        struct Pin(#[allow(dead_code)] u16);
+
+        #[derive(Debug)]
+        struct Card {
+            #[allow(dead_code)]
+            pin: Sensitive<Pin>,
+        }
+
+        let card = Card {
+            pin: Sensitive::new(Pin(1234)),
+        };
+
+        assert_eq!(
+            format!("{card:?}"),
+            format!("Card {{ pin: Sensitive(\"{MASK}\") }}")
+        );
+        assert_eq!(card.pin.to_string(), MASK);
+    }
+
     proptest! {
         #[test]
         fn hides_internal_representation(s in any::<Sensitive<String>>()) {
@@ -137,5 +301,24 @@ mod tests {
         fn preserves_into(a in any::<usize>()) {
             assert_eq!(Sensitive::from(a), Sensitive::new(a));
         }
+
+        #[test]
+        fn exposes_inner_value(a in any::<String>(), b in any::<String>()) {
+            let mut s = Sensitive::new(a.clone());
+            prop_assert_eq!(s.expose(), &a);
+
+            s.expose_mut().push_str(&b);
+            prop_assert_eq!(s.get(), a + &b);
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    proptest! {
+        #[test]
+        fn zeroizes_inner_value(mut a in any::<Sensitive<String>>()) {
+            a.zeroize();
+
+            prop_assert_eq!(a.get(), "");
+        }
     }
 }
@@ -0,0 +1,5205 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(any(test, feature = "testing"))]
+use proptest::{
+    prelude::*,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{lang::Country, Error, Kind};
+
+macro_rules! subdivisions {
+    ($($variant:ident, $country:ident, $code:expr, $name:expr;)*) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        /// A subdivision of a country (like a state or province) as defined by ISO 3166-2
+        ///
+        /// Countries with several levels of subdivisions (like the regions and departments of
+        /// France) have all of them. Some countries, like Antarctica, have none.
+        pub enum Subdivision {
+            $(
+                #[doc = $name]
+                $variant,
+            )*
+        }
+
+        impl Subdivision {
+            /// Every known subdivision, grouped by country
+            pub const ALL: &'static [Subdivision] = &[$(Subdivision::$variant),*];
+
+            pub fn country(&self) -> Country {
+                match self {
+                    $(Subdivision::$variant => Country::$country,)*
+                }
+            }
+
+            /// The full ISO 3166-2 code, like `BR-SP`
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(Subdivision::$variant => concat!(stringify!($country), "-", $code),)*
+                }
+            }
+
+            /// The name of the subdivision, usually in its local language
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Subdivision::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+impl Subdivision {
+    /// The code of the subdivision inside its country, like `SP` for `BR-SP`
+    pub fn local_code(&self) -> &'static str {
+        &self.code()[3..]
+    }
+}
+
+impl FromStr for Subdivision {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Subdivision::ALL
+            .iter()
+            .copied()
+            .find(|d| d.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::FailedParsing(Kind::Subdivision, s.to_string()))
+    }
+}
+
+impl Display for Subdivision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Subdivision {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for Subdivision {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Arbitrary for Subdivision {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(Subdivision::ALL).boxed()
+    }
+}
+
+// ISO 3166-2: variant, country, code inside the country, name
+subdivisions! {
+    AD_02, AD, "02", "Canillo";
+    AD_03, AD, "03", "Encamp";
+    AD_04, AD, "04", "La Massana";
+    AD_05, AD, "05", "Ordino";
+    AD_06, AD, "06", "Sant Julià de Lòria";
+    AD_07, AD, "07", "Andorra la Vella";
+    AD_08, AD, "08", "Escaldes-Engordany";
+    AE_AJ, AE, "AJ", "'Ajmān";
+    AE_AZ, AE, "AZ", "Abū Z̧aby";
+    AE_DU, AE, "DU", "Dubayy";
+    AE_FU, AE, "FU", "Al Fujayrah";
+    AE_RK, AE, "RK", "Ra's al Khaymah";
+    AE_SH, AE, "SH", "Ash Shāriqah";
+    AE_UQ, AE, "UQ", "Umm al Qaywayn";
+    AF_BAL, AF, "BAL", "Balkh";
+    AF_BAM, AF, "BAM", "Bāmyān";
+    AF_BDG, AF, "BDG", "Bādghīs";
+    AF_BDS, AF, "BDS", "Badakhshān";
+    AF_BGL, AF, "BGL", "Baghlān";
+    AF_DAY, AF, "DAY", "Dāykundī";
+    AF_FRA, AF, "FRA", "Farāh";
+    AF_FYB, AF, "FYB", "Fāryāb";
+    AF_GHA, AF, "GHA", "Ghaznī";
+    AF_GHO, AF, "GHO", "Ghōr";
+    AF_HEL, AF, "HEL", "Helmand";
+    AF_HER, AF, "HER", "Herāt";
+    AF_JOW, AF, "JOW", "Jowzjān";
+    AF_KAB, AF, "KAB", "Kābul";
+    AF_KAN, AF, "KAN", "Kandahār";
+    AF_KAP, AF, "KAP", "Kāpīsā";
+    AF_KDZ, AF, "KDZ", "Kunduz";
+    AF_KHO, AF, "KHO", "Khōst";
+    AF_KNR, AF, "KNR", "Kunar";
+    AF_LAG, AF, "LAG", "Laghmān";
+    AF_LOG, AF, "LOG", "Lōgar";
+    AF_NAN, AF, "NAN", "Nangarhār";
+    AF_NIM, AF, "NIM", "Nīmrōz";
+    AF_NUR, AF, "NUR", "Nūristān";
+    AF_PAN, AF, "PAN", "Panjshayr";
+    AF_PAR, AF, "PAR", "Parwān";
+    AF_PIA, AF, "PIA", "Paktiyā";
+    AF_PKA, AF, "PKA", "Paktīkā";
+    AF_SAM, AF, "SAM", "Samangān";
+    AF_SAR, AF, "SAR", "Sar-e Pul";
+    AF_TAK, AF, "TAK", "Takhār";
+    AF_URU, AF, "URU", "Uruzgān";
+    AF_WAR, AF, "WAR", "Wardak";
+    AF_ZAB, AF, "ZAB", "Zābul";
+    AG_03, AG, "03", "Saint George";
+    AG_04, AG, "04", "Saint John";
+    AG_05, AG, "05", "Saint Mary";
+    AG_06, AG, "06", "Saint Paul";
+    AG_07, AG, "07", "Saint Peter";
+    AG_08, AG, "08", "Saint Philip";
+    AG_10, AG, "10", "Barbuda";
+    AG_11, AG, "11", "Redonda";
+    AL_01, AL, "01", "Berat";
+    AL_02, AL, "02", "Durrës";
+    AL_03, AL, "03", "Elbasan";
+    AL_04, AL, "04", "Fier";
+    AL_05, AL, "05", "Gjirokastër";
+    AL_06, AL, "06", "Korçë";
+    AL_07, AL, "07", "Kukës";
+    AL_08, AL, "08", "Lezhë";
+    AL_09, AL, "09", "Dibër";
+    AL_10, AL, "10", "Shkodër";
+    AL_11, AL, "11", "Tiranë";
+    AL_12, AL, "12", "Vlorë";
+    AL_BR, AL, "BR", "Berat";
+    AL_BU, AL, "BU", "Bulqizë";
+    AL_DI, AL, "DI", "Dibër";
+    AL_DL, AL, "DL", "Delvinë";
+    AL_DR, AL, "DR", "Durrës";
+    AL_DV, AL, "DV", "Devoll";
+    AL_EL, AL, "EL", "Elbasan";
+    AL_ER, AL, "ER", "Kolonjë";
+    AL_FR, AL, "FR", "Fier";
+    AL_GJ, AL, "GJ", "Gjirokastër";
+    AL_GR, AL, "GR", "Gramsh";
+    AL_HA, AL, "HA", "Has";
+    AL_KA, AL, "KA", "Kavajë";
+    AL_KB, AL, "KB", "Kurbin";
+    AL_KC, AL, "KC", "Kuçovë";
+    AL_KO, AL, "KO", "Korçë";
+    AL_KR, AL, "KR", "Krujë";
+    AL_KU, AL, "KU", "Kukës";
+    AL_LB, AL, "LB", "Librazhd";
+    AL_LE, AL, "LE", "Lezhë";
+    AL_LU, AL, "LU", "Lushnjë";
+    AL_MK, AL, "MK", "Mallakastër";
+    AL_MM, AL, "MM", "Malësi e Madhe";
+    AL_MR, AL, "MR", "Mirditë";
+    AL_MT, AL, "MT", "Mat";
+    AL_PG, AL, "PG", "Pogradec";
+    AL_PQ, AL, "PQ", "Peqin";
+    AL_PR, AL, "PR", "Përmet";
+    AL_PU, AL, "PU", "Pukë";
+    AL_SH, AL, "SH", "Shkodër";
+    AL_SK, AL, "SK", "Skrapar";
+    AL_SR, AL, "SR", "Sarandë";
+    AL_TE, AL, "TE", "Tepelenë";
+    AL_TP, AL, "TP", "Tropojë";
+    AL_TR, AL, "TR", "Tiranë";
+    AL_VL, AL, "VL", "Vlorë";
+    AM_AG, AM, "AG", "Aragac̣otn";
+    AM_AR, AM, "AR", "Ararat";
+    AM_AV, AM, "AV", "Armavir";
+    AM_ER, AM, "ER", "Erevan";
+    AM_GR, AM, "GR", "Geġark'unik'";
+    AM_KT, AM, "KT", "Kotayk'";
+    AM_LO, AM, "LO", "Loṙi";
+    AM_SH, AM, "SH", "Širak";
+    AM_SU, AM, "SU", "Syunik'";
+    AM_TV, AM, "TV", "Tavuš";
+    AM_VD, AM, "VD", "Vayoć Jor";
+    AO_BGO, AO, "BGO", "Bengo";
+    AO_BGU, AO, "BGU", "Benguela";
+    AO_BIE, AO, "BIE", "Bié";
+    AO_CAB, AO, "CAB", "Cabinda";
+    AO_CCU, AO, "CCU", "Kuando Kubango";
+    AO_CNN, AO, "CNN", "Cunene";
+    AO_CNO, AO, "CNO", "Kwanza Norte";
+    AO_CUS, AO, "CUS", "Kwanza Sul";
+    AO_HUA, AO, "HUA", "Huambo";
+    AO_HUI, AO, "HUI", "Huíla";
+    AO_LNO, AO, "LNO", "Lunda Norte";
+    AO_LSU, AO, "LSU", "Lunda Sul";
+    AO_LUA, AO, "LUA", "Luanda";
+    AO_MAL, AO, "MAL", "Malange";
+    AO_MOX, AO, "MOX", "Moxico";
+    AO_NAM, AO, "NAM", "Namibe";
+    AO_UIG, AO, "UIG", "Uíge";
+    AO_ZAI, AO, "ZAI", "Zaire";
+    AR_A, AR, "A", "Salta";
+    AR_B, AR, "B", "Buenos Aires";
+    AR_C, AR, "C", "Ciudad Autónoma de Buenos Aires";
+    AR_D, AR, "D", "San Luis";
+    AR_E, AR, "E", "Entre Ríos";
+    AR_F, AR, "F", "La Rioja";
+    AR_G, AR, "G", "Santiago del Estero";
+    AR_H, AR, "H", "Chaco";
+    AR_J, AR, "J", "San Juan";
+    AR_K, AR, "K", "Catamarca";
+    AR_L, AR, "L", "La Pampa";
+    AR_M, AR, "M", "Mendoza";
+    AR_N, AR, "N", "Misiones";
+    AR_P, AR, "P", "Formosa";
+    AR_Q, AR, "Q", "Neuquén";
+    AR_R, AR, "R", "Río Negro";
+    AR_S, AR, "S", "Santa Fe";
+    AR_T, AR, "T", "Tucumán";
+    AR_U, AR, "U", "Chubut";
+    AR_V, AR, "V", "Tierra del Fuego";
+    AR_W, AR, "W", "Corrientes";
+    AR_X, AR, "X", "Córdoba";
+    AR_Y, AR, "Y", "Jujuy";
+    AR_Z, AR, "Z", "Santa Cruz";
+    AT_1, AT, "1", "Burgenland";
+    AT_2, AT, "2", "Kärnten";
+    AT_3, AT, "3", "Niederösterreich";
+    AT_4, AT, "4", "Oberösterreich";
+    AT_5, AT, "5", "Salzburg";
+    AT_6, AT, "6", "Steiermark";
+    AT_7, AT, "7", "Tirol";
+    AT_8, AT, "8", "Vorarlberg";
+    AT_9, AT, "9", "Wien";
+    AU_ACT, AU, "ACT", "Australian Capital Territory";
+    AU_NSW, AU, "NSW", "New South Wales";
+    AU_NT, AU, "NT", "Northern Territory";
+    AU_QLD, AU, "QLD", "Queensland";
+    AU_SA, AU, "SA", "South Australia";
+    AU_TAS, AU, "TAS", "Tasmania";
+    AU_VIC, AU, "VIC", "Victoria";
+    AU_WA, AU, "WA", "Western Australia";
+    AZ_ABS, AZ, "ABS", "Abşeron";
+    AZ_AGA, AZ, "AGA", "Ağstafa";
+    AZ_AGC, AZ, "AGC", "Ağcabәdi";
+    AZ_AGM, AZ, "AGM", "Ağdam";
+    AZ_AGS, AZ, "AGS", "Ağdaş";
+    AZ_AGU, AZ, "AGU", "Ağsu";
+    AZ_AST, AZ, "AST", "Astara";
+    AZ_BA, AZ, "BA", "Bakı";
+    AZ_BAB, AZ, "BAB", "Babək";
+    AZ_BAL, AZ, "BAL", "Balakən";
+    AZ_BAR, AZ, "BAR", "Bərdə";
+    AZ_BEY, AZ, "BEY", "Beyləqan";
+    AZ_BIL, AZ, "BIL", "Biləsuvar";
+    AZ_CAB, AZ, "CAB", "Cəbrayıl";
+    AZ_CAL, AZ, "CAL", "Cəlilabad";
+    AZ_CUL, AZ, "CUL", "Culfa";
+    AZ_DAS, AZ, "DAS", "Daşkəsən";
+    AZ_FUZ, AZ, "FUZ", "Füzuli";
+    AZ_GA, AZ, "GA", "Gәncә";
+    AZ_GAD, AZ, "GAD", "Gədəbəy";
+    AZ_GOR, AZ, "GOR", "Goranboy";
+    AZ_GOY, AZ, "GOY", "Göyçay";
+    AZ_GYG, AZ, "GYG", "Göygöl";
+    AZ_HAC, AZ, "HAC", "Hacıqabul";
+    AZ_IMI, AZ, "IMI", "İmişli";
+    AZ_ISM, AZ, "ISM", "İsmayıllı";
+    AZ_KAL, AZ, "KAL", "Kəlbəcər";
+    AZ_KAN, AZ, "KAN", "Kǝngǝrli";
+    AZ_KUR, AZ, "KUR", "Kürdəmir";
+    AZ_LA, AZ, "LA", "Lәnkәran";
+    AZ_LAC, AZ, "LAC", "Laçın";
+    AZ_LAN, AZ, "LAN", "Lənkəran";
+    AZ_LER, AZ, "LER", "Lerik";
+    AZ_MAS, AZ, "MAS", "Masallı";
+    AZ_MI, AZ, "MI", "Mingәçevir";
+    AZ_NA, AZ, "NA", "Naftalan";
+    AZ_NEF, AZ, "NEF", "Neftçala";
+    AZ_NV, AZ, "NV", "Naxçıvan";
+    AZ_NX, AZ, "NX", "Naxçıvan";
+    AZ_OGU, AZ, "OGU", "Oğuz";
+    AZ_ORD, AZ, "ORD", "Ordubad";
+    AZ_QAB, AZ, "QAB", "Qәbәlә";
+    AZ_QAX, AZ, "QAX", "Qax";
+    AZ_QAZ, AZ, "QAZ", "Qazax";
+    AZ_QBA, AZ, "QBA", "Quba";
+    AZ_QBI, AZ, "QBI", "Qubadlı";
+    AZ_QOB, AZ, "QOB", "Qobustan";
+    AZ_QUS, AZ, "QUS", "Qusar";
+    AZ_SA, AZ, "SA", "Şәki";
+    AZ_SAB, AZ, "SAB", "Sabirabad";
+    AZ_SAD, AZ, "SAD", "Sәdәrәk";
+    AZ_SAH, AZ, "SAH", "Şahbuz";
+    AZ_SAK, AZ, "SAK", "Şәki";
+    AZ_SAL, AZ, "SAL", "Salyan";
+    AZ_SAR, AZ, "SAR", "Şәrur";
+    AZ_SAT, AZ, "SAT", "Saatlı";
+    AZ_SBN, AZ, "SBN", "Şabran";
+    AZ_SIY, AZ, "SIY", "Siyәzәn";
+    AZ_SKR, AZ, "SKR", "Şәmkir";
+    AZ_SM, AZ, "SM", "Sumqayıt";
+    AZ_SMI, AZ, "SMI", "Şamaxı";
+    AZ_SMX, AZ, "SMX", "Samux";
+    AZ_SR, AZ, "SR", "Şirvan";
+    AZ_SUS, AZ, "SUS", "Şuşa";
+    AZ_TAR, AZ, "TAR", "Tәrtәr";
+    AZ_TOV, AZ, "TOV", "Tovuz";
+    AZ_UCA, AZ, "UCA", "Ucar";
+    AZ_XA, AZ, "XA", "Xankәndi";
+    AZ_XAC, AZ, "XAC", "Xaçmaz";
+    AZ_XCI, AZ, "XCI", "Xocalı";
+    AZ_XIZ, AZ, "XIZ", "Xızı";
+    AZ_XVD, AZ, "XVD", "Xocavәnd";
+    AZ_YAR, AZ, "YAR", "Yardımlı";
+    AZ_YE, AZ, "YE", "Yevlax";
+    AZ_YEV, AZ, "YEV", "Yevlax";
+    AZ_ZAN, AZ, "ZAN", "Zәngilan";
+    AZ_ZAQ, AZ, "ZAQ", "Zaqatala";
+    AZ_ZAR, AZ, "ZAR", "Zәrdab";
+    BA_01, BA, "01", "Unsko-sanska županija";
+    BA_02, BA, "02", "Posavska županija";
+    BA_03, BA, "03", "Tuzlanska županija";
+    BA_04, BA, "04", "Zeničko-dobojska županija";
+    BA_05, BA, "05", "Bosansko-podrinjska županija";
+    BA_06, BA, "06", "Srednjobosanska županija";
+    BA_07, BA, "07", "Hercegovačko-neretvanska županija";
+    BA_08, BA, "08", "Zapadnohercegovačka županija";
+    BA_09, BA, "09", "Kanton Sarajevo";
+    BA_10, BA, "10", "Kanton br. 10";
+    BA_BIH, BA, "BIH", "Federacija Bosne i Hercegovine";
+    BA_BRC, BA, "BRC", "Brčko distrikt";
+    BA_SRP, BA, "SRP", "Republika Srpska";
+    BB_01, BB, "01", "Christ Church";
+    BB_02, BB, "02", "Saint Andrew";
+    BB_03, BB, "03", "Saint George";
+    BB_04, BB, "04", "Saint James";
+    BB_05, BB, "05", "Saint John";
+    BB_06, BB, "06", "Saint Joseph";
+    BB_07, BB, "07", "Saint Lucy";
+    BB_08, BB, "08", "Saint Michael";
+    BB_09, BB, "09", "Saint Peter";
+    BB_10, BB, "10", "Saint Philip";
+    BB_11, BB, "11", "Saint Thomas";
+    BD_01, BD, "01", "Bandarban";
+    BD_02, BD, "02", "Barguna";
+    BD_03, BD, "03", "Bogra";
+    BD_04, BD, "04", "Brahmanbaria";
+    BD_05, BD, "05", "Bagerhat";
+    BD_06, BD, "06", "Barisal";
+    BD_07, BD, "07", "Bhola";
+    BD_08, BD, "08", "Comilla";
+    BD_09, BD, "09", "Chandpur";
+    BD_10, BD, "10", "Chittagong";
+    BD_11, BD, "11", "Cox's Bazar";
+    BD_12, BD, "12", "Chuadanga";
+    BD_13, BD, "13", "Dhaka";
+    BD_14, BD, "14", "Dinajpur";
+    BD_15, BD, "15", "Faridpur";
+    BD_16, BD, "16", "Feni";
+    BD_17, BD, "17", "Gopalganj";
+    BD_18, BD, "18", "Gazipur";
+    BD_19, BD, "19", "Gaibandha";
+    BD_20, BD, "20", "Habiganj";
+    BD_21, BD, "21", "Jamalpur";
+    BD_22, BD, "22", "Jessore";
+    BD_23, BD, "23", "Jhenaidah";
+    BD_24, BD, "24", "Jaipurhat";
+    BD_25, BD, "25", "Jhalakati";
+    BD_26, BD, "26", "Kishoreganj";
+    BD_27, BD, "27", "Khulna";
+    BD_28, BD, "28", "Kurigram";
+    BD_29, BD, "29", "Khagrachari";
+    BD_30, BD, "30", "Kushtia";
+    BD_31, BD, "31", "Lakshmipur";
+    BD_32, BD, "32", "Lalmonirhat";
+    BD_33, BD, "33", "Manikganj";
+    BD_34, BD, "34", "Mymensingh";
+    BD_35, BD, "35", "Munshiganj";
+    BD_36, BD, "36", "Madaripur";
+    BD_37, BD, "37", "Magura";
+    BD_38, BD, "38", "Moulvibazar";
+    BD_39, BD, "39", "Meherpur";
+    BD_40, BD, "40", "Narayanganj";
+    BD_41, BD, "41", "Netrakona";
+    BD_42, BD, "42", "Narsingdi";
+    BD_43, BD, "43", "Narail";
+    BD_44, BD, "44", "Natore";
+    BD_45, BD, "45", "Nawabganj";
+    BD_46, BD, "46", "Nilphamari";
+    BD_47, BD, "47", "Noakhali";
+    BD_48, BD, "48", "Naogaon";
+    BD_49, BD, "49", "Pabna";
+    BD_50, BD, "50", "Pirojpur";
+    BD_51, BD, "51", "Patuakhali";
+    BD_52, BD, "52", "Panchagarh";
+    BD_53, BD, "53", "Rajbari";
+    BD_54, BD, "54", "Rajshahi";
+    BD_55, BD, "55", "Rangpur";
+    BD_56, BD, "56", "Rangamati";
+    BD_57, BD, "57", "Sherpur";
+    BD_58, BD, "58", "Satkhira";
+    BD_59, BD, "59", "Sirajganj";
+    BD_60, BD, "60", "Sylhet";
+    BD_61, BD, "61", "Sunamganj";
+    BD_62, BD, "62", "Shariatpur";
+    BD_63, BD, "63", "Tangail";
+    BD_64, BD, "64", "Thakurgaon";
+    BD_A, BD, "A", "Barisal";
+    BD_B, BD, "B", "Chittagong";
+    BD_C, BD, "C", "Dhaka";
+    BD_D, BD, "D", "Khulna";
+    BD_E, BD, "E", "Rajshahi";
+    BD_F, BD, "F", "Rangpur";
+    BD_G, BD, "G", "Sylhet";
+    BE_BRU, BE, "BRU", "Brussels Hoofdstedelijk Gewest";
+    BE_VAN, BE, "VAN", "Antwerpen";
+    BE_VBR, BE, "VBR", "Vlaams-Brabant";
+    BE_VLG, BE, "VLG", "Vlaams Gewest";
+    BE_VLI, BE, "VLI", "Limburg";
+    BE_VOV, BE, "VOV", "Oost-Vlaanderen";
+    BE_VWV, BE, "VWV", "West-Vlaanderen";
+    BE_WAL, BE, "WAL", "wallonne, Région";
+    BE_WBR, BE, "WBR", "Brabant wallon";
+    BE_WHT, BE, "WHT", "Hainaut";
+    BE_WLG, BE, "WLG", "Liège";
+    BE_WLX, BE, "WLX", "Luxembourg";
+    BE_WNA, BE, "WNA", "Namur";
+    BF_01, BF, "01", "Boucle du Mouhoun";
+    BF_02, BF, "02", "Cascades";
+    BF_03, BF, "03", "Centre";
+    BF_04, BF, "04", "Centre-Est";
+    BF_05, BF, "05", "Centre-Nord";
+    BF_06, BF, "06", "Centre-Ouest";
+    BF_07, BF, "07", "Centre-Sud";
+    BF_08, BF, "08", "Est";
+    BF_09, BF, "09", "Hauts-Bassins";
+    BF_10, BF, "10", "Nord";
+    BF_11, BF, "11", "Plateau-Central";
+    BF_12, BF, "12", "Sahel";
+    BF_13, BF, "13", "Sud-Ouest";
+    BF_BAL, BF, "BAL", "Balé";
+    BF_BAM, BF, "BAM", "Bam";
+    BF_BAN, BF, "BAN", "Banwa";
+    BF_BAZ, BF, "BAZ", "Bazèga";
+    BF_BGR, BF, "BGR", "Bougouriba";
+    BF_BLG, BF, "BLG", "Boulgou";
+    BF_BLK, BF, "BLK", "Boulkiemdé";
+    BF_COM, BF, "COM", "Comoé";
+    BF_GAN, BF, "GAN", "Ganzourgou";
+    BF_GNA, BF, "GNA", "Gnagna";
+    BF_GOU, BF, "GOU", "Gourma";
+    BF_HOU, BF, "HOU", "Houet";
+    BF_IOB, BF, "IOB", "Ioba";
+    BF_KAD, BF, "KAD", "Kadiogo";
+    BF_KEN, BF, "KEN", "Kénédougou";
+    BF_KMD, BF, "KMD", "Komondjari";
+    BF_KMP, BF, "KMP", "Kompienga";
+    BF_KOP, BF, "KOP", "Koulpélogo";
+    BF_KOS, BF, "KOS", "Kossi";
+    BF_KOT, BF, "KOT", "Kouritenga";
+    BF_KOW, BF, "KOW", "Kourwéogo";
+    BF_LER, BF, "LER", "Léraba";
+    BF_LOR, BF, "LOR", "Loroum";
+    BF_MOU, BF, "MOU", "Mouhoun";
+    BF_NAM, BF, "NAM", "Namentenga";
+    BF_NAO, BF, "NAO", "Nahouri";
+    BF_NAY, BF, "NAY", "Nayala";
+    BF_NOU, BF, "NOU", "Noumbiel";
+    BF_OUB, BF, "OUB", "Oubritenga";
+    BF_OUD, BF, "OUD", "Oudalan";
+    BF_PAS, BF, "PAS", "Passoré";
+    BF_PON, BF, "PON", "Poni";
+    BF_SEN, BF, "SEN", "Séno";
+    BF_SIS, BF, "SIS", "Sissili";
+    BF_SMT, BF, "SMT", "Sanmatenga";
+    BF_SNG, BF, "SNG", "Sanguié";
+    BF_SOM, BF, "SOM", "Soum";
+    BF_SOR, BF, "SOR", "Sourou";
+    BF_TAP, BF, "TAP", "Tapoa";
+    BF_TUI, BF, "TUI", "Tui";
+    BF_YAG, BF, "YAG", "Yagha";
+    BF_YAT, BF, "YAT", "Yatenga";
+    BF_ZIR, BF, "ZIR", "Ziro";
+    BF_ZON, BF, "ZON", "Zondoma";
+    BF_ZOU, BF, "ZOU", "Zoundwéogo";
+    BG_01, BG, "01", "Blagoevgrad";
+    BG_02, BG, "02", "Burgas";
+    BG_03, BG, "03", "Varna";
+    BG_04, BG, "04", "Veliko Tarnovo";
+    BG_05, BG, "05", "Vidin";
+    BG_06, BG, "06", "Vratsa";
+    BG_07, BG, "07", "Gabrovo";
+    BG_08, BG, "08", "Dobrich";
+    BG_09, BG, "09", "Kardzhali";
+    BG_10, BG, "10", "Kyustendil";
+    BG_11, BG, "11", "Lovech";
+    BG_12, BG, "12", "Montana";
+    BG_13, BG, "13", "Pazardzhik";
+    BG_14, BG, "14", "Pernik";
+    BG_15, BG, "15", "Pleven";
+    BG_16, BG, "16", "Plovdiv";
+    BG_17, BG, "17", "Razgrad";
+    BG_18, BG, "18", "Ruse";
+    BG_19, BG, "19", "Silistra";
+    BG_20, BG, "20", "Sliven";
+    BG_21, BG, "21", "Smolyan";
+    BG_22, BG, "22", "Sofia";
+    BG_23, BG, "23", "Sofia";
+    BG_24, BG, "24", "Stara Zagora";
+    BG_25, BG, "25", "Targovishte";
+    BG_26, BG, "26", "Haskovo";
+    BG_27, BG, "27", "Shumen";
+    BG_28, BG, "28", "Yambol";
+    BH_13, BH, "13", "Al Manāmah";
+    BH_14, BH, "14", "Al Janūbīyah";
+    BH_15, BH, "15", "Al Muḩarraq";
+    BH_16, BH, "16", "Al Wusţá";
+    BH_17, BH, "17", "Ash Shamālīyah";
+    BI_BB, BI, "BB", "Bubanza";
+    BI_BL, BI, "BL", "Bujumbura Rural";
+    BI_BM, BI, "BM", "Bujumbura Mairie";
+    BI_BR, BI, "BR", "Bururi";
+    BI_CA, BI, "CA", "Cankuzo";
+    BI_CI, BI, "CI", "Cibitoke";
+    BI_GI, BI, "GI", "Gitega";
+    BI_KI, BI, "KI", "Kirundo";
+    BI_KR, BI, "KR", "Karuzi";
+    BI_KY, BI, "KY", "Kayanza";
+    BI_MA, BI, "MA", "Makamba";
+    BI_MU, BI, "MU", "Muramvya";
+    BI_MW, BI, "MW", "Mwaro";
+    BI_MY, BI, "MY", "Muyinga";
+    BI_NG, BI, "NG", "Ngozi";
+    BI_RT, BI, "RT", "Rutana";
+    BI_RY, BI, "RY", "Ruyigi";
+    BJ_AK, BJ, "AK", "Atakora";
+    BJ_AL, BJ, "AL", "Alibori";
+    BJ_AQ, BJ, "AQ", "Atlantique";
+    BJ_BO, BJ, "BO", "Borgou";
+    BJ_CO, BJ, "CO", "Collines";
+    BJ_DO, BJ, "DO", "Donga";
+    BJ_KO, BJ, "KO", "Kouffo";
+    BJ_LI, BJ, "LI", "Littoral";
+    BJ_MO, BJ, "MO", "Mono";
+    BJ_OU, BJ, "OU", "Ouémé";
+    BJ_PL, BJ, "PL", "Plateau";
+    BJ_ZO, BJ, "ZO", "Zou";
+    BN_BE, BN, "BE", "Belait";
+    BN_BM, BN, "BM", "Brunei-Muara";
+    BN_TE, BN, "TE", "Temburong";
+    BN_TU, BN, "TU", "Tutong";
+    BO_B, BO, "B", "El Beni";
+    BO_C, BO, "C", "Cochabamba";
+    BO_H, BO, "H", "Chuquisaca";
+    BO_L, BO, "L", "La Paz";
+    BO_N, BO, "N", "Pando";
+    BO_O, BO, "O", "Oruro";
+    BO_P, BO, "P", "Potosí";
+    BO_S, BO, "S", "Santa Cruz";
+    BO_T, BO, "T", "Tarija";
+    BQ_BO, BQ, "BO", "Bonaire";
+    BQ_SA, BQ, "SA", "Saba";
+    BQ_SE, BQ, "SE", "Sint Eustatius";
+    BR_AC, BR, "AC", "Acre";
+    BR_AL, BR, "AL", "Alagoas";
+    BR_AM, BR, "AM", "Amazonas";
+    BR_AP, BR, "AP", "Amapá";
+    BR_BA, BR, "BA", "Bahia";
+    BR_CE, BR, "CE", "Ceará";
+    BR_DF, BR, "DF", "Distrito Federal";
+    BR_ES, BR, "ES", "Espírito Santo";
+    BR_GO, BR, "GO", "Goiás";
+    BR_MA, BR, "MA", "Maranhão";
+    BR_MG, BR, "MG", "Minas Gerais";
+    BR_MS, BR, "MS", "Mato Grosso do Sul";
+    BR_MT, BR, "MT", "Mato Grosso";
+    BR_PA, BR, "PA", "Pará";
+    BR_PB, BR, "PB", "Paraíba";
+    BR_PE, BR, "PE", "Pernambuco";
+    BR_PI, BR, "PI", "Piauí";
+    BR_PR, BR, "PR", "Paraná";
+    BR_RJ, BR, "RJ", "Rio de Janeiro";
+    BR_RN, BR, "RN", "Rio Grande do Norte";
+    BR_RO, BR, "RO", "Rondônia";
+    BR_RR, BR, "RR", "Roraima";
+    BR_RS, BR, "RS", "Rio Grande do Sul";
+    BR_SC, BR, "SC", "Santa Catarina";
+    BR_SE, BR, "SE", "Sergipe";
+    BR_SP, BR, "SP", "São Paulo";
+    BR_TO, BR, "TO", "Tocantins";
+    BS_AK, BS, "AK", "Acklins";
+    BS_BI, BS, "BI", "Bimini";
+    BS_BP, BS, "BP", "Black Point";
+    BS_BY, BS, "BY", "Berry Islands";
+    BS_CE, BS, "CE", "Central Eleuthera";
+    BS_CI, BS, "CI", "Cat Island";
+    BS_CK, BS, "CK", "Crooked Island and Long Cay";
+    BS_CO, BS, "CO", "Central Abaco";
+    BS_CS, BS, "CS", "Central Andros";
+    BS_EG, BS, "EG", "East Grand Bahama";
+    BS_EX, BS, "EX", "Exuma";
+    BS_FP, BS, "FP", "City of Freeport";
+    BS_GC, BS, "GC", "Grand Cay";
+    BS_HI, BS, "HI", "Harbour Island";
+    BS_HT, BS, "HT", "Hope Town";
+    BS_IN, BS, "IN", "Inagua";
+    BS_LI, BS, "LI", "Long Island";
+    BS_MC, BS, "MC", "Mangrove Cay";
+    BS_MG, BS, "MG", "Mayaguana";
+    BS_MI, BS, "MI", "Moore's Island";
+    BS_NE, BS, "NE", "North Eleuthera";
+    BS_NO, BS, "NO", "North Abaco";
+    BS_NS, BS, "NS", "North Andros";
+    BS_RC, BS, "RC", "Rum Cay";
+    BS_RI, BS, "RI", "Ragged Island";
+    BS_SA, BS, "SA", "South Andros";
+    BS_SE, BS, "SE", "South Eleuthera";
+    BS_SO, BS, "SO", "South Abaco";
+    BS_SS, BS, "SS", "San Salvador";
+    BS_SW, BS, "SW", "Spanish Wells";
+    BS_WG, BS, "WG", "West Grand Bahama";
+    BT_11, BT, "11", "Paro";
+    BT_12, BT, "12", "Chhukha";
+    BT_13, BT, "13", "Ha";
+    BT_14, BT, "14", "Samtse";
+    BT_15, BT, "15", "Thimphu";
+    BT_21, BT, "21", "Tsirang";
+    BT_22, BT, "22", "Dagana";
+    BT_23, BT, "23", "Punakha";
+    BT_24, BT, "24", "Wangdue Phodrang";
+    BT_31, BT, "31", "Sarpang";
+    BT_32, BT, "32", "Trongsa";
+    BT_33, BT, "33", "Bumthang";
+    BT_34, BT, "34", "Zhemgang";
+    BT_41, BT, "41", "Trashigang";
+    BT_42, BT, "42", "Monggar";
+    BT_43, BT, "43", "Pemagatshel";
+    BT_44, BT, "44", "Lhuentse";
+    BT_45, BT, "45", "Samdrup Jongkha";
+    BT_GA, BT, "GA", "Gasa";
+    BT_TY, BT, "TY", "Trashi Yangtse";
+    BW_CE, BW, "CE", "Central";
+    BW_CH, BW, "CH", "Chobe";
+    BW_FR, BW, "FR", "Francistown";
+    BW_GA, BW, "GA", "Gaborone";
+    BW_GH, BW, "GH", "Ghanzi";
+    BW_JW, BW, "JW", "Jwaneng";
+    BW_KG, BW, "KG", "Kgalagadi";
+    BW_KL, BW, "KL", "Kgatleng";
+    BW_KW, BW, "KW", "Kweneng";
+    BW_LO, BW, "LO", "Lobatse";
+    BW_NE, BW, "NE", "North East";
+    BW_NW, BW, "NW", "North West";
+    BW_SE, BW, "SE", "South East";
+    BW_SO, BW, "SO", "Southern";
+    BW_SP, BW, "SP", "Selibe Phikwe";
+    BW_ST, BW, "ST", "Sowa Town";
+    BY_BR, BY, "BR", "Bresckaja voblasć";
+    BY_HM, BY, "HM", "Gorod Minsk";
+    BY_HO, BY, "HO", "Gomel'skaja oblast'";
+    BY_HR, BY, "HR", "Grodnenskaja oblast'";
+    BY_MA, BY, "MA", "Mahilioŭskaja voblasć";
+    BY_MI, BY, "MI", "Minskaja oblast'";
+    BY_VI, BY, "VI", "Viciebskaja voblasć";
+    BZ_CY, BZ, "CY", "Cayo";
+    BZ_CZL, BZ, "CZL", "Corozal";
+    BZ_OW, BZ, "OW", "Orange Walk";
+    BZ_SC, BZ, "SC", "Stann Creek";
+    BZ_TOL, BZ, "TOL", "Toledo";
+    CA_AB, CA, "AB", "Alberta";
+    CA_BC, CA, "BC", "British Columbia";
+    CA_MB, CA, "MB", "Manitoba";
+    CA_NB, CA, "NB", "New Brunswick";
+    CA_NL, CA, "NL", "Newfoundland and Labrador";
+    CA_NS, CA, "NS", "Nova Scotia";
+    CA_NT, CA, "NT", "Northwest Territories";
+    CA_NU, CA, "NU", "Nunavut";
+    CA_ON, CA, "ON", "Ontario";
+    CA_PE, CA, "PE", "Prince Edward Island";
+    CA_QC, CA, "QC", "Quebec";
+    CA_SK, CA, "SK", "Saskatchewan";
+    CA_YT, CA, "YT", "Yukon";
+    CD_BC, CD, "BC", "Bas-Congo";
+    CD_BN, CD, "BN", "Bandundu";
+    CD_EQ, CD, "EQ", "Équateur";
+    CD_KA, CD, "KA", "Katanga";
+    CD_KE, CD, "KE", "Kasai-Oriental";
+    CD_KN, CD, "KN", "Kinshasa";
+    CD_KW, CD, "KW", "Kasai-Occidental";
+    CD_MA, CD, "MA", "Maniema";
+    CD_NK, CD, "NK", "Nord-Kivu";
+    CD_OR, CD, "OR", "Orientale";
+    CD_SK, CD, "SK", "Sud-Kivu";
+    CF_AC, CF, "AC", "Ouham";
+    CF_BB, CF, "BB", "Bamïngï-Bangoran";
+    CF_BGF, CF, "BGF", "Bangî";
+    CF_BK, CF, "BK", "Basse-Kotto";
+    CF_HK, CF, "HK", "Haute-Kotto";
+    CF_HM, CF, "HM", "Haut-Mbomou";
+    CF_HS, CF, "HS", "Haute-Sangha / Mambéré-Kadéï";
+    CF_KB, CF, "KB", "Gïrïbïngï";
+    CF_KG, CF, "KG", "Kemö-Gïrïbïngï";
+    CF_LB, CF, "LB", "Lobaye";
+    CF_MB, CF, "MB", "Mbomou";
+    CF_MP, CF, "MP", "Ömbëlä-Pökö";
+    CF_NM, CF, "NM", "Nana-Mambéré";
+    CF_OP, CF, "OP", "Ouham-Pendé";
+    CF_SE, CF, "SE", "Sangä";
+    CF_UK, CF, "UK", "Ouaka";
+    CF_VK, CF, "VK", "Vakaga";
+    CG_11, CG, "11", "Bouenza";
+    CG_12, CG, "12", "Pool";
+    CG_13, CG, "13", "Sangha";
+    CG_14, CG, "14", "Plateaux";
+    CG_15, CG, "15", "Cuvette-Ouest";
+    CG_16, CG, "16", "Pointe-Noire";
+    CG_2, CG, "2", "Lékoumou";
+    CG_5, CG, "5", "Kouilou";
+    CG_7, CG, "7", "Likouala";
+    CG_8, CG, "8", "Cuvette";
+    CG_9, CG, "9", "Niari";
+    CG_BZV, CG, "BZV", "Brazzaville";
+    CH_AG, CH, "AG", "Aargau";
+    CH_AI, CH, "AI", "Appenzell Innerrhoden";
+    CH_AR, CH, "AR", "Appenzell Ausserrhoden";
+    CH_BE, CH, "BE", "Bern";
+    CH_BL, CH, "BL", "Basel-Landschaft";
+    CH_BS, CH, "BS", "Basel-Stadt";
+    CH_FR, CH, "FR", "Freiburg";
+    CH_GE, CH, "GE", "Genève";
+    CH_GL, CH, "GL", "Glarus";
+    CH_GR, CH, "GR", "Graubünden";
+    CH_JU, CH, "JU", "Jura";
+    CH_LU, CH, "LU", "Luzern";
+    CH_NE, CH, "NE", "Neuchâtel";
+    CH_NW, CH, "NW", "Nidwalden";
+    CH_OW, CH, "OW", "Obwalden";
+    CH_SG, CH, "SG", "Sankt Gallen";
+    CH_SH, CH, "SH", "Schaffhausen";
+    CH_SO, CH, "SO", "Solothurn";
+    CH_SZ, CH, "SZ", "Schwyz";
+    CH_TG, CH, "TG", "Thurgau";
+    CH_TI, CH, "TI", "Ticino";
+    CH_UR, CH, "UR", "Uri";
+    CH_VD, CH, "VD", "Vaud";
+    CH_VS, CH, "VS", "Valais";
+    CH_ZG, CH, "ZG", "Zug";
+    CH_ZH, CH, "ZH", "Zürich";
+    CI_01, CI, "01", "Lagunes";
+    CI_02, CI, "02", "Haut-Sassandra";
+    CI_03, CI, "03", "Savanes";
+    CI_04, CI, "04", "Vallée du Bandama";
+    CI_05, CI, "05", "Moyen-Comoé";
+    CI_06, CI, "06", "18 Montagnes";
+    CI_07, CI, "07", "Lacs";
+    CI_08, CI, "08", "Zanzan";
+    CI_09, CI, "09", "Bas-Sassandra";
+    CI_10, CI, "10", "Denguélé";
+    CI_11, CI, "11", "Nzi-Comoé";
+    CI_12, CI, "12", "Marahoué";
+    CI_13, CI, "13", "Sud-Comoé";
+    CI_14, CI, "14", "Worodougou";
+    CI_15, CI, "15", "Sud-Bandama";
+    CI_16, CI, "16", "Agnébi";
+    CI_17, CI, "17", "Bafing";
+    CI_18, CI, "18", "Fromager";
+    CI_19, CI, "19", "Moyen-Cavally";
+    CL_AI, CL, "AI", "Aysén";
+    CL_AN, CL, "AN", "Antofagasta";
+    CL_AP, CL, "AP", "Arica y Parinacota";
+    CL_AR, CL, "AR", "Araucanía";
+    CL_AT, CL, "AT", "Atacama";
+    CL_BI, CL, "BI", "Biobío";
+    CL_CO, CL, "CO", "Coquimbo";
+    CL_LI, CL, "LI", "Libertador General Bernardo O'Higgins";
+    CL_LL, CL, "LL", "Los Lagos";
+    CL_LR, CL, "LR", "Los Ríos";
+    CL_MA, CL, "MA", "Magallanes";
+    CL_ML, CL, "ML", "Maule";
+    CL_RM, CL, "RM", "Región Metropolitana de Santiago";
+    CL_TA, CL, "TA", "Tarapacá";
+    CL_VS, CL, "VS", "Valparaíso";
+    CM_AD, CM, "AD", "Adamaoua";
+    CM_CE, CM, "CE", "Centre";
+    CM_EN, CM, "EN", "Extrême-Nord";
+    CM_ES, CM, "ES", "East";
+    CM_LT, CM, "LT", "Littoral";
+    CM_NO, CM, "NO", "Nord";
+    CM_NW, CM, "NW", "Nord-Ouest";
+    CM_OU, CM, "OU", "Ouest";
+    CM_SU, CM, "SU", "South";
+    CM_SW, CM, "SW", "South-West";
+    CN_11, CN, "11", "Beijing";
+    CN_12, CN, "12", "Tianjin";
+    CN_13, CN, "13", "Hebei";
+    CN_14, CN, "14", "Shanxi";
+    CN_15, CN, "15", "Nei Mongol";
+    CN_21, CN, "21", "Liaoning";
+    CN_22, CN, "22", "Jilin";
+    CN_23, CN, "23", "Heilongjiang";
+    CN_31, CN, "31", "Shanghai";
+    CN_32, CN, "32", "Jiangsu";
+    CN_33, CN, "33", "Zhejiang";
+    CN_34, CN, "34", "Anhui";
+    CN_35, CN, "35", "Fujian";
+    CN_36, CN, "36", "Jiangxi";
+    CN_37, CN, "37", "Shandong";
+    CN_41, CN, "41", "Henan";
+    CN_42, CN, "42", "Hubei";
+    CN_43, CN, "43", "Hunan";
+    CN_44, CN, "44", "Guangdong";
+    CN_45, CN, "45", "Guangxi";
+    CN_46, CN, "46", "Hainan";
+    CN_50, CN, "50", "Chongqing";
+    CN_51, CN, "51", "Sichuan";
+    CN_52, CN, "52", "Guizhou";
+    CN_53, CN, "53", "Yunnan";
+    CN_54, CN, "54", "Xizang";
+    CN_61, CN, "61", "Shaanxi";
+    CN_62, CN, "62", "Gansu";
+    CN_63, CN, "63", "Qinghai";
+    CN_64, CN, "64", "Ningxia";
+    CN_65, CN, "65", "Xinjiang";
+    CN_71, CN, "71", "Taiwan";
+    CN_91, CN, "91", "Hong Kong";
+    CN_92, CN, "92", "Aomen";
+    CO_AMA, CO, "AMA", "Amazonas";
+    CO_ANT, CO, "ANT", "Antioquia";
+    CO_ARA, CO, "ARA", "Arauca";
+    CO_ATL, CO, "ATL", "Atlántico";
+    CO_BOL, CO, "BOL", "Bolívar";
+    CO_BOY, CO, "BOY", "Boyacá";
+    CO_CAL, CO, "CAL", "Caldas";
+    CO_CAQ, CO, "CAQ", "Caquetá";
+    CO_CAS, CO, "CAS", "Casanare";
+    CO_CAU, CO, "CAU", "Cauca";
+    CO_CES, CO, "CES", "Cesar";
+    CO_CHO, CO, "CHO", "Chocó";
+    CO_COR, CO, "COR", "Córdoba";
+    CO_CUN, CO, "CUN", "Cundinamarca";
+    CO_DC, CO, "DC", "Distrito Capital de Bogotá";
+    CO_GUA, CO, "GUA", "Guainía";
+    CO_GUV, CO, "GUV", "Guaviare";
+    CO_HUI, CO, "HUI", "Huila";
+    CO_LAG, CO, "LAG", "La Guajira";
+    CO_MAG, CO, "MAG", "Magdalena";
+    CO_MET, CO, "MET", "Meta";
+    CO_NAR, CO, "NAR", "Nariño";
+    CO_NSA, CO, "NSA", "Norte de Santander";
+    CO_PUT, CO, "PUT", "Putumayo";
+    CO_QUI, CO, "QUI", "Quindío";
+    CO_RIS, CO, "RIS", "Risaralda";
+    CO_SAN, CO, "SAN", "Santander";
+    CO_SAP, CO, "SAP", "San Andrés, Providencia y Santa Catalina";
+    CO_SUC, CO, "SUC", "Sucre";
+    CO_TOL, CO, "TOL", "Tolima";
+    CO_VAC, CO, "VAC", "Valle del Cauca";
+    CO_VAU, CO, "VAU", "Vaupés";
+    CO_VID, CO, "VID", "Vichada";
+    CR_A, CR, "A", "Alajuela";
+    CR_C, CR, "C", "Cartago";
+    CR_G, CR, "G", "Guanacaste";
+    CR_H, CR, "H", "Heredia";
+    CR_L, CR, "L", "Limón";
+    CR_P, CR, "P", "Puntarenas";
+    CR_SJ, CR, "SJ", "San José";
+    CU_01, CU, "01", "Pinar del Río";
+    CU_03, CU, "03", "La Habana";
+    CU_04, CU, "04", "Matanzas";
+    CU_05, CU, "05", "Villa Clara";
+    CU_06, CU, "06", "Cienfuegos";
+    CU_07, CU, "07", "Sancti Spíritus";
+    CU_08, CU, "08", "Ciego de Ávila";
+    CU_09, CU, "09", "Camagüey";
+    CU_10, CU, "10", "Las Tunas";
+    CU_11, CU, "11", "Holguín";
+    CU_12, CU, "12", "Granma";
+    CU_13, CU, "13", "Santiago de Cuba";
+    CU_14, CU, "14", "Guantánamo";
+    CU_15, CU, "15", "Artemisa";
+    CU_16, CU, "16", "Mayabeque";
+    CU_99, CU, "99", "Isla de la Juventud";
+    CV_B, CV, "B", "Ilhas de Barlavento";
+    CV_BR, CV, "BR", "Brava";
+    CV_BV, CV, "BV", "Boa Vista";
+    CV_CA, CV, "CA", "Santa Catarina";
+    CV_CF, CV, "CF", "Santa Catarina do Fogo";
+    CV_CR, CV, "CR", "Santa Cruz";
+    CV_MA, CV, "MA", "Maio";
+    CV_MO, CV, "MO", "Mosteiros";
+    CV_PA, CV, "PA", "Paul";
+    CV_PN, CV, "PN", "Porto Novo";
+    CV_PR, CV, "PR", "Praia";
+    CV_RB, CV, "RB", "Ribeira Brava";
+    CV_RG, CV, "RG", "Ribeira Grande";
+    CV_RS, CV, "RS", "Ribeira Grande de Santiago";
+    CV_S, CV, "S", "Ilhas de Sotavento";
+    CV_SD, CV, "SD", "São Domingos";
+    CV_SF, CV, "SF", "São Filipe";
+    CV_SL, CV, "SL", "Sal";
+    CV_SM, CV, "SM", "São Miguel";
+    CV_SO, CV, "SO", "São Lourenço dos Órgãos";
+    CV_SS, CV, "SS", "São Salvador do Mundo";
+    CV_SV, CV, "SV", "São Vicente";
+    CV_TA, CV, "TA", "Tarrafal";
+    CV_TS, CV, "TS", "Tarrafal de São Nicolau";
+    CY_01, CY, "01", "Lefkoşa";
+    CY_02, CY, "02", "Lemesos";
+    CY_03, CY, "03", "Larnaka";
+    CY_04, CY, "04", "Ammochostos";
+    CY_05, CY, "05", "Baf";
+    CY_06, CY, "06", "Girne";
+    CZ_101, CZ, "101", "Praha 1";
+    CZ_102, CZ, "102", "Praha 2";
+    CZ_103, CZ, "103", "Praha 3";
+    CZ_104, CZ, "104", "Praha 4";
+    CZ_105, CZ, "105", "Praha 5";
+    CZ_106, CZ, "106", "Praha 6";
+    CZ_107, CZ, "107", "Praha 7";
+    CZ_108, CZ, "108", "Praha 8";
+    CZ_109, CZ, "109", "Praha 9";
+    CZ_10A, CZ, "10A", "Praha 10";
+    CZ_10B, CZ, "10B", "Praha 11";
+    CZ_10C, CZ, "10C", "Praha 12";
+    CZ_10D, CZ, "10D", "Praha 13";
+    CZ_10E, CZ, "10E", "Praha 14";
+    CZ_10F, CZ, "10F", "Praha 15";
+    CZ_201, CZ, "201", "Benešov";
+    CZ_202, CZ, "202", "Beroun";
+    CZ_203, CZ, "203", "Kladno";
+    CZ_204, CZ, "204", "Kolín";
+    CZ_205, CZ, "205", "Kutná Hora";
+    CZ_206, CZ, "206", "Mělník";
+    CZ_207, CZ, "207", "Mladá Boleslav";
+    CZ_208, CZ, "208", "Nymburk";
+    CZ_209, CZ, "209", "Praha-východ";
+    CZ_20A, CZ, "20A", "Praha-západ";
+    CZ_20B, CZ, "20B", "Příbram";
+    CZ_20C, CZ, "20C", "Rakovník";
+    CZ_311, CZ, "311", "České Budějovice";
+    CZ_312, CZ, "312", "Český Krumlov";
+    CZ_313, CZ, "313", "Jindřichův Hradec";
+    CZ_314, CZ, "314", "Písek";
+    CZ_315, CZ, "315", "Prachatice";
+    CZ_316, CZ, "316", "Strakonice";
+    CZ_317, CZ, "317", "Tábor";
+    CZ_321, CZ, "321", "Domažlice";
+    CZ_322, CZ, "322", "Klatovy";
+    CZ_323, CZ, "323", "Plzeň-město";
+    CZ_324, CZ, "324", "Plzeň-jih";
+    CZ_325, CZ, "325", "Plzeň-sever";
+    CZ_326, CZ, "326", "Rokycany";
+    CZ_327, CZ, "327", "Tachov";
+    CZ_411, CZ, "411", "Cheb";
+    CZ_412, CZ, "412", "Karlovy Vary";
+    CZ_413, CZ, "413", "Sokolov";
+    CZ_421, CZ, "421", "Děčín";
+    CZ_422, CZ, "422", "Chomutov";
+    CZ_423, CZ, "423", "Litoměřice";
+    CZ_424, CZ, "424", "Louny";
+    CZ_425, CZ, "425", "Most";
+    CZ_426, CZ, "426", "Teplice";
+    CZ_427, CZ, "427", "Ústí nad Labem";
+    CZ_511, CZ, "511", "Česká Lípa";
+    CZ_512, CZ, "512", "Jablonec nad Nisou";
+    CZ_513, CZ, "513", "Liberec";
+    CZ_514, CZ, "514", "Semily";
+    CZ_521, CZ, "521", "Hradec Králové";
+    CZ_522, CZ, "522", "Jičín";
+    CZ_523, CZ, "523", "Náchod";
+    CZ_524, CZ, "524", "Rychnov nad Kněžnou";
+    CZ_525, CZ, "525", "Trutnov";
+    CZ_531, CZ, "531", "Chrudim";
+    CZ_532, CZ, "532", "Pardubice";
+    CZ_533, CZ, "533", "Svitavy";
+    CZ_534, CZ, "534", "Ústí nad Orlicí";
+    CZ_611, CZ, "611", "Havlíčkův Brod";
+    CZ_612, CZ, "612", "Jihlava";
+    CZ_613, CZ, "613", "Pelhřimov";
+    CZ_614, CZ, "614", "Třebíč";
+    CZ_615, CZ, "615", "Žd'ár nad Sázavou";
+    CZ_621, CZ, "621", "Blansko";
+    CZ_622, CZ, "622", "Brno-město";
+    CZ_623, CZ, "623", "Brno-venkov";
+    CZ_624, CZ, "624", "Břeclav";
+    CZ_625, CZ, "625", "Hodonín";
+    CZ_626, CZ, "626", "Vyškov";
+    CZ_627, CZ, "627", "Znojmo";
+    CZ_711, CZ, "711", "Jeseník";
+    CZ_712, CZ, "712", "Olomouc";
+    CZ_713, CZ, "713", "Prostĕjov";
+    CZ_714, CZ, "714", "Přerov";
+    CZ_715, CZ, "715", "Šumperk";
+    CZ_721, CZ, "721", "Kromĕříž";
+    CZ_722, CZ, "722", "Uherské Hradištĕ";
+    CZ_723, CZ, "723", "Vsetín";
+    CZ_724, CZ, "724", "Zlín";
+    CZ_801, CZ, "801", "Bruntál";
+    CZ_802, CZ, "802", "Frýdek Místek";
+    CZ_803, CZ, "803", "Karviná";
+    CZ_804, CZ, "804", "Nový Jičín";
+    CZ_805, CZ, "805", "Opava";
+    CZ_806, CZ, "806", "Ostrava město";
+    CZ_JC, CZ, "JC", "Jihočeský kraj";
+    CZ_JM, CZ, "JM", "Jihomoravský kraj";
+    CZ_KA, CZ, "KA", "Karlovarský kraj";
+    CZ_KR, CZ, "KR", "Královéhradecký kraj";
+    CZ_LI, CZ, "LI", "Liberecký kraj";
+    CZ_MO, CZ, "MO", "Moravskoslezský kraj";
+    CZ_OL, CZ, "OL", "Olomoucký kraj";
+    CZ_PA, CZ, "PA", "Pardubický kraj";
+    CZ_PL, CZ, "PL", "Plzeňský kraj";
+    CZ_PR, CZ, "PR", "Praha, hlavní mešto";
+    CZ_ST, CZ, "ST", "Středočeský kraj";
+    CZ_US, CZ, "US", "Ústecký kraj";
+    CZ_VY, CZ, "VY", "Vysočina";
+    CZ_ZL, CZ, "ZL", "Zlínský kraj";
+    DE_BB, DE, "BB", "Brandenburg";
+    DE_BE, DE, "BE", "Berlin";
+    DE_BW, DE, "BW", "Baden-Württemberg";
+    DE_BY, DE, "BY", "Bayern";
+    DE_HB, DE, "HB", "Bremen";
+    DE_HE, DE, "HE", "Hessen";
+    DE_HH, DE, "HH", "Hamburg";
+    DE_MV, DE, "MV", "Mecklenburg-Vorpommern";
+    DE_NI, DE, "NI", "Niedersachsen";
+    DE_NW, DE, "NW", "Nordrhein-Westfalen";
+    DE_RP, DE, "RP", "Rheinland-Pfalz";
+    DE_SH, DE, "SH", "Schleswig-Holstein";
+    DE_SL, DE, "SL", "Saarland";
+    DE_SN, DE, "SN", "Sachsen";
+    DE_ST, DE, "ST", "Sachsen-Anhalt";
+    DE_TH, DE, "TH", "Thüringen";
+    DJ_AR, DJ, "AR", "Arta";
+    DJ_AS, DJ, "AS", "Ali Sabieh";
+    DJ_DI, DJ, "DI", "Dikhīl";
+    DJ_OB, DJ, "OB", "Obock";
+    DJ_TA, DJ, "TA", "Tadjourah";
+    DK_81, DK, "81", "Nordjylland";
+    DK_82, DK, "82", "Midtjylland";
+    DK_83, DK, "83", "Syddanmark";
+    DK_84, DK, "84", "Hovedstaden";
+    DK_85, DK, "85", "Sjælland";
+    DM_02, DM, "02", "Saint Andrew";
+    DM_03, DM, "03", "Saint David";
+    DM_04, DM, "04", "Saint George";
+    DM_05, DM, "05", "Saint John";
+    DM_06, DM, "06", "Saint Joseph";
+    DM_07, DM, "07", "Saint Luke";
+    DM_08, DM, "08", "Saint Mark";
+    DM_09, DM, "09", "Saint Patrick";
+    DM_10, DM, "10", "Saint Paul";
+    DM_11, DM, "11", "Saint Peter";
+    DO_01, DO, "01", "Distrito Nacional";
+    DO_02, DO, "02", "Azua";
+    DO_03, DO, "03", "Baoruco";
+    DO_04, DO, "04", "Barahona";
+    DO_05, DO, "05", "Dajabón";
+    DO_06, DO, "06", "Duarte";
+    DO_07, DO, "07", "La Estrelleta";
+    DO_08, DO, "08", "El Seibo";
+    DO_09, DO, "09", "Espaillat";
+    DO_10, DO, "10", "Independencia";
+    DO_11, DO, "11", "La Altagracia";
+    DO_12, DO, "12", "La Romana";
+    DO_13, DO, "13", "La Vega";
+    DO_14, DO, "14", "María Trinidad Sánchez";
+    DO_15, DO, "15", "Monte Cristi";
+    DO_16, DO, "16", "Pedernales";
+    DO_17, DO, "17", "Peravia";
+    DO_18, DO, "18", "Puerto Plata";
+    DO_19, DO, "19", "Hermanas Mirabal";
+    DO_20, DO, "20", "Samaná";
+    DO_21, DO, "21", "San Cristóbal";
+    DO_22, DO, "22", "San Juan";
+    DO_23, DO, "23", "San Pedro de Macorís";
+    DO_24, DO, "24", "Sánchez Ramírez";
+    DO_25, DO, "25", "Santiago";
+    DO_26, DO, "26", "Santiago Rodríguez";
+    DO_27, DO, "27", "Valverde";
+    DO_28, DO, "28", "Monseñor Nouel";
+    DO_29, DO, "29", "Monte Plata";
+    DO_30, DO, "30", "Hato Mayor";
+    DO_31, DO, "31", "San José de Ocoa";
+    DO_32, DO, "32", "Santo Domingo";
+    DO_33, DO, "33", "Cibao Nordeste";
+    DO_34, DO, "34", "Cibao Noroeste";
+    DO_35, DO, "35", "Cibao Norte";
+    DO_36, DO, "36", "Cibao Sur";
+    DO_37, DO, "37", "El Valle";
+    DO_38, DO, "38", "Enriquillo";
+    DO_39, DO, "39", "Higuamo";
+    DO_40, DO, "40", "Ozama";
+    DO_41, DO, "41", "Valdesia";
+    DO_42, DO, "42", "Yuma";
+    DZ_01, DZ, "01", "Adrar";
+    DZ_02, DZ, "02", "Chlef";
+    DZ_03, DZ, "03", "Laghouat";
+    DZ_04, DZ, "04", "Oum el Bouaghi";
+    DZ_05, DZ, "05", "Batna";
+    DZ_06, DZ, "06", "Béjaïa";
+    DZ_07, DZ, "07", "Biskra";
+    DZ_08, DZ, "08", "Béchar";
+    DZ_09, DZ, "09", "Blida";
+    DZ_10, DZ, "10", "Bouira";
+    DZ_11, DZ, "11", "Tamanrasset";
+    DZ_12, DZ, "12", "Tébessa";
+    DZ_13, DZ, "13", "Tlemcen";
+    DZ_14, DZ, "14", "Tiaret";
+    DZ_15, DZ, "15", "Tizi Ouzou";
+    DZ_16, DZ, "16", "Alger";
+    DZ_17, DZ, "17", "Djelfa";
+    DZ_18, DZ, "18", "Jijel";
+    DZ_19, DZ, "19", "Sétif";
+    DZ_20, DZ, "20", "Saïda";
+    DZ_21, DZ, "21", "Skikda";
+    DZ_22, DZ, "22", "Sidi Bel Abbès";
+    DZ_23, DZ, "23", "Annaba";
+    DZ_24, DZ, "24", "Guelma";
+    DZ_25, DZ, "25", "Constantine";
+    DZ_26, DZ, "26", "Médéa";
+    DZ_27, DZ, "27", "Mostaganem";
+    DZ_28, DZ, "28", "Msila";
+    DZ_29, DZ, "29", "Mascara";
+    DZ_30, DZ, "30", "Ouargla";
+    DZ_31, DZ, "31", "Oran";
+    DZ_32, DZ, "32", "El Bayadh";
+    DZ_33, DZ, "33", "Illizi";
+    DZ_34, DZ, "34", "Bordj Bou Arréridj";
+    DZ_35, DZ, "35", "Boumerdès";
+    DZ_36, DZ, "36", "El Tarf";
+    DZ_37, DZ, "37", "Tindouf";
+    DZ_38, DZ, "38", "Tissemsilt";
+    DZ_39, DZ, "39", "El Oued";
+    DZ_40, DZ, "40", "Khenchela";
+    DZ_41, DZ, "41", "Souk Ahras";
+    DZ_42, DZ, "42", "Tipaza";
+    DZ_43, DZ, "43", "Mila";
+    DZ_44, DZ, "44", "Aïn Defla";
+    DZ_45, DZ, "45", "Naama";
+    DZ_46, DZ, "46", "Aïn Témouchent";
+    DZ_47, DZ, "47", "Ghardaïa";
+    DZ_48, DZ, "48", "Relizane";
+    EC_A, EC, "A", "Azuay";
+    EC_B, EC, "B", "Bolívar";
+    EC_C, EC, "C", "Carchi";
+    EC_D, EC, "D", "Orellana";
+    EC_E, EC, "E", "Esmeraldas";
+    EC_F, EC, "F", "Cañar";
+    EC_G, EC, "G", "Guayas";
+    EC_H, EC, "H", "Chimborazo";
+    EC_I, EC, "I", "Imbabura";
+    EC_L, EC, "L", "Loja";
+    EC_M, EC, "M", "Manabí";
+    EC_N, EC, "N", "Napo";
+    EC_O, EC, "O", "El Oro";
+    EC_P, EC, "P", "Pichincha";
+    EC_R, EC, "R", "Los Ríos";
+    EC_S, EC, "S", "Morona-Santiago";
+    EC_SD, EC, "SD", "Santo Domingo de los Tsáchilas";
+    EC_SE, EC, "SE", "Santa Elena";
+    EC_T, EC, "T", "Tungurahua";
+    EC_U, EC, "U", "Sucumbíos";
+    EC_W, EC, "W", "Galápagos";
+    EC_X, EC, "X", "Cotopaxi";
+    EC_Y, EC, "Y", "Pastaza";
+    EC_Z, EC, "Z", "Zamora-Chinchipe";
+    EE_37, EE, "37", "Harjumaa";
+    EE_39, EE, "39", "Hiiumaa";
+    EE_44, EE, "44", "Ida-Virumaa";
+    EE_49, EE, "49", "Jõgevamaa";
+    EE_51, EE, "51", "Järvamaa";
+    EE_57, EE, "57", "Läänemaa";
+    EE_59, EE, "59", "Lääne-Virumaa";
+    EE_65, EE, "65", "Põlvamaa";
+    EE_67, EE, "67", "Pärnumaa";
+    EE_70, EE, "70", "Raplamaa";
+    EE_74, EE, "74", "Saaremaa";
+    EE_78, EE, "78", "Tartumaa";
+    EE_82, EE, "82", "Valgamaa";
+    EE_84, EE, "84", "Viljandimaa";
+    EE_86, EE, "86", "Võrumaa";
+    EG_ALX, EG, "ALX", "Al Iskandarīyah";
+    EG_ASN, EG, "ASN", "Aswān";
+    EG_AST, EG, "AST", "Asyūţ";
+    EG_BA, EG, "BA", "Al Baḩr al Aḩmar";
+    EG_BH, EG, "BH", "Al Buḩayrah";
+    EG_BNS, EG, "BNS", "Banī Suwayf";
+    EG_C, EG, "C", "Al Qāhirah";
+    EG_DK, EG, "DK", "Ad Daqahlīyah";
+    EG_DT, EG, "DT", "Dumyāţ";
+    EG_FYM, EG, "FYM", "Al Fayyūm";
+    EG_GH, EG, "GH", "Al Gharbīyah";
+    EG_GZ, EG, "GZ", "Al Jīzah";
+    EG_IS, EG, "IS", "Al Ismā'īlīyah";
+    EG_JS, EG, "JS", "Janūb Sīnā'";
+    EG_KB, EG, "KB", "Al Qalyūbīyah";
+    EG_KFS, EG, "KFS", "Kafr ash Shaykh";
+    EG_KN, EG, "KN", "Qinā";
+    EG_LX, EG, "LX", "Al Uqşur";
+    EG_MN, EG, "MN", "Al Minyā";
+    EG_MNF, EG, "MNF", "Al Minūfīyah";
+    EG_MT, EG, "MT", "Maţrūḩ";
+    EG_PTS, EG, "PTS", "Būr Sa‘īd";
+    EG_SHG, EG, "SHG", "Sūhāj";
+    EG_SHR, EG, "SHR", "Ash Sharqīyah";
+    EG_SIN, EG, "SIN", "Shamāl Sīnā'";
+    EG_SUZ, EG, "SUZ", "As Suways";
+    EG_WAD, EG, "WAD", "Al Wādī al Jadīd";
+    ER_AN, ER, "AN", "Ansabā";
+    ER_DK, ER, "DK", "Debubawi K’eyyĭḥ Baḥri";
+    ER_DU, ER, "DU", "Al Janūbī";
+    ER_GB, ER, "GB", "Gash-Barka";
+    ER_MA, ER, "MA", "Al Awsaţ";
+    ER_SK, ER, "SK", "Semienawi K’eyyĭḥ Baḥri";
+    ES_A, ES, "A", "Alacant*";
+    ES_AB, ES, "AB", "Albacete";
+    ES_AL, ES, "AL", "Almería";
+    ES_AN, ES, "AN", "Andalucía";
+    ES_AR, ES, "AR", "Aragón";
+    ES_AS, ES, "AS", "Asturias, Principado de";
+    ES_AV, ES, "AV", "Ávila";
+    ES_B, ES, "B", "Barcelona";
+    ES_BA, ES, "BA", "Badajoz";
+    ES_BI, ES, "BI", "Bizkaia*";
+    ES_BU, ES, "BU", "Burgos";
+    ES_C, ES, "C", "A Coruña";
+    ES_CA, ES, "CA", "Cádiz";
+    ES_CB, ES, "CB", "Cantabria";
+    ES_CC, ES, "CC", "Cáceres";
+    ES_CE, ES, "CE", "Ceuta";
+    ES_CL, ES, "CL", "Castilla y León";
+    ES_CM, ES, "CM", "Castilla-La Mancha";
+    ES_CN, ES, "CN", "Canarias";
+    ES_CO, ES, "CO", "Córdoba";
+    ES_CR, ES, "CR", "Ciudad Real";
+    ES_CS, ES, "CS", "Castelló*";
+    ES_CT, ES, "CT", "Catalunya";
+    ES_CU, ES, "CU", "Cuenca";
+    ES_EX, ES, "EX", "Extremadura";
+    ES_GA, ES, "GA", "Galicia";
+    ES_GC, ES, "GC", "Las Palmas";
+    ES_GI, ES, "GI", "Girona";
+    ES_GR, ES, "GR", "Granada";
+    ES_GU, ES, "GU", "Guadalajara";
+    ES_H, ES, "H", "Huelva";
+    ES_HU, ES, "HU", "Huesca";
+    ES_IB, ES, "IB", "Illes Balears";
+    ES_J, ES, "J", "Jaén";
+    ES_L, ES, "L", "Lleida";
+    ES_LE, ES, "LE", "León";
+    ES_LO, ES, "LO", "La Rioja";
+    ES_LU, ES, "LU", "Lugo";
+    ES_M, ES, "M", "Madrid";
+    ES_MA, ES, "MA", "Málaga";
+    ES_MC, ES, "MC", "Murcia, Región de";
+    ES_MD, ES, "MD", "Madrid, Comunidad de";
+    ES_ML, ES, "ML", "Melilla";
+    ES_MU, ES, "MU", "Murcia";
+    ES_NA, ES, "NA", "Nafarroa*";
+    ES_NC, ES, "NC", "Nafarroako Foru Komunitatea*";
+    ES_O, ES, "O", "Asturias";
+    ES_OR, ES, "OR", "Ourense";
+    ES_P, ES, "P", "Palencia";
+    ES_PM, ES, "PM", "Balears";
+    ES_PO, ES, "PO", "Pontevedra";
+    ES_PV, ES, "PV", "Euskal Herria";
+    ES_RI, ES, "RI", "La Rioja";
+    ES_S, ES, "S", "Cantabria";
+    ES_SA, ES, "SA", "Salamanca";
+    ES_SE, ES, "SE", "Sevilla";
+    ES_SG, ES, "SG", "Segovia";
+    ES_SO, ES, "SO", "Soria";
+    ES_SS, ES, "SS", "Gipuzkoa*";
+    ES_T, ES, "T", "Tarragona";
+    ES_TE, ES, "TE", "Teruel";
+    ES_TF, ES, "TF", "Santa Cruz de Tenerife";
+    ES_TO, ES, "TO", "Toledo";
+    ES_V, ES, "V", "Valencia";
+    ES_VA, ES, "VA", "Valladolid";
+    ES_VC, ES, "VC", "Valenciana, Comunidad";
+    ES_VI, ES, "VI", "Álava";
+    ES_Z, ES, "Z", "Zaragoza";
+    ES_ZA, ES, "ZA", "Zamora";
+    ET_AA, ET, "AA", "Addis Ababa";
+    ET_AF, ET, "AF", "Afar";
+    ET_AM, ET, "AM", "Amara";
+    ET_BE, ET, "BE", "Benshangul-Gumaz";
+    ET_DD, ET, "DD", "Dire Dawa";
+    ET_GA, ET, "GA", "Gambēla Hizboch";
+    ET_HA, ET, "HA", "Harari People";
+    ET_OR, ET, "OR", "Oromia";
+    ET_SN, ET, "SN", "Southern Nations, Nationalities and Peoples";
+    ET_SO, ET, "SO", "Somali";
+    ET_TI, ET, "TI", "Tigrai";
+    FI_01, FI, "01", "Ahvenanmaan maakunta";
+    FI_02, FI, "02", "Etelä-Karjala";
+    FI_03, FI, "03", "Etelä-Pohjanmaa";
+    FI_04, FI, "04", "Etelä-Savo";
+    FI_05, FI, "05", "Kainuu";
+    FI_06, FI, "06", "Egentliga Tavastland";
+    FI_07, FI, "07", "Keski-Pohjanmaa";
+    FI_08, FI, "08", "Keski-Suomi";
+    FI_09, FI, "09", "Kymenlaakso";
+    FI_10, FI, "10", "Lappi";
+    FI_11, FI, "11", "Birkaland";
+    FI_12, FI, "12", "Österbotten";
+    FI_13, FI, "13", "Norra Karelen";
+    FI_14, FI, "14", "Norra Österbotten";
+    FI_15, FI, "15", "Norra Savolax";
+    FI_16, FI, "16", "Päijänne-Tavastland";
+    FI_17, FI, "17", "Satakunda";
+    FI_18, FI, "18", "Nyland";
+    FI_19, FI, "19", "Egentliga Finland";
+    FJ_01, FJ, "01", "Ba";
+    FJ_02, FJ, "02", "Bua";
+    FJ_03, FJ, "03", "Cakaudrove";
+    FJ_04, FJ, "04", "Kadavu";
+    FJ_05, FJ, "05", "Lau";
+    FJ_06, FJ, "06", "Lomaiviti";
+    FJ_07, FJ, "07", "Macuata";
+    FJ_08, FJ, "08", "Nadroga and Navosa";
+    FJ_09, FJ, "09", "Naitasiri";
+    FJ_10, FJ, "10", "Namosi";
+    FJ_11, FJ, "11", "Ra";
+    FJ_12, FJ, "12", "Rewa";
+    FJ_13, FJ, "13", "Serua";
+    FJ_14, FJ, "14", "Tailevu";
+    FJ_C, FJ, "C", "Central";
+    FJ_E, FJ, "E", "Eastern";
+    FJ_N, FJ, "N", "Northern";
+    FJ_R, FJ, "R", "Rotuma";
+    FJ_W, FJ, "W", "Western";
+    FM_KSA, FM, "KSA", "Kosrae";
+    FM_PNI, FM, "PNI", "Pohnpei";
+    FM_TRK, FM, "TRK", "Chuuk";
+    FM_YAP, FM, "YAP", "Yap";
+    FR_01, FR, "01", "Ain";
+    FR_02, FR, "02", "Aisne";
+    FR_03, FR, "03", "Allier";
+    FR_04, FR, "04", "Alpes-de-Haute-Provence";
+    FR_05, FR, "05", "Hautes-Alpes";
+    FR_06, FR, "06", "Alpes-Maritimes";
+    FR_07, FR, "07", "Ardèche";
+    FR_08, FR, "08", "Ardennes";
+    FR_09, FR, "09", "Ariège";
+    FR_10, FR, "10", "Aube";
+    FR_11, FR, "11", "Aude";
+    FR_12, FR, "12", "Aveyron";
+    FR_13, FR, "13", "Bouches-du-Rhône";
+    FR_14, FR, "14", "Calvados";
+    FR_15, FR, "15", "Cantal";
+    FR_16, FR, "16", "Charente";
+    FR_17, FR, "17", "Charente-Maritime";
+    FR_18, FR, "18", "Cher";
+    FR_19, FR, "19", "Corrèze";
+    FR_20R, FR, "20R", "Corse";
+    FR_21, FR, "21", "Côte-d'Or";
+    FR_22, FR, "22", "Côtes-d'Armor";
+    FR_23, FR, "23", "Creuse";
+    FR_24, FR, "24", "Dordogne";
+    FR_25, FR, "25", "Doubs";
+    FR_26, FR, "26", "Drôme";
+    FR_27, FR, "27", "Eure";
+    FR_28, FR, "28", "Eure-et-Loir";
+    FR_29, FR, "29", "Finistère";
+    FR_2A, FR, "2A", "Corse-du-Sud";
+    FR_2B, FR, "2B", "Haute-Corse";
+    FR_30, FR, "30", "Gard";
+    FR_31, FR, "31", "Haute-Garonne";
+    FR_32, FR, "32", "Gers";
+    FR_33, FR, "33", "Gironde";
+    FR_34, FR, "34", "Hérault";
+    FR_35, FR, "35", "Ille-et-Vilaine";
+    FR_36, FR, "36", "Indre";
+    FR_37, FR, "37", "Indre-et-Loire";
+    FR_38, FR, "38", "Isère";
+    FR_39, FR, "39", "Jura";
+    FR_40, FR, "40", "Landes";
+    FR_41, FR, "41", "Loir-et-Cher";
+    FR_42, FR, "42", "Loire";
+    FR_43, FR, "43", "Haute-Loire";
+    FR_44, FR, "44", "Loire-Atlantique";
+    FR_45, FR, "45", "Loiret";
+    FR_46, FR, "46", "Lot";
+    FR_47, FR, "47", "Lot-et-Garonne";
+    FR_48, FR, "48", "Lozère";
+    FR_49, FR, "49", "Maine-et-Loire";
+    FR_50, FR, "50", "Manche";
+    FR_51, FR, "51", "Marne";
+    FR_52, FR, "52", "Haute-Marne";
+    FR_53, FR, "53", "Mayenne";
+    FR_54, FR, "54", "Meurthe-et-Moselle";
+    FR_55, FR, "55", "Meuse";
+    FR_56, FR, "56", "Morbihan";
+    FR_57, FR, "57", "Moselle";
+    FR_58, FR, "58", "Nièvre";
+    FR_59, FR, "59", "Nord";
+    FR_60, FR, "60", "Oise";
+    FR_61, FR, "61", "Orne";
+    FR_62, FR, "62", "Pas-de-Calais";
+    FR_63, FR, "63", "Puy-de-Dôme";
+    FR_64, FR, "64", "Pyrénées-Atlantiques";
+    FR_65, FR, "65", "Hautes-Pyrénées";
+    FR_66, FR, "66", "Pyrénées-Orientales";
+    FR_67, FR, "67", "Bas-Rhin";
+    FR_68, FR, "68", "Haut-Rhin";
+    FR_69, FR, "69", "Rhône";
+    FR_70, FR, "70", "Haute-Saône";
+    FR_71, FR, "71", "Saône-et-Loire";
+    FR_72, FR, "72", "Sarthe";
+    FR_73, FR, "73", "Savoie";
+    FR_74, FR, "74", "Haute-Savoie";
+    FR_75, FR, "75", "Paris";
+    FR_76, FR, "76", "Seine-Maritime";
+    FR_77, FR, "77", "Seine-et-Marne";
+    FR_78, FR, "78", "Yvelines";
+    FR_79, FR, "79", "Deux-Sèvres";
+    FR_80, FR, "80", "Somme";
+    FR_81, FR, "81", "Tarn";
+    FR_82, FR, "82", "Tarn-et-Garonne";
+    FR_83, FR, "83", "Var";
+    FR_84, FR, "84", "Vaucluse";
+    FR_85, FR, "85", "Vendée";
+    FR_86, FR, "86", "Vienne";
+    FR_87, FR, "87", "Haute-Vienne";
+    FR_88, FR, "88", "Vosges";
+    FR_89, FR, "89", "Yonne";
+    FR_90, FR, "90", "Territoire de Belfort";
+    FR_91, FR, "91", "Essonne";
+    FR_92, FR, "92", "Hauts-de-Seine";
+    FR_93, FR, "93", "Seine-Saint-Denis";
+    FR_94, FR, "94", "Val-de-Marne";
+    FR_95, FR, "95", "Val-d'Oise";
+    FR_A, FR, "A", "Alsace";
+    FR_ARA, FR, "ARA", "Auvergne-Rhône-Alpes";
+    FR_B, FR, "B", "Aquitaine";
+    FR_BFC, FR, "BFC", "Bourgogne-Franche-Comté";
+    FR_BL, FR, "BL", "Saint-Barthélemy";
+    FR_BRE, FR, "BRE", "Bretagne";
+    FR_C, FR, "C", "Auvergne";
+    FR_CP, FR, "CP", "Clipperton";
+    FR_CVL, FR, "CVL", "Centre-Val de Loire";
+    FR_D, FR, "D", "Bourgogne";
+    FR_E, FR, "E", "Bretagne";
+    FR_F, FR, "F", "Centre";
+    FR_G, FR, "G", "Champagne-Ardenne";
+    FR_GES, FR, "GES", "Grand-Est";
+    FR_GF, FR, "GF", "Guyane";
+    FR_GP, FR, "GP", "Guadeloupe";
+    FR_H, FR, "H", "Corse";
+    FR_HDF, FR, "HDF", "Hauts-de-France";
+    FR_I, FR, "I", "Franche-Comté";
+    FR_IDF, FR, "IDF", "Île-de-France";
+    FR_J, FR, "J", "Île-de-France";
+    FR_K, FR, "K", "Languedoc-Roussillon";
+    FR_L, FR, "L", "Limousin";
+    FR_M, FR, "M", "Lorraine";
+    FR_MF, FR, "MF", "Saint-Martin";
+    FR_MQ, FR, "MQ", "Martinique";
+    FR_N, FR, "N", "Midi-Pyrénées";
+    FR_NAQ, FR, "NAQ", "Nouvelle-Aquitaine";
+    FR_NC, FR, "NC", "Nouvelle-Calédonie";
+    FR_NOR, FR, "NOR", "Normandie";
+    FR_O, FR, "O", "Nord-Pas-de-Calais";
+    FR_OCC, FR, "OCC", "Occitanie";
+    FR_P, FR, "P", "Basse-Normandie";
+    FR_PAC, FR, "PAC", "Provence-Alpes-Côte-d’Azur";
+    FR_PDL, FR, "PDL", "Pays-de-la-Loire";
+    FR_PF, FR, "PF", "Polynésie française";
+    FR_PM, FR, "PM", "Saint-Pierre-et-Miquelon";
+    FR_Q, FR, "Q", "Haute-Normandie";
+    FR_R, FR, "R", "Pays-de-la-Loire";
+    FR_RE, FR, "RE", "La Réunion";
+    FR_S, FR, "S", "Picardie";
+    FR_T, FR, "T", "Poitou-Charentes";
+    FR_TF, FR, "TF", "Terres australes françaises";
+    FR_U, FR, "U", "Provence-Alpes-Côte-d'Azur";
+    FR_V, FR, "V", "Rhône-Alpes";
+    FR_WF, FR, "WF", "Wallis-et-Futuna";
+    FR_YT, FR, "YT", "Mayotte";
+    GA_1, GA, "1", "Estuaire";
+    GA_2, GA, "2", "Haut-Ogooué";
+    GA_3, GA, "3", "Moyen-Ogooué";
+    GA_4, GA, "4", "Ngounié";
+    GA_5, GA, "5", "Nyanga";
+    GA_6, GA, "6", "Ogooué-Ivindo";
+    GA_7, GA, "7", "Ogooué-Lolo";
+    GA_8, GA, "8", "Ogooué-Maritime";
+    GA_9, GA, "9", "Woleu-Ntem";
+    GB_ABD, GB, "ABD", "Aberdeenshire";
+    GB_ABE, GB, "ABE", "Aberdeen City";
+    GB_AGB, GB, "AGB", "Argyll and Bute";
+    GB_AGY, GB, "AGY", "Isle of Anglesey";
+    GB_ANS, GB, "ANS", "Angus";
+    GB_ANT, GB, "ANT", "Antrim";
+    GB_ARD, GB, "ARD", "Ards";
+    GB_ARM, GB, "ARM", "Armagh";
+    GB_BAS, GB, "BAS", "Bath and North East Somerset";
+    GB_BBD, GB, "BBD", "Blackburn with Darwen";
+    GB_BDF, GB, "BDF", "Bedford";
+    GB_BDG, GB, "BDG", "Barking and Dagenham";
+    GB_BEN, GB, "BEN", "Brent";
+    GB_BEX, GB, "BEX", "Bexley";
+    GB_BFS, GB, "BFS", "Belfast";
+    GB_BGE, GB, "BGE", "Bridgend";
+    GB_BGW, GB, "BGW", "Blaenau Gwent";
+    GB_BIR, GB, "BIR", "Birmingham";
+    GB_BKM, GB, "BKM", "Buckinghamshire";
+    GB_BLA, GB, "BLA", "Ballymena";
+    GB_BLY, GB, "BLY", "Ballymoney";
+    GB_BMH, GB, "BMH", "Bournemouth";
+    GB_BNB, GB, "BNB", "Banbridge";
+    GB_BNE, GB, "BNE", "Barnet";
+    GB_BNH, GB, "BNH", "Brighton and Hove";
+    GB_BNS, GB, "BNS", "Barnsley";
+    GB_BOL, GB, "BOL", "Bolton";
+    GB_BPL, GB, "BPL", "Blackpool";
+    GB_BRC, GB, "BRC", "Bracknell Forest";
+    GB_BRD, GB, "BRD", "Bradford";
+    GB_BRY, GB, "BRY", "Bromley";
+    GB_BST, GB, "BST", "Bristol, City of";
+    GB_BUR, GB, "BUR", "Bury";
+    GB_CAM, GB, "CAM", "Cambridgeshire";
+    GB_CAY, GB, "CAY", "Caerphilly";
+    GB_CBF, GB, "CBF", "Central Bedfordshire";
+    GB_CGN, GB, "CGN", "Ceredigion";
+    GB_CGV, GB, "CGV", "Craigavon";
+    GB_CHE, GB, "CHE", "Cheshire East";
+    GB_CHW, GB, "CHW", "Cheshire West and Chester";
+    GB_CKF, GB, "CKF", "Carrickfergus";
+    GB_CKT, GB, "CKT", "Cookstown";
+    GB_CLD, GB, "CLD", "Calderdale";
+    GB_CLK, GB, "CLK", "Clackmannanshire";
+    GB_CLR, GB, "CLR", "Coleraine";
+    GB_CMA, GB, "CMA", "Cumbria";
+    GB_CMD, GB, "CMD", "Camden";
+    GB_CMN, GB, "CMN", "Carmarthenshire";
+    GB_CON, GB, "CON", "Cornwall";
+    GB_COV, GB, "COV", "Coventry";
+    GB_CRF, GB, "CRF", "Cardiff";
+    GB_CRY, GB, "CRY", "Croydon";
+    GB_CSR, GB, "CSR", "Castlereagh";
+    GB_CWY, GB, "CWY", "Conwy";
+    GB_DAL, GB, "DAL", "Darlington";
+    GB_DBY, GB, "DBY", "Derbyshire";
+    GB_DEN, GB, "DEN", "Denbighshire";
+    GB_DER, GB, "DER", "Derby";
+    GB_DEV, GB, "DEV", "Devon";
+    GB_DGN, GB, "DGN", "Dungannon and South Tyrone";
+    GB_DGY, GB, "DGY", "Dumfries and Galloway";
+    GB_DNC, GB, "DNC", "Doncaster";
+    GB_DND, GB, "DND", "Dundee City";
+    GB_DOR, GB, "DOR", "Dorset";
+    GB_DOW, GB, "DOW", "Down";
+    GB_DRY, GB, "DRY", "Derry";
+    GB_DUD, GB, "DUD", "Dudley";
+    GB_DUR, GB, "DUR", "Durham County";
+    GB_EAL, GB, "EAL", "Ealing";
+    GB_EAW, GB, "EAW", "England and Wales";
+    GB_EAY, GB, "EAY", "East Ayrshire";
+    GB_EDH, GB, "EDH", "Edinburgh, City of";
+    GB_EDU, GB, "EDU", "East Dunbartonshire";
+    GB_ELN, GB, "ELN", "East Lothian";
+    GB_ELS, GB, "ELS", "Eilean Siar";
+    GB_ENF, GB, "ENF", "Enfield";
+    GB_ENG, GB, "ENG", "England";
+    GB_ERW, GB, "ERW", "East Renfrewshire";
+    GB_ERY, GB, "ERY", "East Riding of Yorkshire";
+    GB_ESS, GB, "ESS", "Essex";
+    GB_ESX, GB, "ESX", "East Sussex";
+    GB_FAL, GB, "FAL", "Falkirk";
+    GB_FER, GB, "FER", "Fermanagh";
+    GB_FIF, GB, "FIF", "Fife";
+    GB_FLN, GB, "FLN", "Flintshire";
+    GB_GAT, GB, "GAT", "Gateshead";
+    GB_GBN, GB, "GBN", "Great Britain";
+    GB_GLG, GB, "GLG", "Glasgow City";
+    GB_GLS, GB, "GLS", "Gloucestershire";
+    GB_GRE, GB, "GRE", "Greenwich";
+    GB_GWN, GB, "GWN", "Gwynedd";
+    GB_HAL, GB, "HAL", "Halton";
+    GB_HAM, GB, "HAM", "Hampshire";
+    GB_HAV, GB, "HAV", "Havering";
+    GB_HCK, GB, "HCK", "Hackney";
+    GB_HEF, GB, "HEF", "Herefordshire";
+    GB_HIL, GB, "HIL", "Hillingdon";
+    GB_HLD, GB, "HLD", "Highland";
+    GB_HMF, GB, "HMF", "Hammersmith and Fulham";
+    GB_HNS, GB, "HNS", "Hounslow";
+    GB_HPL, GB, "HPL", "Hartlepool";
+    GB_HRT, GB, "HRT", "Hertfordshire";
+    GB_HRW, GB, "HRW", "Harrow";
+    GB_HRY, GB, "HRY", "Haringey";
+    GB_IOS, GB, "IOS", "Isles of Scilly";
+    GB_IOW, GB, "IOW", "Isle of Wight";
+    GB_ISL, GB, "ISL", "Islington";
+    GB_IVC, GB, "IVC", "Inverclyde";
+    GB_KEC, GB, "KEC", "Kensington and Chelsea";
+    GB_KEN, GB, "KEN", "Kent";
+    GB_KHL, GB, "KHL", "Kingston upon Hull";
+    GB_KIR, GB, "KIR", "Kirklees";
+    GB_KTT, GB, "KTT", "Kingston upon Thames";
+    GB_KWL, GB, "KWL", "Knowsley";
+    GB_LAN, GB, "LAN", "Lancashire";
+    GB_LBH, GB, "LBH", "Lambeth";
+    GB_LCE, GB, "LCE", "Leicester";
+    GB_LDS, GB, "LDS", "Leeds";
+    GB_LEC, GB, "LEC", "Leicestershire";
+    GB_LEW, GB, "LEW", "Lewisham";
+    GB_LIN, GB, "LIN", "Lincolnshire";
+    GB_LIV, GB, "LIV", "Liverpool";
+    GB_LMV, GB, "LMV", "Limavady";
+    GB_LND, GB, "LND", "London, City of";
+    GB_LRN, GB, "LRN", "Larne";
+    GB_LSB, GB, "LSB", "Lisburn";
+    GB_LUT, GB, "LUT", "Luton";
+    GB_MAN, GB, "MAN", "Manchester";
+    GB_MDB, GB, "MDB", "Middlesbrough";
+    GB_MDW, GB, "MDW", "Medway";
+    GB_MFT, GB, "MFT", "Magherafelt";
+    GB_MIK, GB, "MIK", "Milton Keynes";
+    GB_MLN, GB, "MLN", "Midlothian";
+    GB_MON, GB, "MON", "Monmouthshire";
+    GB_MRT, GB, "MRT", "Merton";
+    GB_MRY, GB, "MRY", "Moray";
+    GB_MTY, GB, "MTY", "Merthyr Tydfil";
+    GB_MYL, GB, "MYL", "Moyle";
+    GB_NAY, GB, "NAY", "North Ayrshire";
+    GB_NBL, GB, "NBL", "Northumberland";
+    GB_NDN, GB, "NDN", "North Down";
+    GB_NEL, GB, "NEL", "North East Lincolnshire";
+    GB_NET, GB, "NET", "Newcastle upon Tyne";
+    GB_NFK, GB, "NFK", "Norfolk";
+    GB_NGM, GB, "NGM", "Nottingham";
+    GB_NIR, GB, "NIR", "Northern Ireland";
+    GB_NLK, GB, "NLK", "North Lanarkshire";
+    GB_NLN, GB, "NLN", "North Lincolnshire";
+    GB_NSM, GB, "NSM", "North Somerset";
+    GB_NTA, GB, "NTA", "Newtownabbey";
+    GB_NTH, GB, "NTH", "Northamptonshire";
+    GB_NTL, GB, "NTL", "Neath Port Talbot";
+    GB_NTT, GB, "NTT", "Nottinghamshire";
+    GB_NTY, GB, "NTY", "North Tyneside";
+    GB_NWM, GB, "NWM", "Newham";
+    GB_NWP, GB, "NWP", "Newport";
+    GB_NYK, GB, "NYK", "North Yorkshire";
+    GB_NYM, GB, "NYM", "Newry and Mourne";
+    GB_OLD, GB, "OLD", "Oldham";
+    GB_OMH, GB, "OMH", "Omagh";
+    GB_ORK, GB, "ORK", "Orkney Islands";
+    GB_OXF, GB, "OXF", "Oxfordshire";
+    GB_PEM, GB, "PEM", "Pembrokeshire";
+    GB_PKN, GB, "PKN", "Perth and Kinross";
+    GB_PLY, GB, "PLY", "Plymouth";
+    GB_POL, GB, "POL", "Poole";
+    GB_POR, GB, "POR", "Portsmouth";
+    GB_POW, GB, "POW", "Powys";
+    GB_PTE, GB, "PTE", "Peterborough";
+    GB_RCC, GB, "RCC", "Redcar and Cleveland";
+    GB_RCH, GB, "RCH", "Rochdale";
+    GB_RCT, GB, "RCT", "Rhondda, Cynon, Taff";
+    GB_RDB, GB, "RDB", "Redbridge";
+    GB_RDG, GB, "RDG", "Reading";
+    GB_RFW, GB, "RFW", "Renfrewshire";
+    GB_RIC, GB, "RIC", "Richmond upon Thames";
+    GB_ROT, GB, "ROT", "Rotherham";
+    GB_RUT, GB, "RUT", "Rutland";
+    GB_SAW, GB, "SAW", "Sandwell";
+    GB_SAY, GB, "SAY", "South Ayrshire";
+    GB_SCB, GB, "SCB", "Scottish Borders, The";
+    GB_SCT, GB, "SCT", "Scotland";
+    GB_SFK, GB, "SFK", "Suffolk";
+    GB_SFT, GB, "SFT", "Sefton";
+    GB_SGC, GB, "SGC", "South Gloucestershire";
+    GB_SHF, GB, "SHF", "Sheffield";
+    GB_SHN, GB, "SHN", "St. Helens";
+    GB_SHR, GB, "SHR", "Shropshire";
+    GB_SKP, GB, "SKP", "Stockport";
+    GB_SLF, GB, "SLF", "Salford";
+    GB_SLG, GB, "SLG", "Slough";
+    GB_SLK, GB, "SLK", "South Lanarkshire";
+    GB_SND, GB, "SND", "Sunderland";
+    GB_SOL, GB, "SOL", "Solihull";
+    GB_SOM, GB, "SOM", "Somerset";
+    GB_SOS, GB, "SOS", "Southend-on-Sea";
+    GB_SRY, GB, "SRY", "Surrey";
+    GB_STB, GB, "STB", "Strabane";
+    GB_STE, GB, "STE", "Stoke-on-Trent";
+    GB_STG, GB, "STG", "Stirling";
+    GB_STH, GB, "STH", "Southampton";
+    GB_STN, GB, "STN", "Sutton";
+    GB_STS, GB, "STS", "Staffordshire";
+    GB_STT, GB, "STT", "Stockton-on-Tees";
+    GB_STY, GB, "STY", "South Tyneside";
+    GB_SWA, GB, "SWA", "Swansea";
+    GB_SWD, GB, "SWD", "Swindon";
+    GB_SWK, GB, "SWK", "Southwark";
+    GB_TAM, GB, "TAM", "Tameside";
+    GB_TFW, GB, "TFW", "Telford and Wrekin";
+    GB_THR, GB, "THR", "Thurrock";
+    GB_TOB, GB, "TOB", "Torbay";
+    GB_TOF, GB, "TOF", "Torfaen";
+    GB_TRF, GB, "TRF", "Trafford";
+    GB_TWH, GB, "TWH", "Tower Hamlets";
+    GB_UKM, GB, "UKM", "United Kingdom";
+    GB_VGL, GB, "VGL", "Vale of Glamorgan, The";
+    GB_WAR, GB, "WAR", "Warwickshire";
+    GB_WBK, GB, "WBK", "West Berkshire";
+    GB_WDU, GB, "WDU", "West Dunbartonshire";
+    GB_WFT, GB, "WFT", "Waltham Forest";
+    GB_WGN, GB, "WGN", "Wigan";
+    GB_WIL, GB, "WIL", "Wiltshire";
+    GB_WKF, GB, "WKF", "Wakefield";
+    GB_WLL, GB, "WLL", "Walsall";
+    GB_WLN, GB, "WLN", "West Lothian";
+    GB_WLS, GB, "WLS", "Wales";
+    GB_WLV, GB, "WLV", "Wolverhampton";
+    GB_WND, GB, "WND", "Wandsworth";
+    GB_WNM, GB, "WNM", "Windsor and Maidenhead";
+    GB_WOK, GB, "WOK", "Wokingham";
+    GB_WOR, GB, "WOR", "Worcestershire";
+    GB_WRL, GB, "WRL", "Wirral";
+    GB_WRT, GB, "WRT", "Warrington";
+    GB_WRX, GB, "WRX", "Wrexham";
+    GB_WSM, GB, "WSM", "Westminster";
+    GB_WSX, GB, "WSX", "West Sussex";
+    GB_YOR, GB, "YOR", "York";
+    GB_ZET, GB, "ZET", "Shetland Islands";
+    GD_01, GD, "01", "Saint Andrew";
+    GD_02, GD, "02", "Saint David";
+    GD_03, GD, "03", "Saint George";
+    GD_04, GD, "04", "Saint John";
+    GD_05, GD, "05", "Saint Mark";
+    GD_06, GD, "06", "Saint Patrick";
+    GD_10, GD, "10", "Southern Grenadine Islands";
+    GE_AB, GE, "AB", "Abkhazia";
+    GE_AJ, GE, "AJ", "Ajaria";
+    GE_GU, GE, "GU", "Guria";
+    GE_IM, GE, "IM", "Imereti";
+    GE_KA, GE, "KA", "K'akheti";
+    GE_KK, GE, "KK", "Kvemo Kartli";
+    GE_MM, GE, "MM", "Mtskheta-Mtianeti";
+    GE_RL, GE, "RL", "Rach'a-Lechkhumi-Kvemo Svaneti";
+    GE_SJ, GE, "SJ", "Samtskhe-Javakheti";
+    GE_SK, GE, "SK", "Shida Kartli";
+    GE_SZ, GE, "SZ", "Samegrelo-Zemo Svaneti";
+    GE_TB, GE, "TB", "Tbilisi";
+    GH_AA, GH, "AA", "Greater Accra";
+    GH_AH, GH, "AH", "Ashanti";
+    GH_BA, GH, "BA", "Brong-Ahafo";
+    GH_CP, GH, "CP", "Central";
+    GH_EP, GH, "EP", "Eastern";
+    GH_NP, GH, "NP", "Northern";
+    GH_TV, GH, "TV", "Volta";
+    GH_UE, GH, "UE", "Upper East";
+    GH_UW, GH, "UW", "Upper West";
+    GH_WP, GH, "WP", "Western";
+    GL_KU, GL, "KU", "Kommune Kujalleq";
+    GL_QA, GL, "QA", "Qaasuitsup Kommunia";
+    GL_QE, GL, "QE", "Qeqqata Kommunia";
+    GL_SM, GL, "SM", "Kommuneqarfik Sermersooq";
+    GM_B, GM, "B", "Banjul";
+    GM_L, GM, "L", "Lower River";
+    GM_M, GM, "M", "Central River";
+    GM_N, GM, "N", "North Bank";
+    GM_U, GM, "U", "Upper River";
+    GM_W, GM, "W", "Western";
+    GN_B, GN, "B", "Boké";
+    GN_BE, GN, "BE", "Beyla";
+    GN_BF, GN, "BF", "Boffa";
+    GN_BK, GN, "BK", "Boké";
+    GN_C, GN, "C", "Conakry";
+    GN_CO, GN, "CO", "Coyah";
+    GN_D, GN, "D", "Kindia";
+    GN_DB, GN, "DB", "Dabola";
+    GN_DI, GN, "DI", "Dinguiraye";
+    GN_DL, GN, "DL", "Dalaba";
+    GN_DU, GN, "DU", "Dubréka";
+    GN_F, GN, "F", "Faranah";
+    GN_FA, GN, "FA", "Faranah";
+    GN_FO, GN, "FO", "Forécariah";
+    GN_FR, GN, "FR", "Fria";
+    GN_GA, GN, "GA", "Gaoual";
+    GN_GU, GN, "GU", "Guékédou";
+    GN_K, GN, "K", "Kankan";
+    GN_KA, GN, "KA", "Kankan";
+    GN_KB, GN, "KB", "Koubia";
+    GN_KD, GN, "KD", "Kindia";
+    GN_KE, GN, "KE", "Kérouané";
+    GN_KN, GN, "KN", "Koundara";
+    GN_KO, GN, "KO", "Kouroussa";
+    GN_KS, GN, "KS", "Kissidougou";
+    GN_L, GN, "L", "Labé";
+    GN_LA, GN, "LA", "Labé";
+    GN_LE, GN, "LE", "Lélouma";
+    GN_LO, GN, "LO", "Lola";
+    GN_M, GN, "M", "Mamou";
+    GN_MC, GN, "MC", "Macenta";
+    GN_MD, GN, "MD", "Mandiana";
+    GN_ML, GN, "ML", "Mali";
+    GN_MM, GN, "MM", "Mamou";
+    GN_N, GN, "N", "Nzérékoré";
+    GN_NZ, GN, "NZ", "Nzérékoré";
+    GN_PI, GN, "PI", "Pita";
+    GN_SI, GN, "SI", "Siguiri";
+    GN_TE, GN, "TE", "Télimélé";
+    GN_TO, GN, "TO", "Tougué";
+    GN_YO, GN, "YO", "Yomou";
+    GQ_AN, GQ, "AN", "Annobón";
+    GQ_BN, GQ, "BN", "Bioko Nord";
+    GQ_BS, GQ, "BS", "Bioko Sud";
+    GQ_C, GQ, "C", "Região Continental";
+    GQ_CS, GQ, "CS", "Centro Sud";
+    GQ_I, GQ, "I", "Região Insular";
+    GQ_KN, GQ, "KN", "Kié-Ntem";
+    GQ_LI, GQ, "LI", "Litoral";
+    GQ_WN, GQ, "WN", "Wele-Nzas";
+    GR_01, GR, "01", "Aitoloakarnanía";
+    GR_03, GR, "03", "Voiotía";
+    GR_04, GR, "04", "Évvoia";
+    GR_05, GR, "05", "Evrytanía";
+    GR_06, GR, "06", "Fthiótida";
+    GR_07, GR, "07", "Fokída";
+    GR_11, GR, "11", "Argolída";
+    GR_12, GR, "12", "Arkadía";
+    GR_13, GR, "13", "Achaḯa";
+    GR_14, GR, "14", "Ileía";
+    GR_15, GR, "15", "Korinthía";
+    GR_16, GR, "16", "Lakonía";
+    GR_17, GR, "17", "Messinía";
+    GR_21, GR, "21", "Zákynthos";
+    GR_22, GR, "22", "Kérkyra";
+    GR_23, GR, "23", "Kefallinía";
+    GR_24, GR, "24", "Lefkáda";
+    GR_31, GR, "31", "Árta";
+    GR_32, GR, "32", "Thesprotía";
+    GR_33, GR, "33", "Ioánnina";
+    GR_34, GR, "34", "Préveza";
+    GR_41, GR, "41", "Kardítsa";
+    GR_42, GR, "42", "Lárisa";
+    GR_43, GR, "43", "Magnisía";
+    GR_44, GR, "44", "Tríkala";
+    GR_51, GR, "51", "Grevená";
+    GR_52, GR, "52", "Dráma";
+    GR_53, GR, "53", "Imathía";
+    GR_54, GR, "54", "Thessaloníki";
+    GR_55, GR, "55", "Kavála";
+    GR_56, GR, "56", "Kastoriá";
+    GR_57, GR, "57", "Kilkís";
+    GR_58, GR, "58", "Kozáni";
+    GR_59, GR, "59", "Pélla";
+    GR_61, GR, "61", "Piería";
+    GR_62, GR, "62", "Sérres";
+    GR_63, GR, "63", "Flórina";
+    GR_64, GR, "64", "Chalkidikí";
+    GR_69, GR, "69", "Ágion Óros";
+    GR_71, GR, "71", "Évros";
+    GR_72, GR, "72", "Xánthi";
+    GR_73, GR, "73", "Rodópi";
+    GR_81, GR, "81", "Dodekánisa";
+    GR_82, GR, "82", "Kykládes";
+    GR_83, GR, "83", "Lésvos";
+    GR_84, GR, "84", "Sámos";
+    GR_85, GR, "85", "Chíos";
+    GR_91, GR, "91", "Irakleío";
+    GR_92, GR, "92", "Lasíthi";
+    GR_93, GR, "93", "Rethýmnis";
+    GR_94, GR, "94", "Chaniá";
+    GR_A, GR, "A", "Anatolikí Makedonía kai Thráki";
+    GR_A1, GR, "A1", "Attikí";
+    GR_B, GR, "B", "Kentrikí Makedonía";
+    GR_C, GR, "C", "Dytikí Makedonía";
+    GR_D, GR, "D", "Ípeiros";
+    GR_E, GR, "E", "Thessalía";
+    GR_F, GR, "F", "Ionía Nísia";
+    GR_G, GR, "G", "Dytikí Elláda";
+    GR_H, GR, "H", "Stereá Elláda";
+    GR_I, GR, "I", "Attikí";
+    GR_J, GR, "J", "Peloponnísos";
+    GR_K, GR, "K", "Voreío Aigaío";
+    GR_L, GR, "L", "Notío Aigaío";
+    GR_M, GR, "M", "Krítí";
+    GT_AV, GT, "AV", "Alta Verapaz";
+    GT_BV, GT, "BV", "Baja Verapaz";
+    GT_CM, GT, "CM", "Chimaltenango";
+    GT_CQ, GT, "CQ", "Chiquimula";
+    GT_ES, GT, "ES", "Escuintla";
+    GT_GU, GT, "GU", "Guatemala";
+    GT_HU, GT, "HU", "Huehuetenango";
+    GT_IZ, GT, "IZ", "Izabal";
+    GT_JA, GT, "JA", "Jalapa";
+    GT_JU, GT, "JU", "Jutiapa";
+    GT_PE, GT, "PE", "Petén";
+    GT_PR, GT, "PR", "El Progreso";
+    GT_QC, GT, "QC", "Quiché";
+    GT_QZ, GT, "QZ", "Quetzaltenango";
+    GT_RE, GT, "RE", "Retalhuleu";
+    GT_SA, GT, "SA", "Sacatepéquez";
+    GT_SM, GT, "SM", "San Marcos";
+    GT_SO, GT, "SO", "Sololá";
+    GT_SR, GT, "SR", "Santa Rosa";
+    GT_SU, GT, "SU", "Suchitepéquez";
+    GT_TO, GT, "TO", "Totonicapán";
+    GT_ZA, GT, "ZA", "Zacapa";
+    GW_BA, GW, "BA", "Bafatá";
+    GW_BL, GW, "BL", "Bolama";
+    GW_BM, GW, "BM", "Biombo";
+    GW_BS, GW, "BS", "Bissau";
+    GW_CA, GW, "CA", "Cacheu";
+    GW_GA, GW, "GA", "Gabú";
+    GW_L, GW, "L", "Leste";
+    GW_N, GW, "N", "Norte";
+    GW_OI, GW, "OI", "Oio";
+    GW_QU, GW, "QU", "Quinara";
+    GW_S, GW, "S", "Sul";
+    GW_TO, GW, "TO", "Tombali";
+    GY_BA, GY, "BA", "Barima-Waini";
+    GY_CU, GY, "CU", "Cuyuni-Mazaruni";
+    GY_DE, GY, "DE", "Demerara-Mahaica";
+    GY_EB, GY, "EB", "East Berbice-Corentyne";
+    GY_ES, GY, "ES", "Essequibo Islands-West Demerara";
+    GY_MA, GY, "MA", "Mahaica-Berbice";
+    GY_PM, GY, "PM", "Pomeroon-Supenaam";
+    GY_PT, GY, "PT", "Potaro-Siparuni";
+    GY_UD, GY, "UD", "Upper Demerara-Berbice";
+    GY_UT, GY, "UT", "Upper Takutu-Upper Essequibo";
+    HN_AT, HN, "AT", "Atlántida";
+    HN_CH, HN, "CH", "Choluteca";
+    HN_CL, HN, "CL", "Colón";
+    HN_CM, HN, "CM", "Comayagua";
+    HN_CP, HN, "CP", "Copán";
+    HN_CR, HN, "CR", "Cortés";
+    HN_EP, HN, "EP", "El Paraíso";
+    HN_FM, HN, "FM", "Francisco Morazán";
+    HN_GD, HN, "GD", "Gracias a Dios";
+    HN_IB, HN, "IB", "Islas de la Bahía";
+    HN_IN, HN, "IN", "Intibucá";
+    HN_LE, HN, "LE", "Lempira";
+    HN_LP, HN, "LP", "La Paz";
+    HN_OC, HN, "OC", "Ocotepeque";
+    HN_OL, HN, "OL", "Olancho";
+    HN_SB, HN, "SB", "Santa Bárbara";
+    HN_VA, HN, "VA", "Valle";
+    HN_YO, HN, "YO", "Yoro";
+    HR_01, HR, "01", "Zagrebačka županija";
+    HR_02, HR, "02", "Krapinsko-zagorska županija";
+    HR_03, HR, "03", "Sisačko-moslavačka županija";
+    HR_04, HR, "04", "Karlovačka županija";
+    HR_05, HR, "05", "Varaždinska županija";
+    HR_06, HR, "06", "Koprivničko-križevačka županija";
+    HR_07, HR, "07", "Bjelovarsko-bilogorska županija";
+    HR_08, HR, "08", "Primorsko-goranska županija";
+    HR_09, HR, "09", "Ličko-senjska županija";
+    HR_10, HR, "10", "Virovitičko-podravska županija";
+    HR_11, HR, "11", "Požeško-slavonska županija";
+    HR_12, HR, "12", "Brodsko-posavska županija";
+    HR_13, HR, "13", "Zadarska županija";
+    HR_14, HR, "14", "Osječko-baranjska županija";
+    HR_15, HR, "15", "Šibensko-kninska županija";
+    HR_16, HR, "16", "Vukovarsko-srijemska županija";
+    HR_17, HR, "17", "Splitsko-dalmatinska županija";
+    HR_18, HR, "18", "Istarska županija";
+    HR_19, HR, "19", "Dubrovačko-neretvanska županija";
+    HR_20, HR, "20", "Međimurska županija";
+    HR_21, HR, "21", "Grad Zagreb";
+    HT_AR, HT, "AR", "Artibonite";
+    HT_CE, HT, "CE", "Centre";
+    HT_GA, HT, "GA", "Grandans";
+    HT_ND, HT, "ND", "Nò";
+    HT_NE, HT, "NE", "Nòdès";
+    HT_NI, HT, "NI", "Nip";
+    HT_NO, HT, "NO", "Nòdwès";
+    HT_OU, HT, "OU", "Lwès";
+    HT_SD, HT, "SD", "Sid";
+    HT_SE, HT, "SE", "Sidès";
+    HU_BA, HU, "BA", "Baranya";
+    HU_BC, HU, "BC", "Békéscsaba";
+    HU_BE, HU, "BE", "Békés";
+    HU_BK, HU, "BK", "Bács-Kiskun";
+    HU_BU, HU, "BU", "Budapest";
+    HU_BZ, HU, "BZ", "Borsod-Abaúj-Zemplén";
+    HU_CS, HU, "CS", "Csongrád";
+    HU_DE, HU, "DE", "Debrecen";
+    HU_DU, HU, "DU", "Dunaújváros";
+    HU_EG, HU, "EG", "Eger";
+    HU_ER, HU, "ER", "Érd";
+    HU_FE, HU, "FE", "Fejér";
+    HU_GS, HU, "GS", "Győr-Moson-Sopron";
+    HU_GY, HU, "GY", "Győr";
+    HU_HB, HU, "HB", "Hajdú-Bihar";
+    HU_HE, HU, "HE", "Heves";
+    HU_HV, HU, "HV", "Hódmezővásárhely";
+    HU_JN, HU, "JN", "Jász-Nagykun-Szolnok";
+    HU_KE, HU, "KE", "Komárom-Esztergom";
+    HU_KM, HU, "KM", "Kecskemét";
+    HU_KV, HU, "KV", "Kaposvár";
+    HU_MI, HU, "MI", "Miskolc";
+    HU_NK, HU, "NK", "Nagykanizsa";
+    HU_NO, HU, "NO", "Nógrád";
+    HU_NY, HU, "NY", "Nyíregyháza";
+    HU_PE, HU, "PE", "Pest";
+    HU_PS, HU, "PS", "Pécs";
+    HU_SD, HU, "SD", "Szeged";
+    HU_SF, HU, "SF", "Székesfehérvár";
+    HU_SH, HU, "SH", "Szombathely";
+    HU_SK, HU, "SK", "Szolnok";
+    HU_SN, HU, "SN", "Sopron";
+    HU_SO, HU, "SO", "Somogy";
+    HU_SS, HU, "SS", "Szekszárd";
+    HU_ST, HU, "ST", "Salgótarján";
+    HU_SZ, HU, "SZ", "Szabolcs-Szatmár-Bereg";
+    HU_TB, HU, "TB", "Tatabánya";
+    HU_TO, HU, "TO", "Tolna";
+    HU_VA, HU, "VA", "Vas";
+    HU_VE, HU, "VE", "Veszprém";
+    HU_VM, HU, "VM", "Veszprém";
+    HU_ZA, HU, "ZA", "Zala";
+    HU_ZE, HU, "ZE", "Zalaegerszeg";
+    ID_AC, ID, "AC", "Aceh";
+    ID_BA, ID, "BA", "Bali";
+    ID_BB, ID, "BB", "Bangka Belitung";
+    ID_BE, ID, "BE", "Bengkulu";
+    ID_BT, ID, "BT", "Banten";
+    ID_GO, ID, "GO", "Gorontalo";
+    ID_JA, ID, "JA", "Jambi";
+    ID_JB, ID, "JB", "Jawa Barat";
+    ID_JI, ID, "JI", "Jawa Timur";
+    ID_JK, ID, "JK", "Jakarta Raya";
+    ID_JT, ID, "JT", "Jawa Tengah";
+    ID_JW, ID, "JW", "Jawa";
+    ID_KA, ID, "KA", "Kalimantan";
+    ID_KB, ID, "KB", "Kalimantan Barat";
+    ID_KI, ID, "KI", "Kalimantan Timur";
+    ID_KR, ID, "KR", "Kepulauan Riau";
+    ID_KS, ID, "KS", "Kalimantan Selatan";
+    ID_KT, ID, "KT", "Kalimantan Tengah";
+    ID_KU, ID, "KU", "Kalimantan Utara";
+    ID_LA, ID, "LA", "Lampung";
+    ID_MA, ID, "MA", "Maluku";
+    ID_ML, ID, "ML", "Maluku";
+    ID_MU, ID, "MU", "Maluku Utara";
+    ID_NB, ID, "NB", "Nusa Tenggara Barat";
+    ID_NT, ID, "NT", "Nusa Tenggara Timur";
+    ID_NU, ID, "NU", "Nusa Tenggara";
+    ID_PA, ID, "PA", "Papua";
+    ID_PB, ID, "PB", "Papua Barat";
+    ID_PP, ID, "PP", "Papua";
+    ID_RI, ID, "RI", "Riau";
+    ID_SA, ID, "SA", "Sulawesi Utara";
+    ID_SB, ID, "SB", "Sumatera Barat";
+    ID_SG, ID, "SG", "Sulawesi Tenggara";
+    ID_SL, ID, "SL", "Sulawesi";
+    ID_SM, ID, "SM", "Sumatera";
+    ID_SN, ID, "SN", "Sulawesi Selatan";
+    ID_SR, ID, "SR", "Sulawesi Barat";
+    ID_SS, ID, "SS", "Sumatera Selatan";
+    ID_ST, ID, "ST", "Sulawesi Tengah";
+    ID_SU, ID, "SU", "Sumatera Utara";
+    ID_YO, ID, "YO", "Yogyakarta";
+    IE_C, IE, "C", "Connacht";
+    IE_CE, IE, "CE", "An Clár";
+    IE_CN, IE, "CN", "An Cabhán";
+    IE_CO, IE, "CO", "Corcaigh";
+    IE_CW, IE, "CW", "Carlow";
+    IE_D, IE, "D", "Baile Átha Cliath";
+    IE_DL, IE, "DL", "Donegal";
+    IE_G, IE, "G", "Gaillimh";
+    IE_KE, IE, "KE", "Cill Dara";
+    IE_KK, IE, "KK", "Cill Chainnigh";
+    IE_KY, IE, "KY", "Ciarraí";
+    IE_L, IE, "L", "Laighin";
+    IE_LD, IE, "LD", "An Longfort";
+    IE_LH, IE, "LH", "Louth";
+    IE_LK, IE, "LK", "Limerick";
+    IE_LM, IE, "LM", "Leitrim";
+    IE_LS, IE, "LS", "Laois";
+    IE_M, IE, "M", "An Mhumhain";
+    IE_MH, IE, "MH", "An Mhí";
+    IE_MN, IE, "MN", "Monaghan";
+    IE_MO, IE, "MO", "Maigh Eo";
+    IE_OY, IE, "OY", "Offaly";
+    IE_RN, IE, "RN", "Ros Comáin";
+    IE_SO, IE, "SO", "Sligeach";
+    IE_TA, IE, "TA", "Tiobraid Árann";
+    IE_U, IE, "U", "Ulaidh";
+    IE_WD, IE, "WD", "Port Láirge";
+    IE_WH, IE, "WH", "An Iarmhí";
+    IE_WW, IE, "WW", "Cill Mhantáin";
+    IE_WX, IE, "WX", "Loch Garman";
+    IL_D, IL, "D", "Southern District";
+    IL_HA, IL, "HA", "Haifa";
+    IL_JM, IL, "JM", "Jerusalem";
+    IL_M, IL, "M", "Central District";
+    IL_TA, IL, "TA", "Tel Aviv";
+    IL_Z, IL, "Z", "Northern District";
+    IN_AN, IN, "AN", "Andaman and Nicobar Islands";
+    IN_AP, IN, "AP", "Andhra Pradesh";
+    IN_AR, IN, "AR", "Arunachal Pradesh";
+    IN_AS, IN, "AS", "Assam";
+    IN_BR, IN, "BR", "Bihar";
+    IN_CH, IN, "CH", "Chandigarh";
+    IN_CT, IN, "CT", "Chhattisgarh";
+    IN_DD, IN, "DD", "Daman and Diu";
+    IN_DL, IN, "DL", "Delhi";
+    IN_DN, IN, "DN", "Dadra and Nagar Haveli";
+    IN_GA, IN, "GA", "Goa";
+    IN_GJ, IN, "GJ", "Gujarat";
+    IN_HP, IN, "HP", "Himachal Pradesh";
+    IN_HR, IN, "HR", "Haryana";
+    IN_JH, IN, "JH", "Jharkhand";
+    IN_JK, IN, "JK", "Jammu and Kashmir";
+    IN_KA, IN, "KA", "Karnataka";
+    IN_KL, IN, "KL", "Kerala";
+    IN_LD, IN, "LD", "Lakshadweep";
+    IN_MH, IN, "MH", "Maharashtra";
+    IN_ML, IN, "ML", "Meghalaya";
+    IN_MN, IN, "MN", "Manipur";
+    IN_MP, IN, "MP", "Madhya Pradesh";
+    IN_MZ, IN, "MZ", "Mizoram";
+    IN_NL, IN, "NL", "Nagaland";
+    IN_OR, IN, "OR", "Odisha";
+    IN_PB, IN, "PB", "Punjab";
+    IN_PY, IN, "PY", "Puducherry";
+    IN_RJ, IN, "RJ", "Rajasthan";
+    IN_SK, IN, "SK", "Sikkim";
+    IN_TG, IN, "TG", "Telangana";
+    IN_TN, IN, "TN", "Tamil Nadu";
+    IN_TR, IN, "TR", "Tripura";
+    IN_UP, IN, "UP", "Uttar Pradesh";
+    IN_UT, IN, "UT", "Uttarakhand";
+    IN_WB, IN, "WB", "West Bengal";
+    IQ_AN, IQ, "AN", "Al Anbār";
+    IQ_AR, IQ, "AR", "Arbīl";
+    IQ_BA, IQ, "BA", "Al Başrah";
+    IQ_BB, IQ, "BB", "Bābil";
+    IQ_BG, IQ, "BG", "Baghdād";
+    IQ_DA, IQ, "DA", "Dahūk";
+    IQ_DI, IQ, "DI", "Diyālá";
+    IQ_DQ, IQ, "DQ", "Dhī Qār";
+    IQ_KA, IQ, "KA", "Karbalā'";
+    IQ_KI, IQ, "KI", "Kirkūk";
+    IQ_MA, IQ, "MA", "Maysān";
+    IQ_MU, IQ, "MU", "Al Muthanná";
+    IQ_NA, IQ, "NA", "An Najaf";
+    IQ_NI, IQ, "NI", "Nīnawá";
+    IQ_QA, IQ, "QA", "Al Qādisīyah";
+    IQ_SD, IQ, "SD", "Şalāḩ ad Dīn";
+    IQ_SU, IQ, "SU", "As Sulaymānīyah";
+    IQ_WA, IQ, "WA", "Wāsiţ";
+    IR_01, IR, "01", "Āz̄arbāyjān-e Sharqī";
+    IR_02, IR, "02", "Āz̄arbāyjān-e Gharbī";
+    IR_03, IR, "03", "Ardabīl";
+    IR_04, IR, "04", "Eşfahān";
+    IR_05, IR, "05", "Īlām";
+    IR_06, IR, "06", "Būshehr";
+    IR_07, IR, "07", "Tehrān";
+    IR_08, IR, "08", "Chahār Maḩāll va Bakhtīārī";
+    IR_10, IR, "10", "Khūzestān";
+    IR_11, IR, "11", "Zanjān";
+    IR_12, IR, "12", "Semnān";
+    IR_13, IR, "13", "Sīstān va Balūchestān";
+    IR_14, IR, "14", "Fārs";
+    IR_15, IR, "15", "Kermān";
+    IR_16, IR, "16", "Kordestān";
+    IR_17, IR, "17", "Kermānshāh";
+    IR_18, IR, "18", "Kohgīlūyeh va Būyer Aḩmad";
+    IR_19, IR, "19", "Gīlān";
+    IR_20, IR, "20", "Lorestān";
+    IR_21, IR, "21", "Māzandarān";
+    IR_22, IR, "22", "Markazī";
+    IR_23, IR, "23", "Hormozgān";
+    IR_24, IR, "24", "Hamadān";
+    IR_25, IR, "25", "Yazd";
+    IR_26, IR, "26", "Qom";
+    IR_27, IR, "27", "Golestān";
+    IR_28, IR, "28", "Qazvīn";
+    IR_29, IR, "29", "Khorāsān-e Janūbī";
+    IR_30, IR, "30", "Khorāsān-e Razavī";
+    IR_31, IR, "31", "Khorāsān-e Shemālī";
+    IR_32, IR, "32", "Alborz";
+    IS_0, IS, "0", "Reykjavík";
+    IS_1, IS, "1", "Höfuðborgarsvæði utan Reykjavíkur";
+    IS_2, IS, "2", "Suðurnes";
+    IS_3, IS, "3", "Vesturland";
+    IS_4, IS, "4", "Vestfirðir";
+    IS_5, IS, "5", "Norðurland vestra";
+    IS_6, IS, "6", "Norðurland eystra";
+    IS_7, IS, "7", "Austurland";
+    IS_8, IS, "8", "Suðurland";
+    IT_21, IT, "21", "Piemonte";
+    IT_23, IT, "23", "Val d'Aoste";
+    IT_25, IT, "25", "Lombardia";
+    IT_32, IT, "32", "Trentino-Alto Adige";
+    IT_34, IT, "34", "Veneto";
+    IT_36, IT, "36", "Friuli-Venezia Giulia";
+    IT_42, IT, "42", "Liguria";
+    IT_45, IT, "45", "Emilia-Romagna";
+    IT_52, IT, "52", "Toscana";
+    IT_55, IT, "55", "Umbria";
+    IT_57, IT, "57", "Marche";
+    IT_62, IT, "62", "Lazio";
+    IT_65, IT, "65", "Abruzzo";
+    IT_67, IT, "67", "Molise";
+    IT_72, IT, "72", "Campania";
+    IT_75, IT, "75", "Puglia";
+    IT_77, IT, "77", "Basilicata";
+    IT_78, IT, "78", "Calabria";
+    IT_82, IT, "82", "Sicilia";
+    IT_88, IT, "88", "Sardegna";
+    IT_AG, IT, "AG", "Agrigento";
+    IT_AL, IT, "AL", "Alessandria";
+    IT_AN, IT, "AN", "Ancona";
+    IT_AO, IT, "AO", "Aosta";
+    IT_AP, IT, "AP", "Ascoli Piceno";
+    IT_AQ, IT, "AQ", "L'Aquila";
+    IT_AR, IT, "AR", "Arezzo";
+    IT_AT, IT, "AT", "Asti";
+    IT_AV, IT, "AV", "Avellino";
+    IT_BA, IT, "BA", "Bari";
+    IT_BG, IT, "BG", "Bergamo";
+    IT_BI, IT, "BI", "Biella";
+    IT_BL, IT, "BL", "Belluno";
+    IT_BN, IT, "BN", "Benevento";
+    IT_BO, IT, "BO", "Bologna";
+    IT_BR, IT, "BR", "Brindisi";
+    IT_BS, IT, "BS", "Brescia";
+    IT_BT, IT, "BT", "Barletta-Andria-Trani";
+    IT_BZ, IT, "BZ", "Bolzano";
+    IT_CA, IT, "CA", "Cagliari";
+    IT_CB, IT, "CB", "Campobasso";
+    IT_CE, IT, "CE", "Caserta";
+    IT_CH, IT, "CH", "Chieti";
+    IT_CI, IT, "CI", "Carbonia-Iglesias";
+    IT_CL, IT, "CL", "Caltanissetta";
+    IT_CN, IT, "CN", "Cuneo";
+    IT_CO, IT, "CO", "Como";
+    IT_CR, IT, "CR", "Cremona";
+    IT_CS, IT, "CS", "Cosenza";
+    IT_CT, IT, "CT", "Catania";
+    IT_CZ, IT, "CZ", "Catanzaro";
+    IT_EN, IT, "EN", "Enna";
+    IT_FC, IT, "FC", "Forlì-Cesena";
+    IT_FE, IT, "FE", "Ferrara";
+    IT_FG, IT, "FG", "Foggia";
+    IT_FI, IT, "FI", "Firenze";
+    IT_FM, IT, "FM", "Fermo";
+    IT_FR, IT, "FR", "Frosinone";
+    IT_GE, IT, "GE", "Genova";
+    IT_GO, IT, "GO", "Gorizia";
+    IT_GR, IT, "GR", "Grosseto";
+    IT_IM, IT, "IM", "Imperia";
+    IT_IS, IT, "IS", "Isernia";
+    IT_KR, IT, "KR", "Crotone";
+    IT_LC, IT, "LC", "Lecco";
+    IT_LE, IT, "LE", "Lecce";
+    IT_LI, IT, "LI", "Livorno";
+    IT_LO, IT, "LO", "Lodi";
+    IT_LT, IT, "LT", "Latina";
+    IT_LU, IT, "LU", "Lucca";
+    IT_MB, IT, "MB", "Monza e Brianza";
+    IT_MC, IT, "MC", "Macerata";
+    IT_ME, IT, "ME", "Messina";
+    IT_MI, IT, "MI", "Milano";
+    IT_MN, IT, "MN", "Mantova";
+    IT_MO, IT, "MO", "Modena";
+    IT_MS, IT, "MS", "Massa-Carrara";
+    IT_MT, IT, "MT", "Matera";
+    IT_NA, IT, "NA", "Napoli";
+    IT_NO, IT, "NO", "Novara";
+    IT_NU, IT, "NU", "Nuoro";
+    IT_OG, IT, "OG", "Ogliastra";
+    IT_OR, IT, "OR", "Oristano";
+    IT_OT, IT, "OT", "Olbia-Tempio";
+    IT_PA, IT, "PA", "Palermo";
+    IT_PC, IT, "PC", "Piacenza";
+    IT_PD, IT, "PD", "Padova";
+    IT_PE, IT, "PE", "Pescara";
+    IT_PG, IT, "PG", "Perugia";
+    IT_PI, IT, "PI", "Pisa";
+    IT_PN, IT, "PN", "Pordenone";
+    IT_PO, IT, "PO", "Prato";
+    IT_PR, IT, "PR", "Parma";
+    IT_PT, IT, "PT", "Pistoia";
+    IT_PU, IT, "PU", "Pesaro e Urbino";
+    IT_PV, IT, "PV", "Pavia";
+    IT_PZ, IT, "PZ", "Potenza";
+    IT_RA, IT, "RA", "Ravenna";
+    IT_RC, IT, "RC", "Reggio Calabria";
+    IT_RE, IT, "RE", "Reggio Emilia";
+    IT_RG, IT, "RG", "Ragusa";
+    IT_RI, IT, "RI", "Rieti";
+    IT_RM, IT, "RM", "Roma";
+    IT_RN, IT, "RN", "Rimini";
+    IT_RO, IT, "RO", "Rovigo";
+    IT_SA, IT, "SA", "Salerno";
+    IT_SI, IT, "SI", "Siena";
+    IT_SO, IT, "SO", "Sondrio";
+    IT_SP, IT, "SP", "La Spezia";
+    IT_SR, IT, "SR", "Siracusa";
+    IT_SS, IT, "SS", "Sassari";
+    IT_SV, IT, "SV", "Savona";
+    IT_TA, IT, "TA", "Taranto";
+    IT_TE, IT, "TE", "Teramo";
+    IT_TN, IT, "TN", "Trento";
+    IT_TO, IT, "TO", "Torino";
+    IT_TP, IT, "TP", "Trapani";
+    IT_TR, IT, "TR", "Terni";
+    IT_TS, IT, "TS", "Trieste";
+    IT_TV, IT, "TV", "Treviso";
+    IT_UD, IT, "UD", "Udine";
+    IT_VA, IT, "VA", "Varese";
+    IT_VB, IT, "VB", "Verbano-Cusio-Ossola";
+    IT_VC, IT, "VC", "Vercelli";
+    IT_VE, IT, "VE", "Venezia";
+    IT_VI, IT, "VI", "Vicenza";
+    IT_VR, IT, "VR", "Verona";
+    IT_VS, IT, "VS", "Medio Campidano";
+    IT_VT, IT, "VT", "Viterbo";
+    IT_VV, IT, "VV", "Vibo Valentia";
+    JM_01, JM, "01", "Kingston";
+    JM_02, JM, "02", "Saint Andrew";
+    JM_03, JM, "03", "Saint Thomas";
+    JM_04, JM, "04", "Portland";
+    JM_05, JM, "05", "Saint Mary";
+    JM_06, JM, "06", "Saint Ann";
+    JM_07, JM, "07", "Trelawny";
+    JM_08, JM, "08", "Saint James";
+    JM_09, JM, "09", "Hanover";
+    JM_10, JM, "10", "Westmoreland";
+    JM_11, JM, "11", "Saint Elizabeth";
+    JM_12, JM, "12", "Manchester";
+    JM_13, JM, "13", "Clarendon";
+    JM_14, JM, "14", "Saint Catherine";
+    JO_AJ, JO, "AJ", "ʽAjlūn";
+    JO_AM, JO, "AM", "‘Ammān";
+    JO_AQ, JO, "AQ", "Al ʽAqabah";
+    JO_AT, JO, "AT", "Aţ Ţafīlah";
+    JO_AZ, JO, "AZ", "Az Zarqā'";
+    JO_BA, JO, "BA", "Al Balqā'";
+    JO_IR, JO, "IR", "Irbid";
+    JO_JA, JO, "JA", "Jarash";
+    JO_KA, JO, "KA", "Al Karak";
+    JO_MA, JO, "MA", "Al Mafraq";
+    JO_MD, JO, "MD", "Mādabā";
+    JO_MN, JO, "MN", "Ma'ān";
+    JP_01, JP, "01", "Hokkaido";
+    JP_02, JP, "02", "Aomori";
+    JP_03, JP, "03", "Iwate";
+    JP_04, JP, "04", "Miyagi";
+    JP_05, JP, "05", "Akita";
+    JP_06, JP, "06", "Yamagata";
+    JP_07, JP, "07", "Fukushima";
+    JP_08, JP, "08", "Ibaraki";
+    JP_09, JP, "09", "Tochigi";
+    JP_10, JP, "10", "Gunma";
+    JP_11, JP, "11", "Saitama";
+    JP_12, JP, "12", "Chiba";
+    JP_13, JP, "13", "Tokyo";
+    JP_14, JP, "14", "Kanagawa";
+    JP_15, JP, "15", "Niigata";
+    JP_16, JP, "16", "Toyama";
+    JP_17, JP, "17", "Ishikawa";
+    JP_18, JP, "18", "Fukui";
+    JP_19, JP, "19", "Yamanashi";
+    JP_20, JP, "20", "Nagano";
+    JP_21, JP, "21", "Gifu";
+    JP_22, JP, "22", "Shizuoka";
+    JP_23, JP, "23", "Aichi";
+    JP_24, JP, "24", "Mie";
+    JP_25, JP, "25", "Shiga";
+    JP_26, JP, "26", "Kyoto";
+    JP_27, JP, "27", "Osaka";
+    JP_28, JP, "28", "Hyogo";
+    JP_29, JP, "29", "Nara";
+    JP_30, JP, "30", "Wakayama";
+    JP_31, JP, "31", "Tottori";
+    JP_32, JP, "32", "Shimane";
+    JP_33, JP, "33", "Okayama";
+    JP_34, JP, "34", "Hiroshima";
+    JP_35, JP, "35", "Yamaguchi";
+    JP_36, JP, "36", "Tokushima";
+    JP_37, JP, "37", "Kagawa";
+    JP_38, JP, "38", "Ehime";
+    JP_39, JP, "39", "Kochi";
+    JP_40, JP, "40", "Fukuoka";
+    JP_41, JP, "41", "Saga";
+    JP_42, JP, "42", "Nagasaki";
+    JP_43, JP, "43", "Kumamoto";
+    JP_44, JP, "44", "Oita";
+    JP_45, JP, "45", "Miyazaki";
+    JP_46, JP, "46", "Kagoshima";
+    JP_47, JP, "47", "Okinawa";
+    KE_01, KE, "01", "Baringo";
+    KE_02, KE, "02", "Bomet";
+    KE_03, KE, "03", "Bungoma";
+    KE_04, KE, "04", "Busia";
+    KE_05, KE, "05", "Elgeyo/Marakwet";
+    KE_06, KE, "06", "Embu";
+    KE_07, KE, "07", "Garissa";
+    KE_08, KE, "08", "Homa Bay";
+    KE_09, KE, "09", "Isiolo";
+    KE_10, KE, "10", "Kajiado";
+    KE_11, KE, "11", "Kakamega";
+    KE_12, KE, "12", "Kericho";
+    KE_13, KE, "13", "Kiambu";
+    KE_14, KE, "14", "Kilifi";
+    KE_15, KE, "15", "Kirinyaga";
+    KE_16, KE, "16", "Kisii";
+    KE_17, KE, "17", "Kisumu";
+    KE_18, KE, "18", "Kitui";
+    KE_19, KE, "19", "Kwale";
+    KE_20, KE, "20", "Laikipia";
+    KE_21, KE, "21", "Lamu";
+    KE_22, KE, "22", "Machakos";
+    KE_23, KE, "23", "Makueni";
+    KE_24, KE, "24", "Mandera";
+    KE_25, KE, "25", "Marsabit";
+    KE_26, KE, "26", "Meru";
+    KE_27, KE, "27", "Migori";
+    KE_28, KE, "28", "Mombasa";
+    KE_29, KE, "29", "Murang'a";
+    KE_30, KE, "30", "Nairobi City";
+    KE_31, KE, "31", "Nakuru";
+    KE_32, KE, "32", "Nandi";
+    KE_33, KE, "33", "Narok";
+    KE_34, KE, "34", "Nyamira";
+    KE_35, KE, "35", "Nyandarua";
+    KE_36, KE, "36", "Nyeri";
+    KE_37, KE, "37", "Samburu";
+    KE_38, KE, "38", "Siaya";
+    KE_39, KE, "39", "Taita/Taveta";
+    KE_40, KE, "40", "Tana River";
+    KE_41, KE, "41", "Tharaka-Nithi";
+    KE_42, KE, "42", "Trans Nzoia";
+    KE_43, KE, "43", "Turkana";
+    KE_44, KE, "44", "Uasin Gishu";
+    KE_45, KE, "45", "Vihiga";
+    KE_46, KE, "46", "Wajir";
+    KE_47, KE, "47", "West Pokot";
+    KG_B, KG, "B", "Batken";
+    KG_C, KG, "C", "Chü";
+    KG_GB, KG, "GB", "Bishkek";
+    KG_GO, KG, "GO", "Gorod Oš";
+    KG_J, KG, "J", "Džalal-Abadskaja oblast'";
+    KG_N, KG, "N", "Naryn";
+    KG_O, KG, "O", "Osh";
+    KG_T, KG, "T", "Talas";
+    KG_Y, KG, "Y", "Issyk-Kul'skaja oblast'";
+    KH_1, KH, "1", "Bântéay Méanchey";
+    KH_10, KH, "10", "Krâchéh";
+    KH_11, KH, "11", "Môndól Kiri";
+    KH_12, KH, "12", "Phnom Penh";
+    KH_13, KH, "13", "Preăh Vihéar";
+    KH_14, KH, "14", "Prey Veaeng";
+    KH_15, KH, "15", "Pousaat";
+    KH_16, KH, "16", "Rotanak Kiri";
+    KH_17, KH, "17", "Siem Reab";
+    KH_18, KH, "18", "Krŏng Preăh Sihanouk";
+    KH_19, KH, "19", "Stoĕng Trêng";
+    KH_2, KH, "2", "Baat Dambang";
+    KH_20, KH, "20", "Svaay Rieng";
+    KH_21, KH, "21", "Taakaev";
+    KH_22, KH, "22", "Ŏtdâr Méanchey";
+    KH_23, KH, "23", "Krong Kaeb";
+    KH_24, KH, "24", "Krŏng Pailĭn";
+    KH_3, KH, "3", "Kampong Chaam";
+    KH_4, KH, "4", "Kampong Chhnang";
+    KH_5, KH, "5", "Kâmpóng Spœ";
+    KH_6, KH, "6", "Kâmpóng Thum";
+    KH_7, KH, "7", "Kampot";
+    KH_8, KH, "8", "Kandaal";
+    KH_9, KH, "9", "Kaoh Kong";
+    KI_G, KI, "G", "Gilbert Islands";
+    KI_L, KI, "L", "Line Islands";
+    KI_P, KI, "P", "Phoenix Islands";
+    KM_A, KM, "A", "Andjouân";
+    KM_G, KM, "G", "Andjazîdja";
+    KM_M, KM, "M", "Mohéli";
+    KN_01, KN, "01", "Christ Church Nichola Town";
+    KN_02, KN, "02", "Saint Anne Sandy Point";
+    KN_03, KN, "03", "Saint George Basseterre";
+    KN_04, KN, "04", "Saint George Gingerland";
+    KN_05, KN, "05", "Saint James Windward";
+    KN_06, KN, "06", "Saint John Capisterre";
+    KN_07, KN, "07", "Saint John Figtree";
+    KN_08, KN, "08", "Saint Mary Cayon";
+    KN_09, KN, "09", "Saint Paul Capisterre";
+    KN_10, KN, "10", "Saint Paul Charlestown";
+    KN_11, KN, "11", "Saint Peter Basseterre";
+    KN_12, KN, "12", "Saint Thomas Lowland";
+    KN_13, KN, "13", "Saint Thomas Middle Island";
+    KN_15, KN, "15", "Trinity Palmetto Point";
+    KN_K, KN, "K", "Saint Kitts";
+    KN_N, KN, "N", "Nevis";
+    KP_01, KP, "01", "P'yǒngyang";
+    KP_02, KP, "02", "P'yǒngan-namdo";
+    KP_03, KP, "03", "P'yǒngan-bukto";
+    KP_04, KP, "04", "Chagang-do";
+    KP_05, KP, "05", "Hwanghae-namdo";
+    KP_06, KP, "06", "Hwanghae-bukto";
+    KP_07, KP, "07", "Kangweonto";
+    KP_08, KP, "08", "Hamgyǒng-namdo";
+    KP_09, KP, "09", "Hamgyǒng-bukto";
+    KP_10, KP, "10", "Ryangkangto";
+    KP_13, KP, "13", "Nasǒn";
+    KR_11, KR, "11", "Seoul-T'ǔkpyǒlshi";
+    KR_26, KR, "26", "Busan Gwang'yeogsi";
+    KR_27, KR, "27", "Daegu Gwang'yeogsi";
+    KR_28, KR, "28", "Inch'ǒn-Kwangyǒkshi";
+    KR_29, KR, "29", "Gwangju Gwang'yeogsi";
+    KR_30, KR, "30", "Daejeon Gwang'yeogsi";
+    KR_31, KR, "31", "Ulsan Gwang'yeogsi";
+    KR_41, KR, "41", "Gyeonggido";
+    KR_42, KR, "42", "Gang'weondo";
+    KR_43, KR, "43", "Ch'ungch'ǒngbuk-do";
+    KR_44, KR, "44", "Ch'ungch'ǒngnam-do";
+    KR_45, KR, "45", "Chǒllabuk-do";
+    KR_46, KR, "46", "Chǒllanam-do";
+    KR_47, KR, "47", "Gyeongsangbugdo";
+    KR_48, KR, "48", "Gyeongsangnamdo";
+    KR_49, KR, "49", "Cheju-do";
+    KR_50, KR, "50", "Sejong";
+    KW_AH, KW, "AH", "Al Aḩmadī";
+    KW_FA, KW, "FA", "Al Farwānīyah";
+    KW_HA, KW, "HA", "Ḩawallī";
+    KW_JA, KW, "JA", "Al Jahrā’";
+    KW_KU, KW, "KU", "Al Kuwayt";
+    KW_MU, KW, "MU", "Mubārak al Kabīr";
+    KZ_AKM, KZ, "AKM", "Akmolinskaja oblast'";
+    KZ_AKT, KZ, "AKT", "Aktjubinskaja oblast'";
+    KZ_ALA, KZ, "ALA", "Almaty";
+    KZ_ALM, KZ, "ALM", "Almatinskaja oblast'";
+    KZ_AST, KZ, "AST", "Astana";
+    KZ_ATY, KZ, "ATY", "Atyraū oblysy";
+    KZ_KAR, KZ, "KAR", "Karagandinskaja oblast'";
+    KZ_KUS, KZ, "KUS", "Kostanajskaja oblast'";
+    KZ_KZY, KZ, "KZY", "Kyzylordinskaja oblast'";
+    KZ_MAN, KZ, "MAN", "Mangghystaū oblysy";
+    KZ_PAV, KZ, "PAV", "Pavlodar oblysy";
+    KZ_SEV, KZ, "SEV", "Severo-Kazahstanskaja oblast'";
+    KZ_VOS, KZ, "VOS", "Shyghys Qazaqstan oblysy";
+    KZ_YUZ, KZ, "YUZ", "Južno-Kazahstanskaja oblast'";
+    KZ_ZAP, KZ, "ZAP", "Batys Qazaqstan oblysy";
+    KZ_ZHA, KZ, "ZHA", "Žambylskaja oblast'";
+    LA_AT, LA, "AT", "Attapu";
+    LA_BK, LA, "BK", "Bokèo";
+    LA_BL, LA, "BL", "Bolikhamxai";
+    LA_CH, LA, "CH", "Champasak";
+    LA_HO, LA, "HO", "Houaphan";
+    LA_KH, LA, "KH", "Khammouan";
+    LA_LM, LA, "LM", "Louang Namtha";
+    LA_LP, LA, "LP", "Louangphabang";
+    LA_OU, LA, "OU", "Oudomsai";
+    LA_PH, LA, "PH", "Phôngsali";
+    LA_SL, LA, "SL", "Salavan";
+    LA_SV, LA, "SV", "Savannakhét";
+    LA_VI, LA, "VI", "Vientiane";
+    LA_VT, LA, "VT", "Vientiane";
+    LA_XA, LA, "XA", "Sayaboury";
+    LA_XE, LA, "XE", "Sékong";
+    LA_XI, LA, "XI", "Xiangkhouang";
+    LA_XN, LA, "XN", "Xaisômboun";
+    LB_AK, LB, "AK", "Aakkâr";
+    LB_AS, LB, "AS", "Ash Shimāl";
+    LB_BA, LB, "BA", "Bayrūt";
+    LB_BH, LB, "BH", "Baalbek-Hermel";
+    LB_BI, LB, "BI", "Al Biqā‘";
+    LB_JA, LB, "JA", "Al Janūb";
+    LB_JL, LB, "JL", "Jabal Lubnān";
+    LB_NA, LB, "NA", "An Nabaţīyah";
+    LC_01, LC, "01", "Anse la Raye";
+    LC_02, LC, "02", "Castries";
+    LC_03, LC, "03", "Choiseul";
+    LC_05, LC, "05", "Dennery";
+    LC_06, LC, "06", "Gros Islet";
+    LC_07, LC, "07", "Laborie";
+    LC_08, LC, "08", "Micoud";
+    LC_10, LC, "10", "Soufrière";
+    LC_11, LC, "11", "Vieux Fort";
+    LC_12, LC, "12", "Canaries";
+    LI_01, LI, "01", "Balzers";
+    LI_02, LI, "02", "Eschen";
+    LI_03, LI, "03", "Gamprin";
+    LI_04, LI, "04", "Mauren";
+    LI_05, LI, "05", "Planken";
+    LI_06, LI, "06", "Ruggell";
+    LI_07, LI, "07", "Schaan";
+    LI_08, LI, "08", "Schellenberg";
+    LI_09, LI, "09", "Triesen";
+    LI_10, LI, "10", "Triesenberg";
+    LI_11, LI, "11", "Vaduz";
+    LK_1, LK, "1", "Basnāhira paḷāta";
+    LK_11, LK, "11", "Colombo";
+    LK_12, LK, "12", "Gampaha";
+    LK_13, LK, "13", "Kalutara";
+    LK_2, LK, "2", "Central Province";
+    LK_21, LK, "21", "Kandy";
+    LK_22, LK, "22", "Matale";
+    LK_23, LK, "23", "Nuvara Ĕliya";
+    LK_3, LK, "3", "Dakuṇu paḷāta";
+    LK_31, LK, "31", "Gālla";
+    LK_32, LK, "32", "Matara";
+    LK_33, LK, "33", "Ampāntōṭṭai";
+    LK_4, LK, "4", "Northern Province";
+    LK_41, LK, "41", "Jaffna";
+    LK_42, LK, "42", "Kilinochchi";
+    LK_43, LK, "43", "Mannar";
+    LK_44, LK, "44", "Vavuniya";
+    LK_45, LK, "45", "Mulativ";
+    LK_5, LK, "5", "Eastern Province";
+    LK_51, LK, "51", "Batticaloa";
+    LK_52, LK, "52", "Ampara";
+    LK_53, LK, "53", "Tirukŏṇamalai";
+    LK_6, LK, "6", "North Western Province";
+    LK_61, LK, "61", "Kuruṇægala";
+    LK_62, LK, "62", "Puttalam";
+    LK_7, LK, "7", "North Central Province";
+    LK_71, LK, "71", "Anuradhapura";
+    LK_72, LK, "72", "Pŏlaṉṉaṛuvai";
+    LK_8, LK, "8", "Ūvā mākāṇam";
+    LK_81, LK, "81", "Badulla";
+    LK_82, LK, "82", "Monaragala";
+    LK_9, LK, "9", "Chappirakamuva mākāṇam";
+    LK_91, LK, "91", "Irattiṉapuri";
+    LK_92, LK, "92", "Kægalla";
+    LR_BG, LR, "BG", "Bong";
+    LR_BM, LR, "BM", "Bomi";
+    LR_CM, LR, "CM", "Grand Cape Mount";
+    LR_GB, LR, "GB", "Grand Bassa";
+    LR_GG, LR, "GG", "Grand Gedeh";
+    LR_GK, LR, "GK", "Grand Kru";
+    LR_GP, LR, "GP", "Gbarpolu";
+    LR_LO, LR, "LO", "Lofa";
+    LR_MG, LR, "MG", "Margibi";
+    LR_MO, LR, "MO", "Montserrado";
+    LR_MY, LR, "MY", "Maryland";
+    LR_NI, LR, "NI", "Nimba";
+    LR_RG, LR, "RG", "River Gee";
+    LR_RI, LR, "RI", "Rivercess";
+    LR_SI, LR, "SI", "Sinoe";
+    LS_A, LS, "A", "Maseru";
+    LS_B, LS, "B", "Butha-Buthe";
+    LS_C, LS, "C", "Leribe";
+    LS_D, LS, "D", "Berea";
+    LS_E, LS, "E", "Mafeteng";
+    LS_F, LS, "F", "Mohale's Hoek";
+    LS_G, LS, "G", "Quthing";
+    LS_H, LS, "H", "Qacha's Nek";
+    LS_J, LS, "J", "Mokhotlong";
+    LS_K, LS, "K", "Thaba-Tseka";
+    LT_01, LT, "01", "Akmenė";
+    LT_02, LT, "02", "Alytaus miestas";
+    LT_03, LT, "03", "Alytus";
+    LT_04, LT, "04", "Anykščiai";
+    LT_05, LT, "05", "Birštono";
+    LT_06, LT, "06", "Biržai";
+    LT_07, LT, "07", "Druskininkai";
+    LT_08, LT, "08", "Elektrénai";
+    LT_09, LT, "09", "Ignalina";
+    LT_10, LT, "10", "Jonava";
+    LT_11, LT, "11", "Joniškis";
+    LT_12, LT, "12", "Jurbarkas";
+    LT_13, LT, "13", "Kaišiadorys";
+    LT_14, LT, "14", "Kalvarijos";
+    LT_15, LT, "15", "Kauno miestas";
+    LT_16, LT, "16", "Kaunas";
+    LT_17, LT, "17", "Kazlų Rūdos";
+    LT_18, LT, "18", "Kėdainiai";
+    LT_19, LT, "19", "Kelmė";
+    LT_20, LT, "20", "Klaipėdos miestas";
+    LT_21, LT, "21", "Klaipėda";
+    LT_22, LT, "22", "Kretinga";
+    LT_23, LT, "23", "Kupiškis";
+    LT_24, LT, "24", "Lazdijai";
+    LT_25, LT, "25", "Marijampolė";
+    LT_26, LT, "26", "Mažeikiai";
+    LT_27, LT, "27", "Molėtai";
+    LT_28, LT, "28", "Neringa";
+    LT_29, LT, "29", "Pagégiai";
+    LT_30, LT, "30", "Pakruojis";
+    LT_31, LT, "31", "Palangos miestas";
+    LT_32, LT, "32", "Panevėžio miestas";
+    LT_33, LT, "33", "Panevėžys";
+    LT_34, LT, "34", "Pasvalys";
+    LT_35, LT, "35", "Plungė";
+    LT_36, LT, "36", "Prienai";
+    LT_37, LT, "37", "Radviliškis";
+    LT_38, LT, "38", "Raseiniai";
+    LT_39, LT, "39", "Rietavo";
+    LT_40, LT, "40", "Rokiškis";
+    LT_41, LT, "41", "Šakiai";
+    LT_42, LT, "42", "Šalčininkai";
+    LT_43, LT, "43", "Šiaulių miestas";
+    LT_44, LT, "44", "Šiauliai";
+    LT_45, LT, "45", "Šilalė";
+    LT_46, LT, "46", "Šilutė";
+    LT_47, LT, "47", "Širvintos";
+    LT_48, LT, "48", "Skuodas";
+    LT_49, LT, "49", "Švenčionys";
+    LT_50, LT, "50", "Tauragė";
+    LT_51, LT, "51", "Telšiai";
+    LT_52, LT, "52", "Trakai";
+    LT_53, LT, "53", "Ukmergė";
+    LT_54, LT, "54", "Utena";
+    LT_55, LT, "55", "Varėna";
+    LT_56, LT, "56", "Vilkaviškis";
+    LT_57, LT, "57", "Vilniaus miestas";
+    LT_58, LT, "58", "Vilnius";
+    LT_59, LT, "59", "Visaginas";
+    LT_60, LT, "60", "Zarasai";
+    LT_AL, LT, "AL", "Alytaus apskritis";
+    LT_KL, LT, "KL", "Klaipėdos apskritis";
+    LT_KU, LT, "KU", "Kauno apskritis";
+    LT_MR, LT, "MR", "Marijampolės apskritis";
+    LT_PN, LT, "PN", "Panevėžio apskritis";
+    LT_SA, LT, "SA", "Šiaulių apskritis";
+    LT_TA, LT, "TA", "Tauragės apskritis";
+    LT_TE, LT, "TE", "Telšių apskritis";
+    LT_UT, LT, "UT", "Utenos apskritis";
+    LT_VL, LT, "VL", "Vilniaus apskritis";
+    LU_D, LU, "D", "Diekirch";
+    LU_G, LU, "G", "Grevenmacher";
+    LU_L, LU, "L", "Lëtzebuerg";
+    LV_001, LV, "001", "Aglonas novads";
+    LV_002, LV, "002", "Aizkraukles novads";
+    LV_003, LV, "003", "Aizputes novads";
+    LV_004, LV, "004", "Aknīstes novads";
+    LV_005, LV, "005", "Alojas novads";
+    LV_006, LV, "006", "Alsungas novads";
+    LV_007, LV, "007", "Alūksnes novads";
+    LV_008, LV, "008", "Amatas novads";
+    LV_009, LV, "009", "Apes novads";
+    LV_010, LV, "010", "Auces novads";
+    LV_011, LV, "011", "Ādažu novads";
+    LV_012, LV, "012", "Babītes novads";
+    LV_013, LV, "013", "Baldones novads";
+    LV_014, LV, "014", "Baltinavas novads";
+    LV_015, LV, "015", "Balvu novads";
+    LV_016, LV, "016", "Bauskas novads";
+    LV_017, LV, "017", "Beverīnas novads";
+    LV_018, LV, "018", "Brocēnu novads";
+    LV_019, LV, "019", "Burtnieku novads";
+    LV_020, LV, "020", "Carnikavas novads";
+    LV_021, LV, "021", "Cesvaines novads";
+    LV_022, LV, "022", "Cēsu novads";
+    LV_023, LV, "023", "Ciblas novads";
+    LV_024, LV, "024", "Dagdas novads";
+    LV_025, LV, "025", "Daugavpils novads";
+    LV_026, LV, "026", "Dobeles novads";
+    LV_027, LV, "027", "Dundagas novads";
+    LV_028, LV, "028", "Durbes novads";
+    LV_029, LV, "029", "Engures novads";
+    LV_030, LV, "030", "Ērgļu novads";
+    LV_031, LV, "031", "Garkalnes novads";
+    LV_032, LV, "032", "Grobiņas novads";
+    LV_033, LV, "033", "Gulbenes novads";
+    LV_034, LV, "034", "Iecavas novads";
+    LV_035, LV, "035", "Ikšķiles novads";
+    LV_036, LV, "036", "Ilūkstes novads";
+    LV_037, LV, "037", "Inčukalna novads";
+    LV_038, LV, "038", "Jaunjelgavas novads";
+    LV_039, LV, "039", "Jaunpiebalgas novads";
+    LV_040, LV, "040", "Jaunpils novads";
+    LV_041, LV, "041", "Jelgavas novads";
+    LV_042, LV, "042", "Jēkabpils novads";
+    LV_043, LV, "043", "Kandavas novads";
+    LV_044, LV, "044", "Kārsavas novads";
+    LV_045, LV, "045", "Kocēnu novads";
+    LV_046, LV, "046", "Kokneses novads";
+    LV_047, LV, "047", "Krāslavas novads";
+    LV_048, LV, "048", "Krimuldas novads";
+    LV_049, LV, "049", "Krustpils novads";
+    LV_050, LV, "050", "Kuldīgas novads";
+    LV_051, LV, "051", "Ķeguma novads";
+    LV_052, LV, "052", "Ķekavas novads";
+    LV_053, LV, "053", "Lielvārdes novads";
+    LV_054, LV, "054", "Limbažu novads";
+    LV_055, LV, "055", "Līgatnes novads";
+    LV_056, LV, "056", "Līvānu novads";
+    LV_057, LV, "057", "Lubānas novads";
+    LV_058, LV, "058", "Ludzas novads";
+    LV_059, LV, "059", "Madonas novads";
+    LV_060, LV, "060", "Mazsalacas novads";
+    LV_061, LV, "061", "Mālpils novads";
+    LV_062, LV, "062", "Mārupes novads";
+    LV_063, LV, "063", "Mērsraga novads";
+    LV_064, LV, "064", "Naukšēnu novads";
+    LV_065, LV, "065", "Neretas novads";
+    LV_066, LV, "066", "Nīcas novads";
+    LV_067, LV, "067", "Ogres novads";
+    LV_068, LV, "068", "Olaines novads";
+    LV_069, LV, "069", "Ozolnieku novads";
+    LV_070, LV, "070", "Pārgaujas novads";
+    LV_071, LV, "071", "Pāvilostas novads";
+    LV_072, LV, "072", "Pļaviņu novads";
+    LV_073, LV, "073", "Preiļu novads";
+    LV_074, LV, "074", "Priekules novads";
+    LV_075, LV, "075", "Priekuļu novads";
+    LV_076, LV, "076", "Raunas novads";
+    LV_077, LV, "077", "Rēzeknes novads";
+    LV_078, LV, "078", "Riebiņu novads";
+    LV_079, LV, "079", "Rojas novads";
+    LV_080, LV, "080", "Ropažu novads";
+    LV_081, LV, "081", "Rucavas novads";
+    LV_082, LV, "082", "Rugāju novads";
+    LV_083, LV, "083", "Rundāles novads";
+    LV_084, LV, "084", "Rūjienas novads";
+    LV_085, LV, "085", "Salas novads";
+    LV_086, LV, "086", "Salacgrīvas novads";
+    LV_087, LV, "087", "Salaspils novads";
+    LV_088, LV, "088", "Saldus novads";
+    LV_089, LV, "089", "Saulkrastu novads";
+    LV_090, LV, "090", "Sējas novads";
+    LV_091, LV, "091", "Siguldas novads";
+    LV_092, LV, "092", "Skrīveru novads";
+    LV_093, LV, "093", "Skrundas novads";
+    LV_094, LV, "094", "Smiltenes novads";
+    LV_095, LV, "095", "Stopiņu novads";
+    LV_096, LV, "096", "Strenču novads";
+    LV_097, LV, "097", "Talsu novads";
+    LV_098, LV, "098", "Tērvetes novads";
+    LV_099, LV, "099", "Tukuma novads";
+    LV_100, LV, "100", "Vaiņodes novads";
+    LV_101, LV, "101", "Valkas novads";
+    LV_102, LV, "102", "Varakļānu novads";
+    LV_103, LV, "103", "Vārkavas novads";
+    LV_104, LV, "104", "Vecpiebalgas novads";
+    LV_105, LV, "105", "Vecumnieku novads";
+    LV_106, LV, "106", "Ventspils novads";
+    LV_107, LV, "107", "Viesītes novads";
+    LV_108, LV, "108", "Viļakas novads";
+    LV_109, LV, "109", "Viļānu novads";
+    LV_110, LV, "110", "Zilupes novads";
+    LV_DGV, LV, "DGV", "Daugavpils";
+    LV_JEL, LV, "JEL", "Jelgava";
+    LV_JKB, LV, "JKB", "Jēkabpils";
+    LV_JUR, LV, "JUR", "Jūrmala";
+    LV_LPX, LV, "LPX", "Liepāja";
+    LV_REZ, LV, "REZ", "Rēzekne";
+    LV_RIX, LV, "RIX", "Rīga";
+    LV_VEN, LV, "VEN", "Ventspils";
+    LV_VMR, LV, "VMR", "Valmiera";
+    LY_BA, LY, "BA", "Banghāzī";
+    LY_BU, LY, "BU", "Al Buţnān";
+    LY_DR, LY, "DR", "Darnah";
+    LY_GT, LY, "GT", "Ghāt";
+    LY_JA, LY, "JA", "Al Jabal al Akhḑar";
+    LY_JG, LY, "JG", "Al Jabal al Gharbī";
+    LY_JI, LY, "JI", "Al Jifārah";
+    LY_JU, LY, "JU", "Al Jufrah";
+    LY_KF, LY, "KF", "Al Kufrah";
+    LY_MB, LY, "MB", "Al Marqab";
+    LY_MI, LY, "MI", "Mişrātah";
+    LY_MJ, LY, "MJ", "Al Marj";
+    LY_MQ, LY, "MQ", "Murzuq";
+    LY_NL, LY, "NL", "Nālūt";
+    LY_NQ, LY, "NQ", "An Nuqāţ al Khams";
+    LY_SB, LY, "SB", "Sabhā";
+    LY_SR, LY, "SR", "Surt";
+    LY_TB, LY, "TB", "Ţarābulus";
+    LY_WA, LY, "WA", "Al Wāḩāt";
+    LY_WD, LY, "WD", "Wādī al Ḩayāt";
+    LY_WS, LY, "WS", "Wādī ash Shāţi’";
+    LY_ZA, LY, "ZA", "Az Zāwiyah";
+    MA_01, MA, "01", "Tanger-Tétouan";
+    MA_02, MA, "02", "Gharb-Chrarda-Beni Hssen";
+    MA_03, MA, "03", "Taza-Al Hoceima-Taounate";
+    MA_04, MA, "04", "L'Oriental";
+    MA_05, MA, "05", "Fès-Boulemane";
+    MA_06, MA, "06", "Meknès-Tafilalet";
+    MA_07, MA, "07", "Rabat-Salé-Zemmour-Zaer";
+    MA_08, MA, "08", "Grand Casablanca";
+    MA_09, MA, "09", "Chaouia-Ouardigha";
+    MA_10, MA, "10", "Doukkala-Abda";
+    MA_11, MA, "11", "Marrakech-Tensift-Al Haouz";
+    MA_12, MA, "12", "Tadla-Azilal";
+    MA_13, MA, "13", "Sous-Massa-Draa";
+    MA_14, MA, "14", "Guelmim-Es Semara";
+    MA_15, MA, "15", "Laâyoune-Boujdour-Sakia el Hamra";
+    MA_16, MA, "16", "Oued ed Dahab-Lagouira";
+    MA_AGD, MA, "AGD", "Agadir-Ida-Outanane";
+    MA_AOU, MA, "AOU", "Aousserd";
+    MA_ASZ, MA, "ASZ", "Assa-Zag";
+    MA_AZI, MA, "AZI", "Azilal";
+    MA_BEM, MA, "BEM", "Beni Mellal";
+    MA_BER, MA, "BER", "Berkane";
+    MA_BES, MA, "BES", "Ben Slimane";
+    MA_BOD, MA, "BOD", "Boujdour";
+    MA_BOM, MA, "BOM", "Boulemane";
+    MA_CAS, MA, "CAS", "Casablanca";
+    MA_CHE, MA, "CHE", "Chefchaouen";
+    MA_CHI, MA, "CHI", "Chichaoua";
+    MA_CHT, MA, "CHT", "Chtouka-Ait Baha";
+    MA_ERR, MA, "ERR", "Errachidia";
+    MA_ESI, MA, "ESI", "Essaouira";
+    MA_ESM, MA, "ESM", "Es Smara";
+    MA_FAH, MA, "FAH", "Fahs-Beni Makada";
+    MA_FES, MA, "FES", "Fès-Dar-Dbibegh";
+    MA_FIG, MA, "FIG", "Figuig";
+    MA_GUE, MA, "GUE", "Guelmim";
+    MA_HAJ, MA, "HAJ", "El Hajeb";
+    MA_HAO, MA, "HAO", "Al Haouz";
+    MA_HOC, MA, "HOC", "Al Hoceïma";
+    MA_IFR, MA, "IFR", "Ifrane";
+    MA_INE, MA, "INE", "Inezgane-Ait Melloul";
+    MA_JDI, MA, "JDI", "El Jadida";
+    MA_JRA, MA, "JRA", "Jrada";
+    MA_KEN, MA, "KEN", "Kénitra";
+    MA_KES, MA, "KES", "Kelaat es Sraghna";
+    MA_KHE, MA, "KHE", "Khemisset";
+    MA_KHN, MA, "KHN", "Khenifra";
+    MA_KHO, MA, "KHO", "Khouribga";
+    MA_LAA, MA, "LAA", "Laâyoune";
+    MA_LAR, MA, "LAR", "Larache";
+    MA_MED, MA, "MED", "Médiouna";
+    MA_MEK, MA, "MEK", "Meknès";
+    MA_MMD, MA, "MMD", "Marrakech-Medina";
+    MA_MMN, MA, "MMN", "Marrakech-Menara";
+    MA_MOH, MA, "MOH", "Mohammadia";
+    MA_MOU, MA, "MOU", "Moulay Yacoub";
+    MA_NAD, MA, "NAD", "Nador";
+    MA_NOU, MA, "NOU", "Nouaceur";
+    MA_OUA, MA, "OUA", "Ouarzazate";
+    MA_OUD, MA, "OUD", "Oued ed Dahab";
+    MA_OUJ, MA, "OUJ", "Oujda-Angad";
+    MA_RAB, MA, "RAB", "Rabat";
+    MA_SAF, MA, "SAF", "Safi";
+    MA_SAL, MA, "SAL", "Salé";
+    MA_SEF, MA, "SEF", "Sefrou";
+    MA_SET, MA, "SET", "Settat";
+    MA_SIK, MA, "SIK", "Sidi Kacem";
+    MA_SKH, MA, "SKH", "Skhirate-Témara";
+    MA_SYB, MA, "SYB", "Sidi Youssef Ben Ali";
+    MA_TAI, MA, "TAI", "Taourirt";
+    MA_TAO, MA, "TAO", "Taounate";
+    MA_TAR, MA, "TAR", "Taroudant";
+    MA_TAT, MA, "TAT", "Tata";
+    MA_TAZ, MA, "TAZ", "Taza";
+    MA_TET, MA, "TET", "Tétouan";
+    MA_TIZ, MA, "TIZ", "Tiznit";
+    MA_TNG, MA, "TNG", "Tanger-Assilah";
+    MA_TNT, MA, "TNT", "Tan-Tan";
+    MA_ZAG, MA, "ZAG", "Zagora";
+    MC_CL, MC, "CL", "La Colle";
+    MC_CO, MC, "CO", "La Condamine";
+    MC_FO, MC, "FO", "Fontvieille";
+    MC_GA, MC, "GA", "La Gare";
+    MC_JE, MC, "JE", "Jardin Exotique";
+    MC_LA, MC, "LA", "Larvotto";
+    MC_MA, MC, "MA", "Malbousquet";
+    MC_MG, MC, "MG", "Moneghetti";
+    MC_MO, MC, "MO", "Monaco-Ville";
+    MC_MU, MC, "MU", "Moulins";
+    MC_PH, MC, "PH", "Port-Hercule";
+    MC_SD, MC, "SD", "Sainte-Dévote";
+    MC_SO, MC, "SO", "La Source";
+    MC_SP, MC, "SP", "Spélugues";
+    MC_SR, MC, "SR", "Saint-Roman";
+    MC_VR, MC, "VR", "Vallon de la Rousse";
+    MD_AN, MD, "AN", "Anenii Noi";
+    MD_BA, MD, "BA", "Bălţi";
+    MD_BD, MD, "BD", "Bender";
+    MD_BR, MD, "BR", "Briceni";
+    MD_BS, MD, "BS", "Basarabeasca";
+    MD_CA, MD, "CA", "Cahul";
+    MD_CL, MD, "CL", "Călăraşi";
+    MD_CM, MD, "CM", "Cimişlia";
+    MD_CR, MD, "CR", "Criuleni";
+    MD_CS, MD, "CS", "Căuşeni";
+    MD_CT, MD, "CT", "Cantemir";
+    MD_CU, MD, "CU", "Chişinău";
+    MD_DO, MD, "DO", "Donduşeni";
+    MD_DR, MD, "DR", "Drochia";
+    MD_DU, MD, "DU", "Dubăsari";
+    MD_ED, MD, "ED", "Edineţ";
+    MD_FA, MD, "FA", "Făleşti";
+    MD_FL, MD, "FL", "Floreşti";
+    MD_GA, MD, "GA", "Găgăuzia, Unitatea teritorială autonomă";
+    MD_GL, MD, "GL", "Glodeni";
+    MD_HI, MD, "HI", "Hînceşti";
+    MD_IA, MD, "IA", "Ialoveni";
+    MD_LE, MD, "LE", "Leova";
+    MD_NI, MD, "NI", "Nisporeni";
+    MD_OC, MD, "OC", "Ocniţa";
+    MD_OR, MD, "OR", "Orhei";
+    MD_RE, MD, "RE", "Rezina";
+    MD_RI, MD, "RI", "Rîşcani";
+    MD_SD, MD, "SD", "Şoldăneşti";
+    MD_SI, MD, "SI", "Sîngerei";
+    MD_SN, MD, "SN", "Stînga Nistrului, unitatea teritorială din";
+    MD_SO, MD, "SO", "Soroca";
+    MD_ST, MD, "ST", "Străşeni";
+    MD_SV, MD, "SV", "Ştefan Vodă";
+    MD_TA, MD, "TA", "Taraclia";
+    MD_TE, MD, "TE", "Teleneşti";
+    MD_UN, MD, "UN", "Ungheni";
+    ME_01, ME, "01", "Andrijevica";
+    ME_02, ME, "02", "Bar";
+    ME_03, ME, "03", "Berane";
+    ME_04, ME, "04", "Bijelo Polje";
+    ME_05, ME, "05", "Budva";
+    ME_06, ME, "06", "Cetinje";
+    ME_07, ME, "07", "Danilovgrad";
+    ME_08, ME, "08", "Herceg-Novi";
+    ME_09, ME, "09", "Kolašin";
+    ME_10, ME, "10", "Kotor";
+    ME_11, ME, "11", "Mojkovac";
+    ME_12, ME, "12", "Nikšić";
+    ME_13, ME, "13", "Plav";
+    ME_14, ME, "14", "Pljevlja";
+    ME_15, ME, "15", "Plužine";
+    ME_16, ME, "16", "Podgorica";
+    ME_17, ME, "17", "Rožaje";
+    ME_18, ME, "18", "Šavnik";
+    ME_19, ME, "19", "Tivat";
+    ME_20, ME, "20", "Ulcinj";
+    ME_21, ME, "21", "Žabljak";
+    ME_22, ME, "22", "Gusinje";
+    ME_23, ME, "23", "Petnjica";
+    MG_A, MG, "A", "Toamasina";
+    MG_D, MG, "D", "Antsiranana";
+    MG_F, MG, "F", "Fianarantsoa";
+    MG_M, MG, "M", "Mahajanga";
+    MG_T, MG, "T", "Antananarivo";
+    MG_U, MG, "U", "Toliara";
+    MH_ALK, MH, "ALK", "Ailuk";
+    MH_ALL, MH, "ALL", "Ailinglaplap";
+    MH_ARN, MH, "ARN", "Arno";
+    MH_AUR, MH, "AUR", "Aur";
+    MH_EBO, MH, "EBO", "Ebon";
+    MH_ENI, MH, "ENI", "Enewetak and Ujelang";
+    MH_JAB, MH, "JAB", "Jabat";
+    MH_JAL, MH, "JAL", "Jaluit";
+    MH_KIL, MH, "KIL", "Bikini and Kili";
+    MH_KWA, MH, "KWA", "Kwajalein";
+    MH_L, MH, "L", "Ralik chain";
+    MH_LAE, MH, "LAE", "Lae";
+    MH_LIB, MH, "LIB", "Lib";
+    MH_LIK, MH, "LIK", "Likiep";
+    MH_MAJ, MH, "MAJ", "Majuro";
+    MH_MAL, MH, "MAL", "Maloelap";
+    MH_MEJ, MH, "MEJ", "Mejit";
+    MH_MIL, MH, "MIL", "Mili";
+    MH_NMK, MH, "NMK", "Namdrik";
+    MH_NMU, MH, "NMU", "Namu";
+    MH_RON, MH, "RON", "Rongelap";
+    MH_T, MH, "T", "Ratak chain";
+    MH_UJA, MH, "UJA", "Ujae";
+    MH_UTI, MH, "UTI", "Utrik";
+    MH_WTH, MH, "WTH", "Wotho";
+    MH_WTJ, MH, "WTJ", "Wotje";
+    MK_01, MK, "01", "Aerodrom †";
+    MK_02, MK, "02", "Aračinovo";
+    MK_03, MK, "03", "Berovo";
+    MK_04, MK, "04", "Bitola";
+    MK_05, MK, "05", "Bogdanci";
+    MK_06, MK, "06", "Bogovinje";
+    MK_07, MK, "07", "Bosilovo";
+    MK_08, MK, "08", "Brvenica";
+    MK_09, MK, "09", "Butel †";
+    MK_10, MK, "10", "Valandovo";
+    MK_11, MK, "11", "Vasilevo";
+    MK_12, MK, "12", "Vevčani";
+    MK_13, MK, "13", "Veles";
+    MK_14, MK, "14", "Vinica";
+    MK_15, MK, "15", "Vraneštica";
+    MK_16, MK, "16", "Vrapčište";
+    MK_17, MK, "17", "Gazi Baba †";
+    MK_18, MK, "18", "Gevgelija";
+    MK_19, MK, "19", "Gostivar";
+    MK_20, MK, "20", "Gradsko";
+    MK_21, MK, "21", "Debar";
+    MK_22, MK, "22", "Debarca";
+    MK_23, MK, "23", "Delčevo";
+    MK_24, MK, "24", "Demir Kapija";
+    MK_25, MK, "25", "Demir Hisar";
+    MK_26, MK, "26", "Dojran";
+    MK_27, MK, "27", "Dolneni";
+    MK_28, MK, "28", "Drugovo";
+    MK_29, MK, "29", "Gjorče Petrov †";
+    MK_30, MK, "30", "Želino";
+    MK_31, MK, "31", "Zajas";
+    MK_32, MK, "32", "Zelenikovo";
+    MK_33, MK, "33", "Zrnovci";
+    MK_34, MK, "34", "Ilinden";
+    MK_35, MK, "35", "Jegunovce";
+    MK_36, MK, "36", "Kavadarci";
+    MK_37, MK, "37", "Karbinci";
+    MK_38, MK, "38", "Karpoš †";
+    MK_39, MK, "39", "Kisela Voda †";
+    MK_40, MK, "40", "Kičevo";
+    MK_41, MK, "41", "Konče";
+    MK_42, MK, "42", "Kočani";
+    MK_43, MK, "43", "Kratovo";
+    MK_44, MK, "44", "Kriva Palanka";
+    MK_45, MK, "45", "Krivogaštani";
+    MK_46, MK, "46", "Kruševo";
+    MK_47, MK, "47", "Kumanovo";
+    MK_48, MK, "48", "Lipkovo";
+    MK_49, MK, "49", "Lozovo";
+    MK_50, MK, "50", "Mavrovo i Rostuša";
+    MK_51, MK, "51", "Makedonska Kamenica";
+    MK_52, MK, "52", "Makedonski Brod";
+    MK_53, MK, "53", "Mogila";
+    MK_54, MK, "54", "Negotino";
+    MK_55, MK, "55", "Novaci";
+    MK_56, MK, "56", "Novo Selo";
+    MK_57, MK, "57", "Oslomej";
+    MK_58, MK, "58", "Ohrid";
+    MK_59, MK, "59", "Petrovec";
+    MK_60, MK, "60", "Pehčevo";
+    MK_61, MK, "61", "Plasnica";
+    MK_62, MK, "62", "Prilep";
+    MK_63, MK, "63", "Probištip";
+    MK_64, MK, "64", "Radoviš";
+    MK_65, MK, "65", "Rankovce";
+    MK_66, MK, "66", "Resen";
+    MK_67, MK, "67", "Rosoman";
+    MK_68, MK, "68", "Saraj †";
+    MK_69, MK, "69", "Sveti Nikole";
+    MK_70, MK, "70", "Sopište";
+    MK_71, MK, "71", "Staro Nagoričane";
+    MK_72, MK, "72", "Struga";
+    MK_73, MK, "73", "Strumica";
+    MK_74, MK, "74", "Studeničani";
+    MK_75, MK, "75", "Tearce";
+    MK_76, MK, "76", "Tetovo";
+    MK_77, MK, "77", "Centar †";
+    MK_78, MK, "78", "Centar Župa";
+    MK_79, MK, "79", "Čair †";
+    MK_80, MK, "80", "Čaška";
+    MK_81, MK, "81", "Češinovo-Obleševo";
+    MK_82, MK, "82", "Čučer Sandevo";
+    MK_83, MK, "83", "Štip";
+    MK_84, MK, "84", "Šuto Orizari †";
+    ML_1, ML, "1", "Kayes";
+    ML_2, ML, "2", "Koulikoro";
+    ML_3, ML, "3", "Sikasso";
+    ML_4, ML, "4", "Ségou";
+    ML_5, ML, "5", "Mopti";
+    ML_6, ML, "6", "Tombouctou";
+    ML_7, ML, "7", "Gao";
+    ML_8, ML, "8", "Kidal";
+    ML_BKO, ML, "BKO", "Bamako";
+    MM_01, MM, "01", "Sagaing";
+    MM_02, MM, "02", "Bago";
+    MM_03, MM, "03", "Magway";
+    MM_04, MM, "04", "Mandalay";
+    MM_05, MM, "05", "Taninthayi";
+    MM_06, MM, "06", "Yangon";
+    MM_07, MM, "07", "Ayeyawady";
+    MM_11, MM, "11", "Kachin";
+    MM_12, MM, "12", "Kayah";
+    MM_13, MM, "13", "Kayin";
+    MM_14, MM, "14", "Chin";
+    MM_15, MM, "15", "Mon";
+    MM_16, MM, "16", "Rakhine";
+    MM_17, MM, "17", "Shan";
+    MM_18, MM, "18", "Nay Pyi Taw";
+    MN_035, MN, "035", "Orhon";
+    MN_037, MN, "037", "Darhan uul";
+    MN_039, MN, "039", "Hentiy";
+    MN_041, MN, "041", "Hövsgöl";
+    MN_043, MN, "043", "Hovd";
+    MN_046, MN, "046", "Uvs";
+    MN_047, MN, "047", "Töv";
+    MN_049, MN, "049", "Selenge";
+    MN_051, MN, "051", "Sühbaatar";
+    MN_053, MN, "053", "Ömnögovĭ";
+    MN_055, MN, "055", "Övörhangay";
+    MN_057, MN, "057", "Dzavhan";
+    MN_059, MN, "059", "Dundgovĭ";
+    MN_061, MN, "061", "Dornod";
+    MN_063, MN, "063", "Dornogovĭ";
+    MN_064, MN, "064", "Govĭ-Sümber";
+    MN_065, MN, "065", "Govĭ-Altay";
+    MN_067, MN, "067", "Bulgan";
+    MN_069, MN, "069", "Bayanhongor";
+    MN_071, MN, "071", "Bayan-Ölgiy";
+    MN_073, MN, "073", "Arhangay";
+    MN_1, MN, "1", "Ulaanbaatar";
+    MR_01, MR, "01", "Hodh ech Chargui";
+    MR_02, MR, "02", "Hodh el Gharbi";
+    MR_03, MR, "03", "Assaba";
+    MR_04, MR, "04", "Gorgol";
+    MR_05, MR, "05", "Brakna";
+    MR_06, MR, "06", "Trarza";
+    MR_07, MR, "07", "Adrar";
+    MR_08, MR, "08", "Dakhlet Nouâdhibou";
+    MR_09, MR, "09", "Tagant";
+    MR_10, MR, "10", "Guidimaka";
+    MR_11, MR, "11", "Tiris Zemmour";
+    MR_12, MR, "12", "Inchiri";
+    MR_NKC, MR, "NKC", "Nouakchott";
+    MT_01, MT, "01", "Attard";
+    MT_02, MT, "02", "Balzan";
+    MT_03, MT, "03", "Birgu";
+    MT_04, MT, "04", "Birkirkara";
+    MT_05, MT, "05", "Birżebbuġa";
+    MT_06, MT, "06", "Bormla";
+    MT_07, MT, "07", "Dingli";
+    MT_08, MT, "08", "Fgura";
+    MT_09, MT, "09", "Floriana";
+    MT_10, MT, "10", "Fontana";
+    MT_11, MT, "11", "Gudja";
+    MT_12, MT, "12", "Gżira";
+    MT_13, MT, "13", "Għajnsielem";
+    MT_14, MT, "14", "Għarb";
+    MT_15, MT, "15", "Għargħur";
+    MT_16, MT, "16", "Għasri";
+    MT_17, MT, "17", "Għaxaq";
+    MT_18, MT, "18", "Ħamrun";
+    MT_19, MT, "19", "Iklin";
+    MT_20, MT, "20", "Isla";
+    MT_21, MT, "21", "Kalkara";
+    MT_22, MT, "22", "Kerċem";
+    MT_23, MT, "23", "Kirkop";
+    MT_24, MT, "24", "Lija";
+    MT_25, MT, "25", "Luqa";
+    MT_26, MT, "26", "Marsa";
+    MT_27, MT, "27", "Marsaskala";
+    MT_28, MT, "28", "Marsaxlokk";
+    MT_29, MT, "29", "Mdina";
+    MT_30, MT, "30", "Mellieħa";
+    MT_31, MT, "31", "Mġarr";
+    MT_32, MT, "32", "Mosta";
+    MT_33, MT, "33", "Mqabba";
+    MT_34, MT, "34", "Msida";
+    MT_35, MT, "35", "Mtarfa";
+    MT_36, MT, "36", "Munxar";
+    MT_37, MT, "37", "Nadur";
+    MT_38, MT, "38", "Naxxar";
+    MT_39, MT, "39", "Paola";
+    MT_40, MT, "40", "Pembroke";
+    MT_41, MT, "41", "Pietà";
+    MT_42, MT, "42", "Qala";
+    MT_43, MT, "43", "Qormi";
+    MT_44, MT, "44", "Qrendi";
+    MT_45, MT, "45", "Rabat Gozo";
+    MT_46, MT, "46", "Rabat Malta";
+    MT_47, MT, "47", "Safi";
+    MT_48, MT, "48", "Saint Julian’s";
+    MT_49, MT, "49", "Saint John";
+    MT_50, MT, "50", "Saint Lawrence";
+    MT_51, MT, "51", "Saint Paul’s Bay";
+    MT_52, MT, "52", "Sannat";
+    MT_53, MT, "53", "Saint Lucia’s";
+    MT_54, MT, "54", "Santa Venera";
+    MT_55, MT, "55", "Siġġiewi";
+    MT_56, MT, "56", "Sliema";
+    MT_57, MT, "57", "Swieqi";
+    MT_58, MT, "58", "Ta’ Xbiex";
+    MT_59, MT, "59", "Tarxien";
+    MT_60, MT, "60", "Valletta";
+    MT_61, MT, "61", "Xagħra";
+    MT_62, MT, "62", "Xewkija";
+    MT_63, MT, "63", "Xgħajra";
+    MT_64, MT, "64", "Żabbar";
+    MT_65, MT, "65", "Żebbuġ Gozo";
+    MT_66, MT, "66", "Żebbuġ Malta";
+    MT_67, MT, "67", "Żejtun";
+    MT_68, MT, "68", "Żurrieq";
+    MU_AG, MU, "AG", "Agalega Islands";
+    MU_BL, MU, "BL", "Black River";
+    MU_BR, MU, "BR", "Beau Bassin-Rose Hill";
+    MU_CC, MU, "CC", "Cargados Carajos Shoals";
+    MU_CU, MU, "CU", "Curepipe";
+    MU_FL, MU, "FL", "Flacq";
+    MU_GP, MU, "GP", "Grand Port";
+    MU_MO, MU, "MO", "Moka";
+    MU_PA, MU, "PA", "Pamplemousses";
+    MU_PL, MU, "PL", "Port Louis";
+    MU_PU, MU, "PU", "Port Louis";
+    MU_PW, MU, "PW", "Plaines Wilhems";
+    MU_QB, MU, "QB", "Quatre Bornes";
+    MU_RO, MU, "RO", "Rodrigues Island";
+    MU_RR, MU, "RR", "Rivière du Rempart";
+    MU_SA, MU, "SA", "Savanne";
+    MU_VP, MU, "VP", "Vacoas-Phoenix";
+    MV_00, MV, "00", "Alifu Dhaalu";
+    MV_01, MV, "01", "Addu Atholhu";
+    MV_02, MV, "02", "Alifu Alifu";
+    MV_03, MV, "03", "Faadhippolhu";
+    MV_04, MV, "04", "Felidhu Atholhu";
+    MV_05, MV, "05", "Hadhdhunmathi";
+    MV_07, MV, "07", "Haa Alifu";
+    MV_08, MV, "08", "Kolhumadulu";
+    MV_12, MV, "12", "Meemu";
+    MV_13, MV, "13", "Maalhosmadulu Uthuruburi";
+    MV_14, MV, "14", "Faafu";
+    MV_17, MV, "17", "Dhaalu";
+    MV_20, MV, "20", "Baa";
+    MV_23, MV, "23", "Haa Dhaalu";
+    MV_24, MV, "24", "Miladhunmadulu Uthuruburi";
+    MV_25, MV, "25", "Miladhunmadulu Dhekunuburi";
+    MV_26, MV, "26", "Kaafu";
+    MV_27, MV, "27", "Gaafu Alifu";
+    MV_28, MV, "28", "Gaafu Dhaalu";
+    MV_29, MV, "29", "Fuvammulah";
+    MV_CE, MV, "CE", "Central";
+    MV_MLE, MV, "MLE", "Maale";
+    MV_NC, MV, "NC", "Medhu-Uthuru";
+    MV_NO, MV, "NO", "North";
+    MV_SC, MV, "SC", "Medhu-Dhekunu";
+    MV_SU, MV, "SU", "Dhekunu";
+    MV_UN, MV, "UN", "Mathi-Uthuru";
+    MV_US, MV, "US", "Mathi-Dhekunu";
+    MW_BA, MW, "BA", "Balaka";
+    MW_BL, MW, "BL", "Blantyre";
+    MW_C, MW, "C", "Central Region";
+    MW_CK, MW, "CK", "Chikwawa";
+    MW_CR, MW, "CR", "Chiradzulu";
+    MW_CT, MW, "CT", "Chitipa";
+    MW_DE, MW, "DE", "Dedza";
+    MW_DO, MW, "DO", "Dowa";
+    MW_KR, MW, "KR", "Karonga";
+    MW_KS, MW, "KS", "Kasungu";
+    MW_LI, MW, "LI", "Lilongwe";
+    MW_LK, MW, "LK", "Likoma";
+    MW_MC, MW, "MC", "Mchinji";
+    MW_MG, MW, "MG", "Mangochi";
+    MW_MH, MW, "MH", "Machinga";
+    MW_MU, MW, "MU", "Mulanje";
+    MW_MZ, MW, "MZ", "Mzimba";
+    MW_N, MW, "N", "Chakumpoto";
+    MW_NB, MW, "NB", "Nkhata Bay";
+    MW_NE, MW, "NE", "Neno";
+    MW_NI, MW, "NI", "Ntchisi";
+    MW_NK, MW, "NK", "Nkhotakota";
+    MW_NS, MW, "NS", "Nsanje";
+    MW_NU, MW, "NU", "Ntcheu";
+    MW_PH, MW, "PH", "Phalombe";
+    MW_RU, MW, "RU", "Rumphi";
+    MW_S, MW, "S", "Chakumwera";
+    MW_SA, MW, "SA", "Salima";
+    MW_TH, MW, "TH", "Thyolo";
+    MW_ZO, MW, "ZO", "Zomba";
+    MX_AGU, MX, "AGU", "Aguascalientes";
+    MX_BCN, MX, "BCN", "Baja California";
+    MX_BCS, MX, "BCS", "Baja California Sur";
+    MX_CAM, MX, "CAM", "Campeche";
+    MX_CHH, MX, "CHH", "Chihuahua";
+    MX_CHP, MX, "CHP", "Chiapas";
+    MX_CMX, MX, "CMX", "Ciudad de México";
+    MX_COA, MX, "COA", "Coahuila de Zaragoza";
+    MX_COL, MX, "COL", "Colima";
+    MX_DUR, MX, "DUR", "Durango";
+    MX_GRO, MX, "GRO", "Guerrero";
+    MX_GUA, MX, "GUA", "Guanajuato";
+    MX_HID, MX, "HID", "Hidalgo";
+    MX_JAL, MX, "JAL", "Jalisco";
+    MX_MEX, MX, "MEX", "México";
+    MX_MIC, MX, "MIC", "Michoacán de Ocampo";
+    MX_MOR, MX, "MOR", "Morelos";
+    MX_NAY, MX, "NAY", "Nayarit";
+    MX_NLE, MX, "NLE", "Nuevo León";
+    MX_OAX, MX, "OAX", "Oaxaca";
+    MX_PUE, MX, "PUE", "Puebla";
+    MX_QUE, MX, "QUE", "Querétaro";
+    MX_ROO, MX, "ROO", "Quintana Roo";
+    MX_SIN, MX, "SIN", "Sinaloa";
+    MX_SLP, MX, "SLP", "San Luis Potosí";
+    MX_SON, MX, "SON", "Sonora";
+    MX_TAB, MX, "TAB", "Tabasco";
+    MX_TAM, MX, "TAM", "Tamaulipas";
+    MX_TLA, MX, "TLA", "Tlaxcala";
+    MX_VER, MX, "VER", "Veracruz de Ignacio de la Llave";
+    MX_YUC, MX, "YUC", "Yucatán";
+    MX_ZAC, MX, "ZAC", "Zacatecas";
+    MY_01, MY, "01", "Johor";
+    MY_02, MY, "02", "Kedah";
+    MY_03, MY, "03", "Kelantan";
+    MY_04, MY, "04", "Melaka";
+    MY_05, MY, "05", "Negeri Sembilan";
+    MY_06, MY, "06", "Pahang";
+    MY_07, MY, "07", "Pulau Pinang";
+    MY_08, MY, "08", "Perak";
+    MY_09, MY, "09", "Perlis";
+    MY_10, MY, "10", "Selangor";
+    MY_11, MY, "11", "Terengganu";
+    MY_12, MY, "12", "Sabah";
+    MY_13, MY, "13", "Sarawak";
+    MY_14, MY, "14", "Wilayah Persekutuan Kuala Lumpur";
+    MY_15, MY, "15", "Wilayah Persekutuan Labuan";
+    MY_16, MY, "16", "Wilayah Persekutuan Putrajaya";
+    MZ_A, MZ, "A", "Niassa";
+    MZ_B, MZ, "B", "Manica";
+    MZ_G, MZ, "G", "Gaza";
+    MZ_I, MZ, "I", "Inhambane";
+    MZ_L, MZ, "L", "Maputo";
+    MZ_MPM, MZ, "MPM", "Maputo";
+    MZ_N, MZ, "N", "Nampula";
+    MZ_P, MZ, "P", "Cabo Delgado";
+    MZ_Q, MZ, "Q", "Zambézia";
+    MZ_S, MZ, "S", "Sofala";
+    MZ_T, MZ, "T", "Tete";
+    NA_CA, NA, "CA", "Zambezi";
+    NA_ER, NA, "ER", "Erongo";
+    NA_HA, NA, "HA", "Hardap";
+    NA_KA, NA, "KA", "Karas";
+    NA_KE, NA, "KE", "Kavango East";
+    NA_KH, NA, "KH", "Khomas";
+    NA_KU, NA, "KU", "Kunene";
+    NA_KW, NA, "KW", "Kavango West";
+    NA_OD, NA, "OD", "Otjozondjupa";
+    NA_OH, NA, "OH", "Omaheke";
+    NA_ON, NA, "ON", "Oshana";
+    NA_OS, NA, "OS", "Omusati";
+    NA_OT, NA, "OT", "Oshikoto";
+    NA_OW, NA, "OW", "Ohangwena";
+    NE_1, NE, "1", "Agadez";
+    NE_2, NE, "2", "Diffa";
+    NE_3, NE, "3", "Dosso";
+    NE_4, NE, "4", "Maradi";
+    NE_5, NE, "5", "Tahoua";
+    NE_6, NE, "6", "Tillabéri";
+    NE_7, NE, "7", "Zinder";
+    NE_8, NE, "8", "Niamey";
+    NG_AB, NG, "AB", "Abia";
+    NG_AD, NG, "AD", "Adamawa";
+    NG_AK, NG, "AK", "Akwa Ibom";
+    NG_AN, NG, "AN", "Anambra";
+    NG_BA, NG, "BA", "Bauchi";
+    NG_BE, NG, "BE", "Benue";
+    NG_BO, NG, "BO", "Borno";
+    NG_BY, NG, "BY", "Bayelsa";
+    NG_CR, NG, "CR", "Cross River";
+    NG_DE, NG, "DE", "Delta";
+    NG_EB, NG, "EB", "Ebonyi";
+    NG_ED, NG, "ED", "Edo";
+    NG_EK, NG, "EK", "Ekiti";
+    NG_EN, NG, "EN", "Enugu";
+    NG_FC, NG, "FC", "Abuja Federal Capital Territory";
+    NG_GO, NG, "GO", "Gombe";
+    NG_IM, NG, "IM", "Imo";
+    NG_JI, NG, "JI", "Jigawa";
+    NG_KD, NG, "KD", "Kaduna";
+    NG_KE, NG, "KE", "Kebbi";
+    NG_KN, NG, "KN", "Kano";
+    NG_KO, NG, "KO", "Kogi";
+    NG_KT, NG, "KT", "Katsina";
+    NG_KW, NG, "KW", "Kwara";
+    NG_LA, NG, "LA", "Lagos";
+    NG_NA, NG, "NA", "Nasarawa";
+    NG_NI, NG, "NI", "Niger";
+    NG_OG, NG, "OG", "Ogun";
+    NG_ON, NG, "ON", "Ondo";
+    NG_OS, NG, "OS", "Osun";
+    NG_OY, NG, "OY", "Oyo";
+    NG_PL, NG, "PL", "Plateau";
+    NG_RI, NG, "RI", "Rivers";
+    NG_SO, NG, "SO", "Sokoto";
+    NG_TA, NG, "TA", "Taraba";
+    NG_YO, NG, "YO", "Yobe";
+    NG_ZA, NG, "ZA", "Zamfara";
+    NI_AN, NI, "AN", "Atlántico Norte";
+    NI_AS, NI, "AS", "Atlántico Sur";
+    NI_BO, NI, "BO", "Boaco";
+    NI_CA, NI, "CA", "Carazo";
+    NI_CI, NI, "CI", "Chinandega";
+    NI_CO, NI, "CO", "Chontales";
+    NI_ES, NI, "ES", "Estelí";
+    NI_GR, NI, "GR", "Granada";
+    NI_JI, NI, "JI", "Jinotega";
+    NI_LE, NI, "LE", "León";
+    NI_MD, NI, "MD", "Madriz";
+    NI_MN, NI, "MN", "Managua";
+    NI_MS, NI, "MS", "Masaya";
+    NI_MT, NI, "MT", "Matagalpa";
+    NI_NS, NI, "NS", "Nueva Segovia";
+    NI_RI, NI, "RI", "Rivas";
+    NI_SJ, NI, "SJ", "Río San Juan";
+    NL_AW, NL, "AW", "Aruba";
+    NL_BQ1, NL, "BQ1", "Bonaire";
+    NL_BQ2, NL, "BQ2", "Saba";
+    NL_BQ3, NL, "BQ3", "Sint Eustatius";
+    NL_CW, NL, "CW", "Curaçao";
+    NL_DR, NL, "DR", "Drenthe";
+    NL_FL, NL, "FL", "Flevoland";
+    NL_FR, NL, "FR", "Fryslân";
+    NL_GE, NL, "GE", "Gelderland";
+    NL_GR, NL, "GR", "Groningen";
+    NL_LI, NL, "LI", "Limburg";
+    NL_NB, NL, "NB", "Noord-Brabant";
+    NL_NH, NL, "NH", "Noord-Holland";
+    NL_OV, NL, "OV", "Overijssel";
+    NL_SX, NL, "SX", "Sint Maarten";
+    NL_UT, NL, "UT", "Utrecht";
+    NL_ZE, NL, "ZE", "Zeeland";
+    NL_ZH, NL, "ZH", "Zuid-Holland";
+    NO_01, NO, "01", "Østfold";
+    NO_02, NO, "02", "Akershus";
+    NO_03, NO, "03", "Oslo";
+    NO_04, NO, "04", "Hedmark";
+    NO_05, NO, "05", "Oppland";
+    NO_06, NO, "06", "Buskerud";
+    NO_07, NO, "07", "Vestfold";
+    NO_08, NO, "08", "Telemark";
+    NO_09, NO, "09", "Aust-Agder";
+    NO_10, NO, "10", "Vest-Agder";
+    NO_11, NO, "11", "Rogaland";
+    NO_12, NO, "12", "Hordaland";
+    NO_14, NO, "14", "Sogn og Fjordane";
+    NO_15, NO, "15", "Møre og Romsdal";
+    NO_16, NO, "16", "Sør-Trøndelag";
+    NO_17, NO, "17", "Nord-Trøndelag";
+    NO_18, NO, "18", "Nordland";
+    NO_19, NO, "19", "Romsa";
+    NO_20, NO, "20", "Finnmark";
+    NO_21, NO, "21", "Svalbard";
+    NO_22, NO, "22", "Jan Mayen";
+    NP_1, NP, "1", "Central";
+    NP_2, NP, "2", "Madhya Pashchimanchal";
+    NP_3, NP, "3", "Pashchimanchal";
+    NP_4, NP, "4", "Eastern";
+    NP_5, NP, "5", "Far Western";
+    NP_BA, NP, "BA", "Bagmati";
+    NP_BH, NP, "BH", "Bheri";
+    NP_DH, NP, "DH", "Dhawalagiri";
+    NP_GA, NP, "GA", "Gandaki";
+    NP_JA, NP, "JA", "Janakpur";
+    NP_KA, NP, "KA", "Karnali";
+    NP_KO, NP, "KO", "Kosi";
+    NP_LU, NP, "LU", "Lumbini";
+    NP_MA, NP, "MA", "Mahakali";
+    NP_ME, NP, "ME", "Mechi";
+    NP_NA, NP, "NA", "Narayani";
+    NP_RA, NP, "RA", "Rapti";
+    NP_SA, NP, "SA", "Sagarmatha";
+    NP_SE, NP, "SE", "Seti";
+    NR_01, NR, "01", "Aiwo";
+    NR_02, NR, "02", "Anabar";
+    NR_03, NR, "03", "Anetan";
+    NR_04, NR, "04", "Anibare";
+    NR_05, NR, "05", "Baiti";
+    NR_06, NR, "06", "Boe";
+    NR_07, NR, "07", "Buada";
+    NR_08, NR, "08", "Denigomodu";
+    NR_09, NR, "09", "Ewa";
+    NR_10, NR, "10", "Ijuw";
+    NR_11, NR, "11", "Meneng";
+    NR_12, NR, "12", "Nibok";
+    NR_13, NR, "13", "Uaboe";
+    NR_14, NR, "14", "Yaren";
+    NZ_AUK, NZ, "AUK", "Auckland";
+    NZ_BOP, NZ, "BOP", "Bay of Plenty";
+    NZ_CAN, NZ, "CAN", "Canterbury";
+    NZ_CIT, NZ, "CIT", "Chatham Islands Territory";
+    NZ_GIS, NZ, "GIS", "Gisborne District";
+    NZ_HKB, NZ, "HKB", "Hawke's Bay";
+    NZ_MBH, NZ, "MBH", "Marlborough District";
+    NZ_MWT, NZ, "MWT", "Manawatu-Wanganui";
+    NZ_N, NZ, "N", "North Island";
+    NZ_NSN, NZ, "NSN", "Nelson City";
+    NZ_NTL, NZ, "NTL", "Northland";
+    NZ_OTA, NZ, "OTA", "Otago";
+    NZ_S, NZ, "S", "South Island";
+    NZ_STL, NZ, "STL", "Southland";
+    NZ_TAS, NZ, "TAS", "Tasman District";
+    NZ_TKI, NZ, "TKI", "Taranaki";
+    NZ_WGN, NZ, "WGN", "Wellington";
+    NZ_WKO, NZ, "WKO", "Waikato";
+    NZ_WTC, NZ, "WTC", "West Coast";
+    OM_BA, OM, "BA", "Al Bāţinah";
+    OM_BU, OM, "BU", "Al Buraymī";
+    OM_DA, OM, "DA", "Ad Dākhilīyah";
+    OM_MA, OM, "MA", "Masqaţ";
+    OM_MU, OM, "MU", "Musandam";
+    OM_SH, OM, "SH", "Ash Sharqīyah";
+    OM_WU, OM, "WU", "Al Wusţá";
+    OM_ZA, OM, "ZA", "Az̧ Z̧āhirah";
+    OM_ZU, OM, "ZU", "Z̧ufār";
+    PA_1, PA, "1", "Bocas del Toro";
+    PA_10, PA, "10", "Panamá Oeste";
+    PA_2, PA, "2", "Coclé";
+    PA_3, PA, "3", "Colón";
+    PA_4, PA, "4", "Chiriquí";
+    PA_5, PA, "5", "Darién";
+    PA_6, PA, "6", "Herrera";
+    PA_7, PA, "7", "Los Santos";
+    PA_8, PA, "8", "Panamá";
+    PA_9, PA, "9", "Veraguas";
+    PA_EM, PA, "EM", "Emberá";
+    PA_KY, PA, "KY", "Kuna Yala";
+    PA_NB, PA, "NB", "Ngöbe-Buglé";
+    PE_AMA, PE, "AMA", "Amarumayu";
+    PE_ANC, PE, "ANC", "Ancash";
+    PE_APU, PE, "APU", "Apurímac";
+    PE_ARE, PE, "ARE", "Arequipa";
+    PE_AYA, PE, "AYA", "Ayacucho";
+    PE_CAJ, PE, "CAJ", "Cajamarca";
+    PE_CAL, PE, "CAL", "El Callao";
+    PE_CUS, PE, "CUS", "Cusco";
+    PE_HUC, PE, "HUC", "Huánuco";
+    PE_HUV, PE, "HUV", "Huancavelica";
+    PE_ICA, PE, "ICA", "Ica";
+    PE_JUN, PE, "JUN", "Hunin";
+    PE_LAL, PE, "LAL", "La Libertad";
+    PE_LAM, PE, "LAM", "Lambayeque";
+    PE_LIM, PE, "LIM", "Lima";
+    PE_LMA, PE, "LMA", "Lima hatun llaqta";
+    PE_LOR, PE, "LOR", "Loreto";
+    PE_MDD, PE, "MDD", "Madre de Dios";
+    PE_MOQ, PE, "MOQ", "Moquegua";
+    PE_PAS, PE, "PAS", "Pasco";
+    PE_PIU, PE, "PIU", "Piura";
+    PE_PUN, PE, "PUN", "Puno";
+    PE_SAM, PE, "SAM", "San Martin";
+    PE_TAC, PE, "TAC", "Tacna";
+    PE_TUM, PE, "TUM", "Tumbes";
+    PE_UCA, PE, "UCA", "Ucayali";
+    PG_CPK, PG, "CPK", "Chimbu";
+    PG_CPM, PG, "CPM", "Central";
+    PG_EBR, PG, "EBR", "East New Britain";
+    PG_EHG, PG, "EHG", "Eastern Highlands";
+    PG_EPW, PG, "EPW", "Enga";
+    PG_ESW, PG, "ESW", "East Sepik";
+    PG_GPK, PG, "GPK", "Gulf";
+    PG_HLA, PG, "HLA", "Hela";
+    PG_JWK, PG, "JWK", "Jiwaka";
+    PG_MBA, PG, "MBA", "Milne Bay";
+    PG_MPL, PG, "MPL", "Morobe";
+    PG_MPM, PG, "MPM", "Madang";
+    PG_MRL, PG, "MRL", "Manus";
+    PG_NCD, PG, "NCD", "National Capital District";
+    PG_NIK, PG, "NIK", "New Ireland";
+    PG_NPP, PG, "NPP", "Northern";
+    PG_NSB, PG, "NSB", "Bougainville";
+    PG_SAN, PG, "SAN", "West Sepik";
+    PG_SHM, PG, "SHM", "Southern Highlands";
+    PG_WBK, PG, "WBK", "West New Britain";
+    PG_WHM, PG, "WHM", "Western Highlands";
+    PG_WPD, PG, "WPD", "Western";
+    PH_00, PH, "00", "National Capital Region";
+    PH_01, PH, "01", "Ilocos";
+    PH_02, PH, "02", "Cagayan Valley";
+    PH_03, PH, "03", "Central Luzon";
+    PH_05, PH, "05", "Bicol";
+    PH_06, PH, "06", "Rehiyon ng Kanlurang Bisaya";
+    PH_07, PH, "07", "Central Visayas";
+    PH_08, PH, "08", "Eastern Visayas";
+    PH_09, PH, "09", "Rehiyon ng Tangway ng Sambuwangga";
+    PH_10, PH, "10", "Northern Mindanao";
+    PH_11, PH, "11", "Davao";
+    PH_12, PH, "12", "Rehiyon ng Soccsksargen";
+    PH_13, PH, "13", "Caraga";
+    PH_14, PH, "14", "Autonomous Region in Muslim Mindanao";
+    PH_15, PH, "15", "Cordillera Administrative Region";
+    PH_40, PH, "40", "Calabarzon";
+    PH_41, PH, "41", "Mimaropa";
+    PH_ABR, PH, "ABR", "Abra";
+    PH_AGN, PH, "AGN", "Agusan del Norte";
+    PH_AGS, PH, "AGS", "Agusan del Sur";
+    PH_AKL, PH, "AKL", "Aklan";
+    PH_ALB, PH, "ALB", "Albay";
+    PH_ANT, PH, "ANT", "Antike";
+    PH_APA, PH, "APA", "Apayao";
+    PH_AUR, PH, "AUR", "Aurora";
+    PH_BAN, PH, "BAN", "Bataan";
+    PH_BAS, PH, "BAS", "Basilan";
+    PH_BEN, PH, "BEN", "Benget";
+    PH_BIL, PH, "BIL", "Biliran";
+    PH_BOH, PH, "BOH", "Bohol";
+    PH_BTG, PH, "BTG", "Batangas";
+    PH_BTN, PH, "BTN", "Batanes";
+    PH_BUK, PH, "BUK", "Bukidnon";
+    PH_BUL, PH, "BUL", "Bulacan";
+    PH_CAG, PH, "CAG", "Cagayan";
+    PH_CAM, PH, "CAM", "Camiguin";
+    PH_CAN, PH, "CAN", "Camarines Norte";
+    PH_CAP, PH, "CAP", "Capiz";
+    PH_CAS, PH, "CAS", "Camarines Sur";
+    PH_CAT, PH, "CAT", "Catanduanes";
+    PH_CAV, PH, "CAV", "Cavite";
+    PH_CEB, PH, "CEB", "Cebu";
+    PH_COM, PH, "COM", "Compostela Valley";
+    PH_DAO, PH, "DAO", "Davao Oriental";
+    PH_DAS, PH, "DAS", "Davao del Sur";
+    PH_DAV, PH, "DAV", "Davao del Norte";
+    PH_DIN, PH, "DIN", "Dinagat Islands";
+    PH_EAS, PH, "EAS", "Eastern Samar";
+    PH_GUI, PH, "GUI", "Gimaras";
+    PH_IFU, PH, "IFU", "Ifugao";
+    PH_ILI, PH, "ILI", "Iloilo";
+    PH_ILN, PH, "ILN", "Hilagang Iloko";
+    PH_ILS, PH, "ILS", "Ilocos Sur";
+    PH_ISA, PH, "ISA", "Isabela";
+    PH_KAL, PH, "KAL", "Kalinga";
+    PH_LAG, PH, "LAG", "Laguna";
+    PH_LAN, PH, "LAN", "Hilagang Lanaw";
+    PH_LAS, PH, "LAS", "Lanao del Sur";
+    PH_LEY, PH, "LEY", "Leyte";
+    PH_LUN, PH, "LUN", "La Union";
+    PH_MAD, PH, "MAD", "Marinduke";
+    PH_MAG, PH, "MAG", "Magindanaw";
+    PH_MAS, PH, "MAS", "Masbate";
+    PH_MDC, PH, "MDC", "Kanlurang Mindoro";
+    PH_MDR, PH, "MDR", "Mindoro Oriental";
+    PH_MOU, PH, "MOU", "Lalawigang Bulubundukin";
+    PH_MSC, PH, "MSC", "Kanlurang Misamis";
+    PH_MSR, PH, "MSR", "Misamis Oriental";
+    PH_NCO, PH, "NCO", "Cotabato";
+    PH_NEC, PH, "NEC", "Kanlurang Negros";
+    PH_NER, PH, "NER", "Negros Oriental";
+    PH_NSA, PH, "NSA", "Hilagang Samar";
+    PH_NUE, PH, "NUE", "Nueva Ecija";
+    PH_NUV, PH, "NUV", "Nueva Vizcaya";
+    PH_PAM, PH, "PAM", "Pampanga";
+    PH_PAN, PH, "PAN", "Pangasinan";
+    PH_PLW, PH, "PLW", "Palawan";
+    PH_QUE, PH, "QUE", "Keson";
+    PH_QUI, PH, "QUI", "Kirino";
+    PH_RIZ, PH, "RIZ", "Risal";
+    PH_ROM, PH, "ROM", "Romblon";
+    PH_SAR, PH, "SAR", "Sarangani";
+    PH_SCO, PH, "SCO", "South Cotabato";
+    PH_SIG, PH, "SIG", "Sikihor";
+    PH_SLE, PH, "SLE", "Katimogang Leyte";
+    PH_SLU, PH, "SLU", "Sulu";
+    PH_SOR, PH, "SOR", "Sorsogon";
+    PH_SUK, PH, "SUK", "Sultan Kudarat";
+    PH_SUN, PH, "SUN", "Hilagang Surigaw";
+    PH_SUR, PH, "SUR", "Surigao del Sur";
+    PH_TAR, PH, "TAR", "Tarlac";
+    PH_TAW, PH, "TAW", "Tawi-Tawi";
+    PH_WSA, PH, "WSA", "Samar";
+    PH_ZAN, PH, "ZAN", "Hilagang Sambuwangga";
+    PH_ZAS, PH, "ZAS", "Timog Sambuwangga";
+    PH_ZMB, PH, "ZMB", "Sambales";
+    PH_ZSI, PH, "ZSI", "Sambuwangga Sibugay";
+    PK_BA, PK, "BA", "Balochistan";
+    PK_GB, PK, "GB", "Gilgit-Baltistān";
+    PK_IS, PK, "IS", "Islāmābād";
+    PK_JK, PK, "JK", "Azad Kashmir";
+    PK_KP, PK, "KP", "Khaībar Pakhtūnkhwā";
+    PK_PB, PK, "PB", "Panjāb";
+    PK_SD, PK, "SD", "Sindh";
+    PK_TA, PK, "TA", "Federally Administered Tribal Areas";
+    PL_DS, PL, "DS", "Dolnośląskie";
+    PL_KP, PL, "KP", "Kujawsko-pomorskie";
+    PL_LB, PL, "LB", "Lubuskie";
+    PL_LD, PL, "LD", "Łódzkie";
+    PL_LU, PL, "LU", "Lubelskie";
+    PL_MA, PL, "MA", "Małopolskie";
+    PL_MZ, PL, "MZ", "Mazowieckie";
+    PL_OP, PL, "OP", "Opolskie";
+    PL_PD, PL, "PD", "Podlaskie";
+    PL_PK, PL, "PK", "Podkarpackie";
+    PL_PM, PL, "PM", "Pomorskie";
+    PL_SK, PL, "SK", "Świętokrzyskie";
+    PL_SL, PL, "SL", "Śląskie";
+    PL_WN, PL, "WN", "Warmińsko-mazurskie";
+    PL_WP, PL, "WP", "Wielkopolskie";
+    PL_ZP, PL, "ZP", "Zachodniopomorskie";
+    PS_BTH, PS, "BTH", "Bayt Laḩm";
+    PS_DEB, PS, "DEB", "Dayr al Balaḩ";
+    PS_GZA, PS, "GZA", "Gaza";
+    PS_HBN, PS, "HBN", "Al Khalīl";
+    PS_JEM, PS, "JEM", "Al Quds";
+    PS_JEN, PS, "JEN", "Janīn";
+    PS_JRH, PS, "JRH", "Arīḩā wa al Aghwār";
+    PS_KYS, PS, "KYS", "Khan Yunis";
+    PS_NBS, PS, "NBS", "Nablus";
+    PS_NGZ, PS, "NGZ", "North Gaza";
+    PS_QQA, PS, "QQA", "Qalqilya";
+    PS_RBH, PS, "RBH", "Ramallah";
+    PS_RFH, PS, "RFH", "Rafah";
+    PS_SLT, PS, "SLT", "Salfit";
+    PS_TBS, PS, "TBS", "Tubas";
+    PS_TKM, PS, "TKM", "Tulkarm";
+    PT_01, PT, "01", "Aveiro";
+    PT_02, PT, "02", "Beja";
+    PT_03, PT, "03", "Braga";
+    PT_04, PT, "04", "Bragança";
+    PT_05, PT, "05", "Castelo Branco";
+    PT_06, PT, "06", "Coimbra";
+    PT_07, PT, "07", "Évora";
+    PT_08, PT, "08", "Faro";
+    PT_09, PT, "09", "Guarda";
+    PT_10, PT, "10", "Leiria";
+    PT_11, PT, "11", "Lisboa";
+    PT_12, PT, "12", "Portalegre";
+    PT_13, PT, "13", "Porto";
+    PT_14, PT, "14", "Santarém";
+    PT_15, PT, "15", "Setúbal";
+    PT_16, PT, "16", "Viana do Castelo";
+    PT_17, PT, "17", "Vila Real";
+    PT_18, PT, "18", "Viseu";
+    PT_20, PT, "20", "Região Autónoma dos Açores";
+    PT_30, PT, "30", "Região Autónoma da Madeira";
+    PW_002, PW, "002", "Aimeliik";
+    PW_004, PW, "004", "Airai";
+    PW_010, PW, "010", "Angaur";
+    PW_050, PW, "050", "Hatobohei";
+    PW_100, PW, "100", "Kayangel";
+    PW_150, PW, "150", "Koror";
+    PW_212, PW, "212", "Melekeok";
+    PW_214, PW, "214", "Ngaraard";
+    PW_218, PW, "218", "Ngarchelong";
+    PW_222, PW, "222", "Ngardmau";
+    PW_224, PW, "224", "Ngatpang";
+    PW_226, PW, "226", "Ngchesar";
+    PW_227, PW, "227", "Ngeremlengui";
+    PW_228, PW, "228", "Ngiwal";
+    PW_350, PW, "350", "Peleliu";
+    PW_370, PW, "370", "Sonsorol";
+    PY_1, PY, "1", "Concepción";
+    PY_10, PY, "10", "Alto Paraná";
+    PY_11, PY, "11", "Central";
+    PY_12, PY, "12", "Ñeembucú";
+    PY_13, PY, "13", "Amambay";
+    PY_14, PY, "14", "Canindeyú";
+    PY_15, PY, "15", "Presidente Hayes";
+    PY_16, PY, "16", "Alto Paraguay";
+    PY_19, PY, "19", "Boquerón";
+    PY_2, PY, "2", "San Pedro";
+    PY_3, PY, "3", "Cordillera";
+    PY_4, PY, "4", "Guairá";
+    PY_5, PY, "5", "Caaguazú";
+    PY_6, PY, "6", "Caazapá";
+    PY_7, PY, "7", "Itapúa";
+    PY_8, PY, "8", "Misiones";
+    PY_9, PY, "9", "Paraguarí";
+    PY_ASU, PY, "ASU", "Asunción";
+    QA_DA, QA, "DA", "Ad Dawḩah";
+    QA_KH, QA, "KH", "Al Khawr wa adh Dhakhīrah";
+    QA_MS, QA, "MS", "Ash Shamāl";
+    QA_RA, QA, "RA", "Ar Rayyān";
+    QA_US, QA, "US", "Umm Şalāl";
+    QA_WA, QA, "WA", "Al Wakrah";
+    QA_ZA, QA, "ZA", "Az̧ Z̧a‘āyin";
+    RO_AB, RO, "AB", "Alba";
+    RO_AG, RO, "AG", "Argeş";
+    RO_AR, RO, "AR", "Arad";
+    RO_B, RO, "B", "Bucureşti";
+    RO_BC, RO, "BC", "Bacău";
+    RO_BH, RO, "BH", "Bihor";
+    RO_BN, RO, "BN", "Bistriţa-Năsăud";
+    RO_BR, RO, "BR", "Brăila";
+    RO_BT, RO, "BT", "Botoşani";
+    RO_BV, RO, "BV", "Braşov";
+    RO_BZ, RO, "BZ", "Buzău";
+    RO_CJ, RO, "CJ", "Cluj";
+    RO_CL, RO, "CL", "Călăraşi";
+    RO_CS, RO, "CS", "Caraş-Severin";
+    RO_CT, RO, "CT", "Constanţa";
+    RO_CV, RO, "CV", "Covasna";
+    RO_DB, RO, "DB", "Dâmboviţa";
+    RO_DJ, RO, "DJ", "Dolj";
+    RO_GJ, RO, "GJ", "Gorj";
+    RO_GL, RO, "GL", "Galaţi";
+    RO_GR, RO, "GR", "Giurgiu";
+    RO_HD, RO, "HD", "Hunedoara";
+    RO_HR, RO, "HR", "Harghita";
+    RO_IF, RO, "IF", "Ilfov";
+    RO_IL, RO, "IL", "Ialomiţa";
+    RO_IS, RO, "IS", "Iaşi";
+    RO_MH, RO, "MH", "Mehedinţi";
+    RO_MM, RO, "MM", "Maramureş";
+    RO_MS, RO, "MS", "Mureş";
+    RO_NT, RO, "NT", "Neamţ";
+    RO_OT, RO, "OT", "Olt";
+    RO_PH, RO, "PH", "Prahova";
+    RO_SB, RO, "SB", "Sibiu";
+    RO_SJ, RO, "SJ", "Sălaj";
+    RO_SM, RO, "SM", "Satu Mare";
+    RO_SV, RO, "SV", "Suceava";
+    RO_TL, RO, "TL", "Tulcea";
+    RO_TM, RO, "TM", "Timiş";
+    RO_TR, RO, "TR", "Teleorman";
+    RO_VL, RO, "VL", "Vâlcea";
+    RO_VN, RO, "VN", "Vrancea";
+    RO_VS, RO, "VS", "Vaslui";
+    RS_00, RS, "00", "Beograd";
+    RS_01, RS, "01", "Severnobački okrug";
+    RS_02, RS, "02", "Srednjebanatski okrug";
+    RS_03, RS, "03", "Severnobanatski okrug";
+    RS_04, RS, "04", "Južnobanatski okrug";
+    RS_05, RS, "05", "Zapadnobački okrug";
+    RS_06, RS, "06", "Južnobački okrug";
+    RS_07, RS, "07", "Sremski okrug";
+    RS_08, RS, "08", "Mačvanski okrug";
+    RS_09, RS, "09", "Kolubarski okrug";
+    RS_10, RS, "10", "Podunavski okrug";
+    RS_11, RS, "11", "Braničevski okrug";
+    RS_12, RS, "12", "Šumadijski okrug";
+    RS_13, RS, "13", "Pomoravski okrug";
+    RS_14, RS, "14", "Borski okrug";
+    RS_15, RS, "15", "Zaječarski okrug";
+    RS_16, RS, "16", "Zlatiborski okrug";
+    RS_17, RS, "17", "Moravički okrug";
+    RS_18, RS, "18", "Raški okrug";
+    RS_19, RS, "19", "Rasinski okrug";
+    RS_20, RS, "20", "Nišavski okrug";
+    RS_21, RS, "21", "Toplički okrug";
+    RS_22, RS, "22", "Pirotski okrug";
+    RS_23, RS, "23", "Jablanički okrug";
+    RS_24, RS, "24", "Pčinjski okrug";
+    RS_25, RS, "25", "Kosovski okrug";
+    RS_26, RS, "26", "Pećki okrug";
+    RS_27, RS, "27", "Prizrenski okrug";
+    RS_28, RS, "28", "Kosovsko-Mitrovački okrug";
+    RS_29, RS, "29", "Kosovsko-Pomoravski okrug";
+    RS_KM, RS, "KM", "Kosovo-Metohija";
+    RS_VO, RS, "VO", "Vojvodina";
+    RU_AD, RU, "AD", "Adygeja, Respublika";
+    RU_AL, RU, "AL", "Altaj, Respublika";
+    RU_ALT, RU, "ALT", "Altajskij kraj";
+    RU_AMU, RU, "AMU", "Amurskaja oblast'";
+    RU_ARK, RU, "ARK", "Arhangel'skaja oblast'";
+    RU_AST, RU, "AST", "Astrahanskaja oblast'";
+    RU_BA, RU, "BA", "Bashkortostan, Respublika";
+    RU_BEL, RU, "BEL", "Belgorodskaja oblast'";
+    RU_BRY, RU, "BRY", "Brjanskaja oblast'";
+    RU_BU, RU, "BU", "Burjatija, Respublika";
+    RU_CE, RU, "CE", "Čečenskaja Respublika";
+    RU_CHE, RU, "CHE", "Čeljabinskaja oblast'";
+    RU_CHU, RU, "CHU", "Chukotskiy avtonomnyy okrug";
+    RU_CU, RU, "CU", "Chuvashskaya Respublika";
+    RU_DA, RU, "DA", "Dagestan, Respublika";
+    RU_IN, RU, "IN", "Ingušetija, Respublika";
+    RU_IRK, RU, "IRK", "Irkutskaja oblast'";
+    RU_IVA, RU, "IVA", "Ivanovskaja oblast'";
+    RU_KAM, RU, "KAM", "Kamčatskij kraj";
+    RU_KB, RU, "KB", "Kabardino-Balkarskaja Respublika";
+    RU_KC, RU, "KC", "Karačaevo-Čerkesskaja Respublika";
+    RU_KDA, RU, "KDA", "Krasnodarskij kraj";
+    RU_KEM, RU, "KEM", "Kemerovskaja oblast'";
+    RU_KGD, RU, "KGD", "Kaliningradskaja oblast'";
+    RU_KGN, RU, "KGN", "Kurganskaja oblast'";
+    RU_KHA, RU, "KHA", "Habarovskij kraj";
+    RU_KHM, RU, "KHM", "Hanty-Mansijskij avtonomnyj okrug";
+    RU_KIR, RU, "KIR", "Kirovskaja oblast'";
+    RU_KK, RU, "KK", "Hakasija, Respublika";
+    RU_KL, RU, "KL", "Kalmykija, Respublika";
+    RU_KLU, RU, "KLU", "Kaluzhskaya oblast'";
+    RU_KO, RU, "KO", "Komi, Respublika";
+    RU_KOS, RU, "KOS", "Kostromskaja oblast'";
+    RU_KR, RU, "KR", "Karelija, Respublika";
+    RU_KRS, RU, "KRS", "Kurskaja oblast'";
+    RU_KYA, RU, "KYA", "Krasnojarskij kraj";
+    RU_LEN, RU, "LEN", "Leningradskaja oblast'";
+    RU_LIP, RU, "LIP", "Lipeckaja oblast'";
+    RU_MAG, RU, "MAG", "Magadanskaja oblast'";
+    RU_ME, RU, "ME", "Marij Èl, Respublika";
+    RU_MO, RU, "MO", "Mordovija, Respublika";
+    RU_MOS, RU, "MOS", "Moskovskaja oblast'";
+    RU_MOW, RU, "MOW", "Moskva";
+    RU_MUR, RU, "MUR", "Murmanskaja oblast'";
+    RU_NEN, RU, "NEN", "Neneckij avtonomnyj okrug";
+    RU_NGR, RU, "NGR", "Novgorodskaja oblast'";
+    RU_NIZ, RU, "NIZ", "Nižegorodskaja oblast'";
+    RU_NVS, RU, "NVS", "Novosibirskaja oblast'";
+    RU_OMS, RU, "OMS", "Omskaja oblast'";
+    RU_ORE, RU, "ORE", "Orenburgskaja oblast'";
+    RU_ORL, RU, "ORL", "Orlovskaja oblast'";
+    RU_PER, RU, "PER", "Permskij kraj";
+    RU_PNZ, RU, "PNZ", "Penzenskaja oblast'";
+    RU_PRI, RU, "PRI", "Primorskij kraj";
+    RU_PSK, RU, "PSK", "Pskovskaja oblast'";
+    RU_ROS, RU, "ROS", "Rostovskaja oblast'";
+    RU_RYA, RU, "RYA", "Rjazanskaja oblast'";
+    RU_SA, RU, "SA", "Saha, Respublika";
+    RU_SAK, RU, "SAK", "Sahalinskaja oblast'";
+    RU_SAM, RU, "SAM", "Samarskaja oblast'";
+    RU_SAR, RU, "SAR", "Saratovskaja oblast'";
+    RU_SE, RU, "SE", "Severnaja Osetija, Respublika";
+    RU_SMO, RU, "SMO", "Smolenskaja oblast'";
+    RU_SPE, RU, "SPE", "Sankt-Peterburg";
+    RU_STA, RU, "STA", "Stavropol'skij kraj";
+    RU_SVE, RU, "SVE", "Sverdlovskaja oblast'";
+    RU_TA, RU, "TA", "Tatarstan, Respublika";
+    RU_TAM, RU, "TAM", "Tambovskaja oblast'";
+    RU_TOM, RU, "TOM", "Tomskaja oblast'";
+    RU_TUL, RU, "TUL", "Tul'skaja oblast'";
+    RU_TVE, RU, "TVE", "Tverskaja oblast'";
+    RU_TY, RU, "TY", "Tyva, Respublika";
+    RU_TYU, RU, "TYU", "Tjumenskaja oblast'";
+    RU_UD, RU, "UD", "Udmurtskaja Respublika";
+    RU_ULY, RU, "ULY", "Ul'janovskaja oblast'";
+    RU_VGG, RU, "VGG", "Volgogradskaja oblast'";
+    RU_VLA, RU, "VLA", "Vladimirskaja oblast'";
+    RU_VLG, RU, "VLG", "Vologodskaja oblast'";
+    RU_VOR, RU, "VOR", "Voronezhskaya oblast'";
+    RU_YAN, RU, "YAN", "Jamalo-Neneckij avtonomnyj okrug";
+    RU_YAR, RU, "YAR", "Jaroslavskaja oblast'";
+    RU_YEV, RU, "YEV", "Evrejskaja avtonomnaja oblast'";
+    RU_ZAB, RU, "ZAB", "Zabajkal'skij kraj";
+    RW_01, RW, "01", "Ville de Kigal";
+    RW_02, RW, "02", "Est";
+    RW_03, RW, "03", "Nord";
+    RW_04, RW, "04", "Ouest";
+    RW_05, RW, "05", "Sud";
+    SA_01, SA, "01", "Ar Riyāḑ";
+    SA_02, SA, "02", "Makkah al Mukarramah";
+    SA_03, SA, "03", "Al Madīnah al Munawwarah";
+    SA_04, SA, "04", "Ash Sharqīyah";
+    SA_05, SA, "05", "Al Qaşīm";
+    SA_06, SA, "06", "Ḩā'il";
+    SA_07, SA, "07", "Tabūk";
+    SA_08, SA, "08", "Al Ḩudūd ash Shamālīyah";
+    SA_09, SA, "09", "Jāzān";
+    SA_10, SA, "10", "Najrān";
+    SA_11, SA, "11", "Al Bāḩah";
+    SA_12, SA, "12", "Al Jawf";
+    SA_14, SA, "14", "'Asīr";
+    SB_CE, SB, "CE", "Central";
+    SB_CH, SB, "CH", "Choiseul";
+    SB_CT, SB, "CT", "Capital Territory";
+    SB_GU, SB, "GU", "Guadalcanal";
+    SB_IS, SB, "IS", "Isabel";
+    SB_MK, SB, "MK", "Makira-Ulawa";
+    SB_ML, SB, "ML", "Malaita";
+    SB_RB, SB, "RB", "Rennell and Bellona";
+    SB_TE, SB, "TE", "Temotu";
+    SB_WE, SB, "WE", "Western";
+    SC_01, SC, "01", "Anse aux Pins";
+    SC_02, SC, "02", "Ans Bwalo";
+    SC_03, SC, "03", "Anse Etoile";
+    SC_04, SC, "04", "Au Cap";
+    SC_05, SC, "05", "Anse Royale";
+    SC_06, SC, "06", "Baie Lazare";
+    SC_07, SC, "07", "Baie Sainte-Anne";
+    SC_08, SC, "08", "Beau Vallon";
+    SC_09, SC, "09", "Bel Air";
+    SC_10, SC, "10", "Bel Ombre";
+    SC_11, SC, "11", "Cascade";
+    SC_12, SC, "12", "Glacis";
+    SC_13, SC, "13", "Grand'Anse Mahé";
+    SC_14, SC, "14", "Grand'Anse Praslin";
+    SC_15, SC, "15", "Ladig";
+    SC_16, SC, "16", "English River";
+    SC_17, SC, "17", "Mon Bikston";
+    SC_18, SC, "18", "Mon Fleri";
+    SC_19, SC, "19", "Plaisance";
+    SC_20, SC, "20", "Pointe La Rue";
+    SC_21, SC, "21", "Porglo";
+    SC_22, SC, "22", "Saint Louis";
+    SC_23, SC, "23", "Takamaka";
+    SC_24, SC, "24", "Lemamel";
+    SC_25, SC, "25", "Roche Caïman";
+    SD_DC, SD, "DC", "Central Darfur";
+    SD_DE, SD, "DE", "East Darfur";
+    SD_DN, SD, "DN", "North Darfur";
+    SD_DS, SD, "DS", "Janūb Dārfūr";
+    SD_DW, SD, "DW", "Gharb Dārfūr";
+    SD_GD, SD, "GD", "Al Qaḑārif";
+    SD_GZ, SD, "GZ", "Al Jazīrah";
+    SD_KA, SD, "KA", "Kassala";
+    SD_KH, SD, "KH", "Al Kharţūm";
+    SD_KN, SD, "KN", "North Kordofan";
+    SD_KS, SD, "KS", "Janūb Kurdufān";
+    SD_NB, SD, "NB", "An Nīl al Azraq";
+    SD_NO, SD, "NO", "Ash Shamālīyah";
+    SD_NR, SD, "NR", "An Nīl";
+    SD_NW, SD, "NW", "An Nīl al Abyaḑ";
+    SD_RS, SD, "RS", "Al Baḩr al Aḩmar";
+    SD_SI, SD, "SI", "Sennar";
+    SE_AB, SE, "AB", "Stockholms län";
+    SE_AC, SE, "AC", "Västerbottens län";
+    SE_BD, SE, "BD", "Norrbottens län";
+    SE_C, SE, "C", "Uppsala län";
+    SE_D, SE, "D", "Södermanlands län";
+    SE_E, SE, "E", "Östergötlands län";
+    SE_F, SE, "F", "Jönköpings län";
+    SE_G, SE, "G", "Kronobergs län";
+    SE_H, SE, "H", "Kalmar län";
+    SE_I, SE, "I", "Gotlands län";
+    SE_K, SE, "K", "Blekinge län";
+    SE_M, SE, "M", "Skåne län";
+    SE_N, SE, "N", "Hallands län";
+    SE_O, SE, "O", "Västra Götalands län";
+    SE_S, SE, "S", "Värmlands län";
+    SE_T, SE, "T", "Örebro län";
+    SE_U, SE, "U", "Västmanlands län";
+    SE_W, SE, "W", "Dalarnas län";
+    SE_X, SE, "X", "Gävleborgs län";
+    SE_Y, SE, "Y", "Västernorrlands län";
+    SE_Z, SE, "Z", "Jämtlands län";
+    SG_01, SG, "01", "Central Singapore";
+    SG_02, SG, "02", "North East";
+    SG_03, SG, "03", "North West";
+    SG_04, SG, "04", "South East";
+    SG_05, SG, "05", "South West";
+    SH_AC, SH, "AC", "Ascension";
+    SH_HL, SH, "HL", "Saint Helena";
+    SH_TA, SH, "TA", "Tristan da Cunha";
+    SI_001, SI, "001", "Ajdovščina";
+    SI_002, SI, "002", "Beltinci";
+    SI_003, SI, "003", "Bled";
+    SI_004, SI, "004", "Bohinj";
+    SI_005, SI, "005", "Borovnica";
+    SI_006, SI, "006", "Bovec";
+    SI_007, SI, "007", "Brda";
+    SI_008, SI, "008", "Brezovica";
+    SI_009, SI, "009", "Brežice";
+    SI_010, SI, "010", "Tišina";
+    SI_011, SI, "011", "Celje";
+    SI_012, SI, "012", "Cerklje na Gorenjskem";
+    SI_013, SI, "013", "Cerknica";
+    SI_014, SI, "014", "Cerkno";
+    SI_015, SI, "015", "Črenšovci";
+    SI_016, SI, "016", "Črna na Koroškem";
+    SI_017, SI, "017", "Črnomelj";
+    SI_018, SI, "018", "Destrnik";
+    SI_019, SI, "019", "Divača";
+    SI_020, SI, "020", "Dobrepolje";
+    SI_021, SI, "021", "Dobrova-Polhov Gradec";
+    SI_022, SI, "022", "Dol pri Ljubljani";
+    SI_023, SI, "023", "Domžale";
+    SI_024, SI, "024", "Dornava";
+    SI_025, SI, "025", "Dravograd";
+    SI_026, SI, "026", "Duplek";
+    SI_027, SI, "027", "Gorenja vas-Poljane";
+    SI_028, SI, "028", "Gorišnica";
+    SI_029, SI, "029", "Gornja Radgona";
+    SI_030, SI, "030", "Gornji Grad";
+    SI_031, SI, "031", "Gornji Petrovci";
+    SI_032, SI, "032", "Grosuplje";
+    SI_033, SI, "033", "Šalovci";
+    SI_034, SI, "034", "Hrastnik";
+    SI_035, SI, "035", "Hrpelje-Kozina";
+    SI_036, SI, "036", "Idrija";
+    SI_037, SI, "037", "Ig";
+    SI_038, SI, "038", "Ilirska Bistrica";
+    SI_039, SI, "039", "Ivančna Gorica";
+    SI_040, SI, "040", "Izola";
+    SI_041, SI, "041", "Jesenice";
+    SI_042, SI, "042", "Juršinci";
+    SI_043, SI, "043", "Kamnik";
+    SI_044, SI, "044", "Kanal";
+    SI_045, SI, "045", "Kidričevo";
+    SI_046, SI, "046", "Kobarid";
+    SI_047, SI, "047", "Kobilje";
+    SI_048, SI, "048", "Kočevje";
+    SI_049, SI, "049", "Komen";
+    SI_050, SI, "050", "Koper";
+    SI_051, SI, "051", "Kozje";
+    SI_052, SI, "052", "Kranj";
+    SI_053, SI, "053", "Kranjska Gora";
+    SI_054, SI, "054", "Krško";
+    SI_055, SI, "055", "Kungota";
+    SI_056, SI, "056", "Kuzma";
+    SI_057, SI, "057", "Laško";
+    SI_058, SI, "058", "Lenart";
+    SI_059, SI, "059", "Lendava";
+    SI_060, SI, "060", "Litija";
+    SI_061, SI, "061", "Ljubljana";
+    SI_062, SI, "062", "Ljubno";
+    SI_063, SI, "063", "Ljutomer";
+    SI_064, SI, "064", "Logatec";
+    SI_065, SI, "065", "Loška Dolina";
+    SI_066, SI, "066", "Loški Potok";
+    SI_067, SI, "067", "Luče";
+    SI_068, SI, "068", "Lukovica";
+    SI_069, SI, "069", "Majšperk";
+    SI_070, SI, "070", "Maribor";
+    SI_071, SI, "071", "Medvode";
+    SI_072, SI, "072", "Mengeš";
+    SI_073, SI, "073", "Metlika";
+    SI_074, SI, "074", "Mežica";
+    SI_075, SI, "075", "Miren-Kostanjevica";
+    SI_076, SI, "076", "Mislinja";
+    SI_077, SI, "077", "Moravče";
+    SI_078, SI, "078", "Moravske Toplice";
+    SI_079, SI, "079", "Mozirje";
+    SI_080, SI, "080", "Murska Sobota";
+    SI_081, SI, "081", "Muta";
+    SI_082, SI, "082", "Naklo";
+    SI_083, SI, "083", "Nazarje";
+    SI_084, SI, "084", "Nova Gorica";
+    SI_085, SI, "085", "Novo Mesto";
+    SI_086, SI, "086", "Odranci";
+    SI_087, SI, "087", "Ormož";
+    SI_088, SI, "088", "Osilnica";
+    SI_089, SI, "089", "Pesnica";
+    SI_090, SI, "090", "Piran";
+    SI_091, SI, "091", "Pivka";
+    SI_092, SI, "092", "Podčetrtek";
+    SI_093, SI, "093", "Podvelka";
+    SI_094, SI, "094", "Postojna";
+    SI_095, SI, "095", "Preddvor";
+    SI_096, SI, "096", "Ptuj";
+    SI_097, SI, "097", "Puconci";
+    SI_098, SI, "098", "Rače-Fram";
+    SI_099, SI, "099", "Radeče";
+    SI_100, SI, "100", "Radenci";
+    SI_101, SI, "101", "Radlje ob Dravi";
+    SI_102, SI, "102", "Radovljica";
+    SI_103, SI, "103", "Ravne na Koroškem";
+    SI_104, SI, "104", "Ribnica";
+    SI_105, SI, "105", "Rogašovci";
+    SI_106, SI, "106", "Rogaška Slatina";
+    SI_107, SI, "107", "Rogatec";
+    SI_108, SI, "108", "Ruše";
+    SI_109, SI, "109", "Semič";
+    SI_110, SI, "110", "Sevnica";
+    SI_111, SI, "111", "Sežana";
+    SI_112, SI, "112", "Slovenj Gradec";
+    SI_113, SI, "113", "Slovenska Bistrica";
+    SI_114, SI, "114", "Slovenske Konjice";
+    SI_115, SI, "115", "Starše";
+    SI_116, SI, "116", "Sveti Jurij";
+    SI_117, SI, "117", "Šenčur";
+    SI_118, SI, "118", "Šentilj";
+    SI_119, SI, "119", "Šentjernej";
+    SI_120, SI, "120", "Šentjur";
+    SI_121, SI, "121", "Škocjan";
+    SI_122, SI, "122", "Škofja Loka";
+    SI_123, SI, "123", "Škofljica";
+    SI_124, SI, "124", "Šmarje pri Jelšah";
+    SI_125, SI, "125", "Šmartno ob Paki";
+    SI_126, SI, "126", "Šoštanj";
+    SI_127, SI, "127", "Štore";
+    SI_128, SI, "128", "Tolmin";
+    SI_129, SI, "129", "Trbovlje";
+    SI_130, SI, "130", "Trebnje";
+    SI_131, SI, "131", "Tržič";
+    SI_132, SI, "132", "Turnišče";
+    SI_133, SI, "133", "Velenje";
+    SI_134, SI, "134", "Velike Lašče";
+    SI_135, SI, "135", "Videm";
+    SI_136, SI, "136", "Vipava";
+    SI_137, SI, "137", "Vitanje";
+    SI_138, SI, "138", "Vodice";
+    SI_139, SI, "139", "Vojnik";
+    SI_140, SI, "140", "Vrhnika";
+    SI_141, SI, "141", "Vuzenica";
+    SI_142, SI, "142", "Zagorje ob Savi";
+    SI_143, SI, "143", "Zavrč";
+    SI_144, SI, "144", "Zreče";
+    SI_146, SI, "146", "Železniki";
+    SI_147, SI, "147", "Žiri";
+    SI_148, SI, "148", "Benedikt";
+    SI_149, SI, "149", "Bistrica ob Sotli";
+    SI_150, SI, "150", "Bloke";
+    SI_151, SI, "151", "Braslovče";
+    SI_152, SI, "152", "Cankova";
+    SI_153, SI, "153", "Cerkvenjak";
+    SI_154, SI, "154", "Dobje";
+    SI_155, SI, "155", "Dobrna";
+    SI_156, SI, "156", "Dobrovnik";
+    SI_157, SI, "157", "Dolenjske Toplice";
+    SI_158, SI, "158", "Grad";
+    SI_159, SI, "159", "Hajdina";
+    SI_160, SI, "160", "Hoče-Slivnica";
+    SI_161, SI, "161", "Hodoš";
+    SI_162, SI, "162", "Horjul";
+    SI_163, SI, "163", "Jezersko";
+    SI_164, SI, "164", "Komenda";
+    SI_165, SI, "165", "Kostel";
+    SI_166, SI, "166", "Križevci";
+    SI_167, SI, "167", "Lovrenc na Pohorju";
+    SI_168, SI, "168", "Markovci";
+    SI_169, SI, "169", "Miklavž na Dravskem Polju";
+    SI_170, SI, "170", "Mirna Peč";
+    SI_171, SI, "171", "Oplotnica";
+    SI_172, SI, "172", "Podlehnik";
+    SI_173, SI, "173", "Polzela";
+    SI_174, SI, "174", "Prebold";
+    SI_175, SI, "175", "Prevalje";
+    SI_176, SI, "176", "Razkrižje";
+    SI_177, SI, "177", "Ribnica na Pohorju";
+    SI_178, SI, "178", "Selnica ob Dravi";
+    SI_179, SI, "179", "Sodražica";
+    SI_180, SI, "180", "Solčava";
+    SI_181, SI, "181", "Sveta Ana";
+    SI_182, SI, "182", "Sveti Andraž v Slovenskih Goricah";
+    SI_183, SI, "183", "Šempeter-Vrtojba";
+    SI_184, SI, "184", "Tabor";
+    SI_185, SI, "185", "Trnovska Vas";
+    SI_186, SI, "186", "Trzin";
+    SI_187, SI, "187", "Velika Polana";
+    SI_188, SI, "188", "Veržej";
+    SI_189, SI, "189", "Vransko";
+    SI_190, SI, "190", "Žalec";
+    SI_191, SI, "191", "Žetale";
+    SI_192, SI, "192", "Žirovnica";
+    SI_193, SI, "193", "Žužemberk";
+    SI_194, SI, "194", "Šmartno pri Litiji";
+    SI_195, SI, "195", "Apače";
+    SI_196, SI, "196", "Cirkulane";
+    SI_197, SI, "197", "Kosanjevica na Krki";
+    SI_198, SI, "198", "Makole";
+    SI_199, SI, "199", "Mokronog-Trebelno";
+    SI_200, SI, "200", "Poljčane";
+    SI_201, SI, "201", "Renče-Vogrsko";
+    SI_202, SI, "202", "Središče ob Dravi";
+    SI_203, SI, "203", "Straža";
+    SI_204, SI, "204", "Sveta Trojica v Slovenskih Goricah";
+    SI_205, SI, "205", "Sveti Tomaž";
+    SI_206, SI, "206", "Šmarješke Toplice";
+    SI_207, SI, "207", "Gorje";
+    SI_208, SI, "208", "Log-Dragomer";
+    SI_209, SI, "209", "Rečica ob Savinji";
+    SI_210, SI, "210", "Sveti Jurij v Slovenskih Goricah";
+    SI_211, SI, "211", "Šentrupert";
+    SI_212, SI, "212", "Mirna";
+    SK_BC, SK, "BC", "Banskobystrický kraj";
+    SK_BL, SK, "BL", "Bratislavský kraj";
+    SK_KI, SK, "KI", "Košický kraj";
+    SK_NI, SK, "NI", "Nitriansky kraj";
+    SK_PV, SK, "PV", "Prešovský kraj";
+    SK_TA, SK, "TA", "Trnavský kraj";
+    SK_TC, SK, "TC", "Trenčiansky kraj";
+    SK_ZI, SK, "ZI", "Žilinský kraj";
+    SL_E, SL, "E", "Eastern";
+    SL_N, SL, "N", "Northern";
+    SL_S, SL, "S", "Southern";
+    SL_W, SL, "W", "Western Area";
+    SM_01, SM, "01", "Acquaviva";
+    SM_02, SM, "02", "Chiesanuova";
+    SM_03, SM, "03", "Domagnano";
+    SM_04, SM, "04", "Faetano";
+    SM_05, SM, "05", "Fiorentino";
+    SM_06, SM, "06", "Borgo Maggiore";
+    SM_07, SM, "07", "San Marino";
+    SM_08, SM, "08", "Montegiardino";
+    SM_09, SM, "09", "Serravalle";
+    SN_DB, SN, "DB", "Diourbel";
+    SN_DK, SN, "DK", "Dakar";
+    SN_FK, SN, "FK", "Fatick";
+    SN_KA, SN, "KA", "Kaffrine";
+    SN_KD, SN, "KD", "Kolda";
+    SN_KE, SN, "KE", "Kédougou";
+    SN_KL, SN, "KL", "Kaolack";
+    SN_LG, SN, "LG", "Louga";
+    SN_MT, SN, "MT", "Matam";
+    SN_SE, SN, "SE", "Sédhiou";
+    SN_SL, SN, "SL", "Saint-Louis";
+    SN_TC, SN, "TC", "Tambacounda";
+    SN_TH, SN, "TH", "Thiès";
+    SN_ZG, SN, "ZG", "Ziguinchor";
+    SO_AW, SO, "AW", "Awdal";
+    SO_BK, SO, "BK", "Bakool";
+    SO_BN, SO, "BN", "Banaadir";
+    SO_BR, SO, "BR", "Bari";
+    SO_BY, SO, "BY", "Bay";
+    SO_GA, SO, "GA", "Galguduud";
+    SO_GE, SO, "GE", "Gedo";
+    SO_HI, SO, "HI", "Hiiraan";
+    SO_JD, SO, "JD", "Jubbada Dhexe";
+    SO_JH, SO, "JH", "Jubbada Hoose";
+    SO_MU, SO, "MU", "Mudug";
+    SO_NU, SO, "NU", "Nugaal";
+    SO_SA, SO, "SA", "Sanaag";
+    SO_SD, SO, "SD", "Shabeellaha Dhexe";
+    SO_SH, SO, "SH", "Shabeellaha Hoose";
+    SO_TO, SO, "TO", "Togdheer";
+    SO_WO, SO, "WO", "Woqooyi Galbeed";
+    SR_BR, SR, "BR", "Brokopondo";
+    SR_CM, SR, "CM", "Commewijne";
+    SR_CR, SR, "CR", "Coronie";
+    SR_MA, SR, "MA", "Marowijne";
+    SR_NI, SR, "NI", "Nickerie";
+    SR_PM, SR, "PM", "Paramaribo";
+    SR_PR, SR, "PR", "Para";
+    SR_SA, SR, "SA", "Saramacca";
+    SR_SI, SR, "SI", "Sipaliwini";
+    SR_WA, SR, "WA", "Wanica";
+    SS_BN, SS, "BN", "Northern Bahr el Ghazal";
+    SS_BW, SS, "BW", "Western Bahr el Ghazal";
+    SS_EC, SS, "EC", "Central Equatoria";
+    SS_EE, SS, "EE", "Eastern Equatoria";
+    SS_EW, SS, "EW", "Western Equatoria";
+    SS_JG, SS, "JG", "Jonglei";
+    SS_LK, SS, "LK", "Lakes";
+    SS_NU, SS, "NU", "Upper Nile";
+    SS_UY, SS, "UY", "Unity";
+    SS_WR, SS, "WR", "Warrap";
+    ST_P, ST, "P", "Príncipe";
+    ST_S, ST, "S", "São Tomé";
+    SV_AH, SV, "AH", "Ahuachapán";
+    SV_CA, SV, "CA", "Cabañas";
+    SV_CH, SV, "CH", "Chalatenango";
+    SV_CU, SV, "CU", "Cuscatlán";
+    SV_LI, SV, "LI", "La Libertad";
+    SV_MO, SV, "MO", "Morazán";
+    SV_PA, SV, "PA", "La Paz";
+    SV_SA, SV, "SA", "Santa Ana";
+    SV_SM, SV, "SM", "San Miguel";
+    SV_SO, SV, "SO", "Sonsonate";
+    SV_SS, SV, "SS", "San Salvador";
+    SV_UN, SV, "UN", "La Unión";
+    SV_US, SV, "US", "Usulután";
+    SY_DI, SY, "DI", "Dimashq";
+    SY_DR, SY, "DR", "Dar'ā";
+    SY_DY, SY, "DY", "Dayr az Zawr";
+    SY_HA, SY, "HA", "Al Ḩasakah";
+    SY_HI, SY, "HI", "Ḩimş";
+    SY_HL, SY, "HL", "Ḩalab";
+    SY_HM, SY, "HM", "Ḩamāh";
+    SY_ID, SY, "ID", "Idlib";
+    SY_LA, SY, "LA", "Al Lādhiqīyah";
+    SY_QU, SY, "QU", "Al Qunayţirah";
+    SY_RA, SY, "RA", "Ar Raqqah";
+    SY_RD, SY, "RD", "Rīf Dimashq";
+    SY_SU, SY, "SU", "As Suwaydā'";
+    SY_TA, SY, "TA", "Ţarţūs";
+    SZ_HH, SZ, "HH", "Hhohho";
+    SZ_LU, SZ, "LU", "Lubombo";
+    SZ_MA, SZ, "MA", "Manzini";
+    SZ_SH, SZ, "SH", "Shiselweni";
+    TD_BA, TD, "BA", "Al Baţḩah";
+    TD_BG, TD, "BG", "Baḩr al Ghazāl";
+    TD_BO, TD, "BO", "Borkou";
+    TD_CB, TD, "CB", "Chari-Baguirmi";
+    TD_EE, TD, "EE", "Ennedi-Est";
+    TD_EO, TD, "EO", "Ennedi-Ouest";
+    TD_GR, TD, "GR", "Guéra";
+    TD_HL, TD, "HL", "Hadjer Lamis";
+    TD_KA, TD, "KA", "Kanem";
+    TD_LC, TD, "LC", "Al Buḩayrah";
+    TD_LO, TD, "LO", "Logone-Occidental";
+    TD_LR, TD, "LR", "Logone-Oriental";
+    TD_MA, TD, "MA", "Mandoul";
+    TD_MC, TD, "MC", "Moyen-Chari";
+    TD_ME, TD, "ME", "Mayo-Kebbi-Est";
+    TD_MO, TD, "MO", "Mayo-Kebbi-Ouest";
+    TD_ND, TD, "ND", "Madīnat Injamīnā";
+    TD_OD, TD, "OD", "Ouaddaï";
+    TD_SA, TD, "SA", "Salamat";
+    TD_SI, TD, "SI", "Sila";
+    TD_TA, TD, "TA", "Tandjilé";
+    TD_TI, TD, "TI", "Tibastī";
+    TD_WF, TD, "WF", "Wadi Fira";
+    TG_C, TG, "C", "Centre";
+    TG_K, TG, "K", "Kara";
+    TG_M, TG, "M", "Maritime";
+    TG_P, TG, "P", "Plateaux";
+    TG_S, TG, "S", "Savannes";
+    TH_10, TH, "10", "Krung Thep Maha Nakhon";
+    TH_11, TH, "11", "Samut Prakan";
+    TH_12, TH, "12", "Nonthaburi";
+    TH_13, TH, "13", "Pathum Thani";
+    TH_14, TH, "14", "Phra Nakhon Si Ayutthaya";
+    TH_15, TH, "15", "Ang Thong";
+    TH_16, TH, "16", "Lop Buri";
+    TH_17, TH, "17", "Sing Buri";
+    TH_18, TH, "18", "Chai Nat";
+    TH_19, TH, "19", "Saraburi";
+    TH_20, TH, "20", "Chon Buri";
+    TH_21, TH, "21", "Rayong";
+    TH_22, TH, "22", "Chanthaburi";
+    TH_23, TH, "23", "Trat";
+    TH_24, TH, "24", "Chachoengsao";
+    TH_25, TH, "25", "Prachin Buri";
+    TH_26, TH, "26", "Nakhon Nayok";
+    TH_27, TH, "27", "Sa Kaeo";
+    TH_30, TH, "30", "Nakhon Ratchasima";
+    TH_31, TH, "31", "Buri Ram";
+    TH_32, TH, "32", "Surin";
+    TH_33, TH, "33", "Si Sa Ket";
+    TH_34, TH, "34", "Ubon Ratchathani";
+    TH_35, TH, "35", "Yasothon";
+    TH_36, TH, "36", "Chaiyaphum";
+    TH_37, TH, "37", "Amnat Charoen";
+    TH_38, TH, "38", "Bueng Kan";
+    TH_39, TH, "39", "Nong Bua Lam Phu";
+    TH_40, TH, "40", "Khon Kaen";
+    TH_41, TH, "41", "Udon Thani";
+    TH_42, TH, "42", "Loei";
+    TH_43, TH, "43", "Nong Khai";
+    TH_44, TH, "44", "Maha Sarakham";
+    TH_45, TH, "45", "Roi Et";
+    TH_46, TH, "46", "Kalasin";
+    TH_47, TH, "47", "Sakon Nakhon";
+    TH_48, TH, "48", "Nakhon Phanom";
+    TH_49, TH, "49", "Mukdahan";
+    TH_50, TH, "50", "Chiang Mai";
+    TH_51, TH, "51", "Lamphun";
+    TH_52, TH, "52", "Lampang";
+    TH_53, TH, "53", "Uttaradit";
+    TH_54, TH, "54", "Phrae";
+    TH_55, TH, "55", "Nan";
+    TH_56, TH, "56", "Phayao";
+    TH_57, TH, "57", "Chiang Rai";
+    TH_58, TH, "58", "Mae Hong Son";
+    TH_60, TH, "60", "Nakhon Sawan";
+    TH_61, TH, "61", "Uthai Thani";
+    TH_62, TH, "62", "Kamphaeng Phet";
+    TH_63, TH, "63", "Tak";
+    TH_64, TH, "64", "Sukhothai";
+    TH_65, TH, "65", "Phitsanulok";
+    TH_66, TH, "66", "Phichit";
+    TH_67, TH, "67", "Phetchabun";
+    TH_70, TH, "70", "Ratchaburi";
+    TH_71, TH, "71", "Kanchanaburi";
+    TH_72, TH, "72", "Suphan Buri";
+    TH_73, TH, "73", "Nakhon Pathom";
+    TH_74, TH, "74", "Samut Sakhon";
+    TH_75, TH, "75", "Samut Songkhram";
+    TH_76, TH, "76", "Phetchaburi";
+    TH_77, TH, "77", "Prachuap Khiri Khan";
+    TH_80, TH, "80", "Nakhon Si Thammarat";
+    TH_81, TH, "81", "Krabi";
+    TH_82, TH, "82", "Phangnga";
+    TH_83, TH, "83", "Phuket";
+    TH_84, TH, "84", "Surat Thani";
+    TH_85, TH, "85", "Ranong";
+    TH_86, TH, "86", "Chumphon";
+    TH_90, TH, "90", "Songkhla";
+    TH_91, TH, "91", "Satun";
+    TH_92, TH, "92", "Trang";
+    TH_93, TH, "93", "Phatthalung";
+    TH_94, TH, "94", "Pattani";
+    TH_95, TH, "95", "Yala";
+    TH_96, TH, "96", "Narathiwat";
+    TH_S, TH, "S", "Phatthaya";
+    TJ_DU, TJ, "DU", "Dushanbe";
+    TJ_GB, TJ, "GB", "Kŭhistoni Badakhshon";
+    TJ_KT, TJ, "KT", "Khatlon";
+    TJ_SU, TJ, "SU", "Sughd";
+    TL_AL, TL, "AL", "Aileu";
+    TL_AN, TL, "AN", "Ainaro";
+    TL_BA, TL, "BA", "Baucau";
+    TL_BO, TL, "BO", "Bobonaro";
+    TL_CO, TL, "CO", "Cova Lima";
+    TL_DI, TL, "DI", "Díli";
+    TL_ER, TL, "ER", "Ermera";
+    TL_LA, TL, "LA", "Lautém";
+    TL_LI, TL, "LI", "Likisá";
+    TL_MF, TL, "MF", "Manufahi";
+    TL_MT, TL, "MT", "Manatuto";
+    TL_OE, TL, "OE", "Oecussi";
+    TL_VI, TL, "VI", "Vikeke";
+    TM_A, TM, "A", "Ahal";
+    TM_B, TM, "B", "Balkan";
+    TM_D, TM, "D", "Daşoguz";
+    TM_L, TM, "L", "Lebap";
+    TM_M, TM, "M", "Mary";
+    TM_S, TM, "S", "Aşgabat";
+    TN_11, TN, "11", "Tunis";
+    TN_12, TN, "12", "Ariana";
+    TN_13, TN, "13", "Ben Arous";
+    TN_14, TN, "14", "La Manouba";
+    TN_21, TN, "21", "Nabeul";
+    TN_22, TN, "22", "Zaghouan";
+    TN_23, TN, "23", "Bizerte";
+    TN_31, TN, "31", "Béja";
+    TN_32, TN, "32", "Jendouba";
+    TN_33, TN, "33", "Le Kef";
+    TN_34, TN, "34", "Siliana";
+    TN_41, TN, "41", "Kairouan";
+    TN_42, TN, "42", "Kasserine";
+    TN_43, TN, "43", "Sidi Bouzid";
+    TN_51, TN, "51", "Sousse";
+    TN_52, TN, "52", "Monastir";
+    TN_53, TN, "53", "Mahdia";
+    TN_61, TN, "61", "Sfax";
+    TN_71, TN, "71", "Gafsa";
+    TN_72, TN, "72", "Tozeur";
+    TN_73, TN, "73", "Kebili";
+    TN_81, TN, "81", "Gabès";
+    TN_82, TN, "82", "Medenine";
+    TN_83, TN, "83", "Tataouine";
+    TO_01, TO, "01", "'Eua";
+    TO_02, TO, "02", "Ha'apai";
+    TO_03, TO, "03", "Niuas";
+    TO_04, TO, "04", "Tongatapu";
+    TO_05, TO, "05", "Vava'u";
+    TR_01, TR, "01", "Adana";
+    TR_02, TR, "02", "Adıyaman";
+    TR_03, TR, "03", "Afyonkarahisar";
+    TR_04, TR, "04", "Ağrı";
+    TR_05, TR, "05", "Amasya";
+    TR_06, TR, "06", "Ankara";
+    TR_07, TR, "07", "Antalya";
+    TR_08, TR, "08", "Artvin";
+    TR_09, TR, "09", "Aydın";
+    TR_10, TR, "10", "Balıkesir";
+    TR_11, TR, "11", "Bilecik";
+    TR_12, TR, "12", "Bingöl";
+    TR_13, TR, "13", "Bitlis";
+    TR_14, TR, "14", "Bolu";
+    TR_15, TR, "15", "Burdur";
+    TR_16, TR, "16", "Bursa";
+    TR_17, TR, "17", "Çanakkale";
+    TR_18, TR, "18", "Çankırı";
+    TR_19, TR, "19", "Çorum";
+    TR_20, TR, "20", "Denizli";
+    TR_21, TR, "21", "Diyarbakır";
+    TR_22, TR, "22", "Edirne";
+    TR_23, TR, "23", "Elazığ";
+    TR_24, TR, "24", "Erzincan";
+    TR_25, TR, "25", "Erzurum";
+    TR_26, TR, "26", "Eskişehir";
+    TR_27, TR, "27", "Gaziantep";
+    TR_28, TR, "28", "Giresun";
+    TR_29, TR, "29", "Gümüşhane";
+    TR_30, TR, "30", "Hakkâri";
+    TR_31, TR, "31", "Hatay";
+    TR_32, TR, "32", "Isparta";
+    TR_33, TR, "33", "Mersin";
+    TR_34, TR, "34", "İstanbul";
+    TR_35, TR, "35", "İzmir";
+    TR_36, TR, "36", "Kars";
+    TR_37, TR, "37", "Kastamonu";
+    TR_38, TR, "38", "Kayseri";
+    TR_39, TR, "39", "Kırklareli";
+    TR_40, TR, "40", "Kırşehir";
+    TR_41, TR, "41", "Kocaeli";
+    TR_42, TR, "42", "Konya";
+    TR_43, TR, "43", "Kütahya";
+    TR_44, TR, "44", "Malatya";
+    TR_45, TR, "45", "Manisa";
+    TR_46, TR, "46", "Kahramanmaraş";
+    TR_47, TR, "47", "Mardin";
+    TR_48, TR, "48", "Muğla";
+    TR_49, TR, "49", "Muş";
+    TR_50, TR, "50", "Nevşehir";
+    TR_51, TR, "51", "Niğde";
+    TR_52, TR, "52", "Ordu";
+    TR_53, TR, "53", "Rize";
+    TR_54, TR, "54", "Sakarya";
+    TR_55, TR, "55", "Samsun";
+    TR_56, TR, "56", "Siirt";
+    TR_57, TR, "57", "Sinop";
+    TR_58, TR, "58", "Sivas";
+    TR_59, TR, "59", "Tekirdağ";
+    TR_60, TR, "60", "Tokat";
+    TR_61, TR, "61", "Trabzon";
+    TR_62, TR, "62", "Tunceli";
+    TR_63, TR, "63", "Şanlıurfa";
+    TR_64, TR, "64", "Uşak";
+    TR_65, TR, "65", "Van";
+    TR_66, TR, "66", "Yozgat";
+    TR_67, TR, "67", "Zonguldak";
+    TR_68, TR, "68", "Aksaray";
+    TR_69, TR, "69", "Bayburt";
+    TR_70, TR, "70", "Karaman";
+    TR_71, TR, "71", "Kırıkkale";
+    TR_72, TR, "72", "Batman";
+    TR_73, TR, "73", "Şırnak";
+    TR_74, TR, "74", "Bartın";
+    TR_75, TR, "75", "Ardahan";
+    TR_76, TR, "76", "Iğdır";
+    TR_77, TR, "77", "Yalova";
+    TR_78, TR, "78", "Karabük";
+    TR_79, TR, "79", "Kilis";
+    TR_80, TR, "80", "Osmaniye";
+    TR_81, TR, "81", "Düzce";
+    TT_ARI, TT, "ARI", "Arima";
+    TT_CHA, TT, "CHA", "Chaguanas";
+    TT_CTT, TT, "CTT", "Couva-Tabaquite-Talparo";
+    TT_DMN, TT, "DMN", "Diego Martin";
+    TT_ETO, TT, "ETO", "Eastern Tobago";
+    TT_PED, TT, "PED", "Penal-Debe";
+    TT_POS, TT, "POS", "Port of Spain";
+    TT_PRT, TT, "PRT", "Princes Town";
+    TT_PTF, TT, "PTF", "Point Fortin";
+    TT_RCM, TT, "RCM", "Rio Claro-Mayaro";
+    TT_SFO, TT, "SFO", "San Fernando";
+    TT_SGE, TT, "SGE", "Sangre Grande";
+    TT_SIP, TT, "SIP", "Siparia";
+    TT_SJL, TT, "SJL", "San Juan-Laventille";
+    TT_TUP, TT, "TUP", "Tunapuna-Piarco";
+    TT_WTO, TT, "WTO", "Western Tobago";
+    TV_FUN, TV, "FUN", "Funafuti";
+    TV_NIT, TV, "NIT", "Niutao";
+    TV_NKF, TV, "NKF", "Nukufetau";
+    TV_NKL, TV, "NKL", "Nukulaelae";
+    TV_NMA, TV, "NMA", "Nanumea";
+    TV_NMG, TV, "NMG", "Nanumanga";
+    TV_NUI, TV, "NUI", "Nui";
+    TV_VAI, TV, "VAI", "Vaitupu";
+    TW_CHA, TW, "CHA", "Changhua";
+    TW_CYI, TW, "CYI", "Chiayi";
+    TW_CYQ, TW, "CYQ", "Chiayi";
+    TW_HSQ, TW, "HSQ", "Hsinchu";
+    TW_HSZ, TW, "HSZ", "Hsinchu";
+    TW_HUA, TW, "HUA", "Hualien";
+    TW_ILA, TW, "ILA", "Ilan";
+    TW_KEE, TW, "KEE", "Keelung";
+    TW_KHH, TW, "KHH", "Kaohsiung";
+    TW_KHQ, TW, "KHQ", "Kaohsiung";
+    TW_MIA, TW, "MIA", "Miaoli";
+    TW_NAN, TW, "NAN", "Nantou";
+    TW_PEN, TW, "PEN", "Penghu";
+    TW_PIF, TW, "PIF", "Pingtung";
+    TW_TAO, TW, "TAO", "Taoyuan";
+    TW_TNN, TW, "TNN", "Tainan";
+    TW_TNQ, TW, "TNQ", "Tainan";
+    TW_TPE, TW, "TPE", "Taipei";
+    TW_TPQ, TW, "TPQ", "Taipei";
+    TW_TTT, TW, "TTT", "Taitung";
+    TW_TXG, TW, "TXG", "Taichung";
+    TW_TXQ, TW, "TXQ", "Taichung";
+    TW_YUN, TW, "YUN", "Yunlin";
+    TZ_01, TZ, "01", "Arusha";
+    TZ_02, TZ, "02", "Dar es Salaam";
+    TZ_03, TZ, "03", "Dodoma";
+    TZ_04, TZ, "04", "Iringa";
+    TZ_05, TZ, "05", "Kagera";
+    TZ_06, TZ, "06", "Kaskazini Pemba";
+    TZ_07, TZ, "07", "Kaskazini Unguja";
+    TZ_08, TZ, "08", "Kigoma";
+    TZ_09, TZ, "09", "Kilimanjaro";
+    TZ_10, TZ, "10", "Kusini Pemba";
+    TZ_11, TZ, "11", "Kusini Unguja";
+    TZ_12, TZ, "12", "Lindi";
+    TZ_13, TZ, "13", "Mara";
+    TZ_14, TZ, "14", "Mbeya";
+    TZ_15, TZ, "15", "Mjini Magharibi";
+    TZ_16, TZ, "16", "Morogoro";
+    TZ_17, TZ, "17", "Mtwara";
+    TZ_18, TZ, "18", "Mwanza";
+    TZ_19, TZ, "19", "Coast";
+    TZ_20, TZ, "20", "Rukwa";
+    TZ_21, TZ, "21", "Ruvuma";
+    TZ_22, TZ, "22", "Shinyanga";
+    TZ_23, TZ, "23", "Singida";
+    TZ_24, TZ, "24", "Tabora";
+    TZ_25, TZ, "25", "Tanga";
+    TZ_26, TZ, "26", "Manyara";
+    TZ_27, TZ, "27", "Geita";
+    TZ_28, TZ, "28", "Katavi";
+    TZ_29, TZ, "29", "Njombe";
+    TZ_30, TZ, "30", "Simiyu";
+    UA_05, UA, "05", "Vinnytska oblast";
+    UA_07, UA, "07", "Volynska oblast";
+    UA_09, UA, "09", "Luhanska oblast";
+    UA_12, UA, "12", "Dnipropetrovska oblast";
+    UA_14, UA, "14", "Donetska oblast";
+    UA_18, UA, "18", "Zhytomyrska oblast";
+    UA_21, UA, "21", "Zakarpatska oblast";
+    UA_23, UA, "23", "Zaporizka oblast";
+    UA_26, UA, "26", "Ivano-Frankivska oblast";
+    UA_30, UA, "30", "Kyiv";
+    UA_32, UA, "32", "Kyivska oblast";
+    UA_35, UA, "35", "Kirovohradska oblast";
+    UA_40, UA, "40", "Sevastopol";
+    UA_43, UA, "43", "Avtonomna Respublika Krym";
+    UA_46, UA, "46", "Lvivska oblast";
+    UA_48, UA, "48", "Mykolaivska oblast";
+    UA_51, UA, "51", "Odeska oblast";
+    UA_53, UA, "53", "Poltavska oblast";
+    UA_56, UA, "56", "Rivnenska oblast";
+    UA_59, UA, "59", "Sumska oblast";
+    UA_61, UA, "61", "Ternopilska oblast";
+    UA_63, UA, "63", "Kharkivska oblast";
+    UA_65, UA, "65", "Khersonska oblast";
+    UA_68, UA, "68", "Khmelnytska oblast";
+    UA_71, UA, "71", "Cherkaska oblast";
+    UA_74, UA, "74", "Chernihivska oblast";
+    UA_77, UA, "77", "Chernivetska oblast";
+    UG_101, UG, "101", "Kalangala";
+    UG_102, UG, "102", "Kampala";
+    UG_103, UG, "103", "Kiboga";
+    UG_104, UG, "104", "Luwero";
+    UG_105, UG, "105", "Masaka";
+    UG_106, UG, "106", "Mpigi";
+    UG_107, UG, "107", "Mubende";
+    UG_108, UG, "108", "Mukono";
+    UG_109, UG, "109", "Nakasongola";
+    UG_110, UG, "110", "Rakai";
+    UG_111, UG, "111", "Sembabule";
+    UG_112, UG, "112", "Kayunga";
+    UG_113, UG, "113", "Wakiso";
+    UG_114, UG, "114", "Mityana";
+    UG_115, UG, "115", "Nakaseke";
+    UG_116, UG, "116", "Lyantonde";
+    UG_117, UG, "117", "Buikwe";
+    UG_118, UG, "118", "Bukomansibi";
+    UG_119, UG, "119", "Butambala";
+    UG_120, UG, "120", "Buvuma";
+    UG_121, UG, "121", "Gomba";
+    UG_122, UG, "122", "Kalungu";
+    UG_123, UG, "123", "Kyankwanzi";
+    UG_124, UG, "124", "Lwengo";
+    UG_201, UG, "201", "Bugiri";
+    UG_202, UG, "202", "Busia";
+    UG_203, UG, "203", "Iganga";
+    UG_204, UG, "204", "Jinja";
+    UG_205, UG, "205", "Kamuli";
+    UG_206, UG, "206", "Kapchorwa";
+    UG_207, UG, "207", "Katakwi";
+    UG_208, UG, "208", "Kumi";
+    UG_209, UG, "209", "Mbale";
+    UG_210, UG, "210", "Pallisa";
+    UG_211, UG, "211", "Soroti";
+    UG_212, UG, "212", "Tororo";
+    UG_213, UG, "213", "Kaberamaido";
+    UG_214, UG, "214", "Mayuge";
+    UG_215, UG, "215", "Sironko";
+    UG_216, UG, "216", "Amuria";
+    UG_217, UG, "217", "Budaka";
+    UG_218, UG, "218", "Bukwa";
+    UG_219, UG, "219", "Butaleja";
+    UG_220, UG, "220", "Kaliro";
+    UG_221, UG, "221", "Manafwa";
+    UG_222, UG, "222", "Namutumba";
+    UG_223, UG, "223", "Bududa";
+    UG_224, UG, "224", "Bukedea";
+    UG_225, UG, "225", "Bulambuli";
+    UG_226, UG, "226", "Buyende";
+    UG_227, UG, "227", "Kibuku";
+    UG_228, UG, "228", "Kween";
+    UG_229, UG, "229", "Luuka";
+    UG_230, UG, "230", "Namayingo";
+    UG_231, UG, "231", "Ngora";
+    UG_232, UG, "232", "Serere";
+    UG_301, UG, "301", "Adjumani";
+    UG_302, UG, "302", "Apac";
+    UG_303, UG, "303", "Arua";
+    UG_304, UG, "304", "Gulu";
+    UG_305, UG, "305", "Kitgum";
+    UG_306, UG, "306", "Kotido";
+    UG_307, UG, "307", "Lira";
+    UG_308, UG, "308", "Moroto";
+    UG_309, UG, "309", "Moyo";
+    UG_310, UG, "310", "Nebbi";
+    UG_311, UG, "311", "Nakapiripirit";
+    UG_312, UG, "312", "Pader";
+    UG_313, UG, "313", "Yumbe";
+    UG_314, UG, "314", "Amolatar";
+    UG_315, UG, "315", "Kaabong";
+    UG_316, UG, "316", "Koboko";
+    UG_317, UG, "317", "Abim";
+    UG_318, UG, "318", "Dokolo";
+    UG_319, UG, "319", "Amuru";
+    UG_320, UG, "320", "Maracha";
+    UG_321, UG, "321", "Oyam";
+    UG_322, UG, "322", "Agago";
+    UG_323, UG, "323", "Alebtong";
+    UG_324, UG, "324", "Amudat";
+    UG_325, UG, "325", "Buhweju";
+    UG_326, UG, "326", "Kole";
+    UG_327, UG, "327", "Lamwo";
+    UG_328, UG, "328", "Napak";
+    UG_329, UG, "329", "Nwoya";
+    UG_330, UG, "330", "Otuke";
+    UG_331, UG, "331", "Zombo";
+    UG_401, UG, "401", "Bundibugyo";
+    UG_402, UG, "402", "Bushenyi";
+    UG_403, UG, "403", "Hoima";
+    UG_404, UG, "404", "Kabale";
+    UG_405, UG, "405", "Kabarole";
+    UG_406, UG, "406", "Kasese";
+    UG_407, UG, "407", "Kibaale";
+    UG_408, UG, "408", "Kisoro";
+    UG_409, UG, "409", "Masindi";
+    UG_410, UG, "410", "Mbarara";
+    UG_411, UG, "411", "Ntungamo";
+    UG_412, UG, "412", "Rukungiri";
+    UG_413, UG, "413", "Kamwenge";
+    UG_414, UG, "414", "Kanungu";
+    UG_415, UG, "415", "Kyenjojo";
+    UG_416, UG, "416", "Ibanda";
+    UG_417, UG, "417", "Isingiro";
+    UG_418, UG, "418", "Kiruhura";
+    UG_419, UG, "419", "Buliisa";
+    UG_420, UG, "420", "Kiryandongo";
+    UG_421, UG, "421", "Kyegegwa";
+    UG_422, UG, "422", "Mitooma";
+    UG_423, UG, "423", "Ntoroko";
+    UG_424, UG, "424", "Rubirizi";
+    UG_425, UG, "425", "Sheema";
+    UG_C, UG, "C", "Central";
+    UG_E, UG, "E", "Eastern";
+    UG_N, UG, "N", "Northern";
+    UG_W, UG, "W", "Western";
+    UM_67, UM, "67", "Johnston Atoll";
+    UM_71, UM, "71", "Midway Islands";
+    UM_76, UM, "76", "Navassa Island";
+    UM_79, UM, "79", "Wake Island";
+    UM_81, UM, "81", "Baker Island";
+    UM_84, UM, "84", "Howland Island";
+    UM_86, UM, "86", "Jarvis Island";
+    UM_89, UM, "89", "Kingman Reef";
+    UM_95, UM, "95", "Palmyra Atoll";
+    US_AK, US, "AK", "Alaska";
+    US_AL, US, "AL", "Alabama";
+    US_AR, US, "AR", "Arkansas";
+    US_AS, US, "AS", "American Samoa";
+    US_AZ, US, "AZ", "Arizona";
+    US_CA, US, "CA", "California";
+    US_CO, US, "CO", "Colorado";
+    US_CT, US, "CT", "Connecticut";
+    US_DC, US, "DC", "District of Columbia";
+    US_DE, US, "DE", "Delaware";
+    US_FL, US, "FL", "Florida";
+    US_GA, US, "GA", "Georgia";
+    US_GU, US, "GU", "Guam";
+    US_HI, US, "HI", "Hawaii";
+    US_IA, US, "IA", "Iowa";
+    US_ID, US, "ID", "Idaho";
+    US_IL, US, "IL", "Illinois";
+    US_IN, US, "IN", "Indiana";
+    US_KS, US, "KS", "Kansas";
+    US_KY, US, "KY", "Kentucky";
+    US_LA, US, "LA", "Louisiana";
+    US_MA, US, "MA", "Massachusetts";
+    US_MD, US, "MD", "Maryland";
+    US_ME, US, "ME", "Maine";
+    US_MI, US, "MI", "Michigan";
+    US_MN, US, "MN", "Minnesota";
+    US_MO, US, "MO", "Missouri";
+    US_MP, US, "MP", "Northern Mariana Islands";
+    US_MS, US, "MS", "Mississippi";
+    US_MT, US, "MT", "Montana";
+    US_NC, US, "NC", "North Carolina";
+    US_ND, US, "ND", "North Dakota";
+    US_NE, US, "NE", "Nebraska";
+    US_NH, US, "NH", "New Hampshire";
+    US_NJ, US, "NJ", "New Jersey";
+    US_NM, US, "NM", "New Mexico";
+    US_NV, US, "NV", "Nevada";
+    US_NY, US, "NY", "New York";
+    US_OH, US, "OH", "Ohio";
+    US_OK, US, "OK", "Oklahoma";
+    US_OR, US, "OR", "Oregon";
+    US_PA, US, "PA", "Pennsylvania";
+    US_PR, US, "PR", "Puerto Rico";
+    US_RI, US, "RI", "Rhode Island";
+    US_SC, US, "SC", "South Carolina";
+    US_SD, US, "SD", "South Dakota";
+    US_TN, US, "TN", "Tennessee";
+    US_TX, US, "TX", "Texas";
+    US_UM, US, "UM", "United States Minor Outlying Islands";
+    US_UT, US, "UT", "Utah";
+    US_VA, US, "VA", "Virginia";
+    US_VI, US, "VI", "Virgin Islands, U.S.";
+    US_VT, US, "VT", "Vermont";
+    US_WA, US, "WA", "Washington";
+    US_WI, US, "WI", "Wisconsin";
+    US_WV, US, "WV", "West Virginia";
+    US_WY, US, "WY", "Wyoming";
+    UY_AR, UY, "AR", "Artigas";
+    UY_CA, UY, "CA", "Canelones";
+    UY_CL, UY, "CL", "Cerro Largo";
+    UY_CO, UY, "CO", "Colonia";
+    UY_DU, UY, "DU", "Durazno";
+    UY_FD, UY, "FD", "Florida";
+    UY_FS, UY, "FS", "Flores";
+    UY_LA, UY, "LA", "Lavalleja";
+    UY_MA, UY, "MA", "Maldonado";
+    UY_MO, UY, "MO", "Montevideo";
+    UY_PA, UY, "PA", "Paysandú";
+    UY_RN, UY, "RN", "Río Negro";
+    UY_RO, UY, "RO", "Rocha";
+    UY_RV, UY, "RV", "Rivera";
+    UY_SA, UY, "SA", "Salto";
+    UY_SJ, UY, "SJ", "San José";
+    UY_SO, UY, "SO", "Soriano";
+    UY_TA, UY, "TA", "Tacuarembó";
+    UY_TT, UY, "TT", "Treinta y Tres";
+    UZ_AN, UZ, "AN", "Andijon";
+    UZ_BU, UZ, "BU", "Buxoro";
+    UZ_FA, UZ, "FA", "Farg‘ona";
+    UZ_JI, UZ, "JI", "Jizzax";
+    UZ_NG, UZ, "NG", "Namangan";
+    UZ_NW, UZ, "NW", "Navoiy";
+    UZ_QA, UZ, "QA", "Qashqadaryo";
+    UZ_QR, UZ, "QR", "Qoraqalpog‘iston Respublikasi";
+    UZ_SA, UZ, "SA", "Samarqand";
+    UZ_SI, UZ, "SI", "Sirdaryo";
+    UZ_SU, UZ, "SU", "Surxondaryo";
+    UZ_TK, UZ, "TK", "Toshkent";
+    UZ_TO, UZ, "TO", "Toshkent";
+    UZ_XO, UZ, "XO", "Xorazm";
+    VC_01, VC, "01", "Charlotte";
+    VC_02, VC, "02", "Saint Andrew";
+    VC_03, VC, "03", "Saint David";
+    VC_04, VC, "04", "Saint George";
+    VC_05, VC, "05", "Saint Patrick";
+    VC_06, VC, "06", "Grenadines";
+    VE_A, VE, "A", "Distrito Capital";
+    VE_B, VE, "B", "Anzoátegui";
+    VE_C, VE, "C", "Apure";
+    VE_D, VE, "D", "Aragua";
+    VE_E, VE, "E", "Barinas";
+    VE_F, VE, "F", "Bolívar";
+    VE_G, VE, "G", "Carabobo";
+    VE_H, VE, "H", "Cojedes";
+    VE_I, VE, "I", "Falcón";
+    VE_J, VE, "J", "Guárico";
+    VE_K, VE, "K", "Lara";
+    VE_L, VE, "L", "Mérida";
+    VE_M, VE, "M", "Miranda";
+    VE_N, VE, "N", "Monagas";
+    VE_O, VE, "O", "Nueva Esparta";
+    VE_P, VE, "P", "Portuguesa";
+    VE_R, VE, "R", "Sucre";
+    VE_S, VE, "S", "Táchira";
+    VE_T, VE, "T", "Trujillo";
+    VE_U, VE, "U", "Yaracuy";
+    VE_V, VE, "V", "Zulia";
+    VE_W, VE, "W", "Dependencias Federales";
+    VE_X, VE, "X", "Vargas";
+    VE_Y, VE, "Y", "Delta Amacuro";
+    VE_Z, VE, "Z", "Amazonas";
+    VN_01, VN, "01", "Lai Châu";
+    VN_02, VN, "02", "Lào Cai";
+    VN_03, VN, "03", "Hà Giang";
+    VN_04, VN, "04", "Cao Bằng";
+    VN_05, VN, "05", "Sơn La";
+    VN_06, VN, "06", "Yên Bái";
+    VN_07, VN, "07", "Tuyên Quang";
+    VN_09, VN, "09", "Lạng Sơn";
+    VN_13, VN, "13", "Quảng Ninh";
+    VN_14, VN, "14", "Hòa Bình";
+    VN_18, VN, "18", "Ninh Bình";
+    VN_20, VN, "20", "Thái Bình";
+    VN_21, VN, "21", "Thanh Hóa";
+    VN_22, VN, "22", "Nghệ An";
+    VN_23, VN, "23", "Hà Tĩnh";
+    VN_24, VN, "24", "Quảng Bình";
+    VN_25, VN, "25", "Quảng Trị";
+    VN_26, VN, "26", "Thừa Thiên-Huế";
+    VN_27, VN, "27", "Quảng Nam";
+    VN_28, VN, "28", "Kon Tum";
+    VN_29, VN, "29", "Quảng Ngãi";
+    VN_30, VN, "30", "Gia Lai";
+    VN_31, VN, "31", "Bình Định";
+    VN_32, VN, "32", "Phú Yên";
+    VN_33, VN, "33", "Đắk Lắk";
+    VN_34, VN, "34", "Khánh Hòa";
+    VN_35, VN, "35", "Lâm Ðồng";
+    VN_36, VN, "36", "Ninh Thuận";
+    VN_37, VN, "37", "Tây Ninh";
+    VN_39, VN, "39", "Ðồng Nai";
+    VN_40, VN, "40", "Bình Thuận";
+    VN_41, VN, "41", "Long An";
+    VN_43, VN, "43", "Bà Rịa - Vũng Tàu";
+    VN_44, VN, "44", "An Giang";
+    VN_45, VN, "45", "Ðồng Tháp";
+    VN_46, VN, "46", "Tiền Giang";
+    VN_47, VN, "47", "Kiến Giang";
+    VN_49, VN, "49", "Vĩnh Long";
+    VN_50, VN, "50", "Bến Tre";
+    VN_51, VN, "51", "Trà Vinh";
+    VN_52, VN, "52", "Sóc Trăng";
+    VN_53, VN, "53", "Bắc Kạn";
+    VN_54, VN, "54", "Bắc Giang";
+    VN_55, VN, "55", "Bạc Liêu";
+    VN_56, VN, "56", "Bắc Ninh";
+    VN_57, VN, "57", "Bình Dương";
+    VN_58, VN, "58", "Bình Phước";
+    VN_59, VN, "59", "Cà Mau";
+    VN_61, VN, "61", "Hải Dương";
+    VN_63, VN, "63", "Hà Nam";
+    VN_66, VN, "66", "Hưng Yên";
+    VN_67, VN, "67", "Nam Ðịnh";
+    VN_68, VN, "68", "Phú Thọ";
+    VN_69, VN, "69", "Thái Nguyên";
+    VN_70, VN, "70", "Vĩnh Phúc";
+    VN_71, VN, "71", "Điện Biên";
+    VN_72, VN, "72", "Đắk Nông";
+    VN_73, VN, "73", "Hậu Giang";
+    VN_CT, VN, "CT", "Can Tho";
+    VN_DN, VN, "DN", "Da Nang";
+    VN_HN, VN, "HN", "Ha Noi";
+    VN_HP, VN, "HP", "Hai Phong";
+    VN_SG, VN, "SG", "Ho Chi Minh";
+    VU_MAP, VU, "MAP", "Malampa";
+    VU_PAM, VU, "PAM", "Pénama";
+    VU_SAM, VU, "SAM", "Sanma";
+    VU_SEE, VU, "SEE", "Shéfa";
+    VU_TAE, VU, "TAE", "Taféa";
+    VU_TOB, VU, "TOB", "Torba";
+    WS_AA, WS, "AA", "A'ana";
+    WS_AL, WS, "AL", "Aiga-i-le-Tai";
+    WS_AT, WS, "AT", "Atua";
+    WS_FA, WS, "FA", "Fa'asaleleaga";
+    WS_GE, WS, "GE", "Gaga'emauga";
+    WS_GI, WS, "GI", "Gagaifomauga";
+    WS_PA, WS, "PA", "Palauli";
+    WS_SA, WS, "SA", "Satupa'itea";
+    WS_TU, WS, "TU", "Tuamasaga";
+    WS_VF, WS, "VF", "Va'a-o-Fonoti";
+    WS_VS, WS, "VS", "Vaisigano";
+    YE_AB, YE, "AB", "Abyān";
+    YE_AD, YE, "AD", "'Adan";
+    YE_AM, YE, "AM", "'Amrān";
+    YE_BA, YE, "BA", "Al Bayḑā'";
+    YE_DA, YE, "DA", "Aḑ Ḑāli'";
+    YE_DH, YE, "DH", "Dhamār";
+    YE_HD, YE, "HD", "Ḩaḑramawt";
+    YE_HJ, YE, "HJ", "Ḩajjah";
+    YE_HU, YE, "HU", "Al Ḩudaydah";
+    YE_IB, YE, "IB", "Ibb";
+    YE_JA, YE, "JA", "Al Jawf";
+    YE_LA, YE, "LA", "Laḩij";
+    YE_MA, YE, "MA", "Ma'rib";
+    YE_MR, YE, "MR", "Al Mahrah";
+    YE_MW, YE, "MW", "Al Maḩwīt";
+    YE_RA, YE, "RA", "Raymah";
+    YE_SA, YE, "SA", "Amānat al ‘Āşimah";
+    YE_SD, YE, "SD", "Şāʻdah";
+    YE_SH, YE, "SH", "Shabwah";
+    YE_SN, YE, "SN", "Şanʻā'";
+    YE_TA, YE, "TA", "Tāʻizz";
+    ZA_EC, ZA, "EC", "Eastern Cape";
+    ZA_FS, ZA, "FS", "Foreisetata";
+    ZA_GT, ZA, "GT", "Gauteng";
+    ZA_LP, ZA, "LP", "Limpopo";
+    ZA_MP, ZA, "MP", "iMpumalanga";
+    ZA_NC, ZA, "NC", "iTlhagwini-Kapa";
+    ZA_NL, ZA, "NL", "GaZulu-Natala";
+    ZA_NW, ZA, "NW", "Bokone Bophirima";
+    ZA_WC, ZA, "WC", "iTjhingalanga-Kapa";
+    ZM_01, ZM, "01", "Western";
+    ZM_02, ZM, "02", "Central";
+    ZM_03, ZM, "03", "Eastern";
+    ZM_04, ZM, "04", "Luapula";
+    ZM_05, ZM, "05", "Northern";
+    ZM_06, ZM, "06", "North-Western";
+    ZM_07, ZM, "07", "Southern";
+    ZM_08, ZM, "08", "Copperbelt";
+    ZM_09, ZM, "09", "Lusaka";
+    ZM_10, ZM, "10", "Muchinga";
+    ZW_BU, ZW, "BU", "Bulawayo";
+    ZW_HA, ZW, "HA", "Harare";
+    ZW_MA, ZW, "MA", "Manicaland";
+    ZW_MC, ZW, "MC", "Mashonaland Central";
+    ZW_ME, ZW, "ME", "Mashonaland East";
+    ZW_MI, ZW, "MI", "Midlands";
+    ZW_MN, ZW, "MN", "Matabeleland North";
+    ZW_MS, ZW, "MS", "Matabeleland South";
+    ZW_MV, ZW, "MV", "Masvingo";
+    ZW_MW, ZW, "MW", "Mashonaland West";
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn parses_codes() {
+        assert_eq!("BR-SP".parse::<Subdivision>(), Ok(Subdivision::BR_SP));
+        assert_eq!("us-ca".parse::<Subdivision>(), Ok(Subdivision::US_CA));
+        assert_eq!(Subdivision::JP_13.name(), "Tokyo");
+        assert_eq!(Subdivision::MX_CMX.local_code(), "CMX");
+        assert_eq!(
+            "BR-XX".parse::<Subdivision>(),
+            Err(Error::FailedParsing(Kind::Subdivision, "BR-XX".to_string()))
+        );
+    }
+
+    #[test]
+    fn lists_subdivisions_by_country() {
+        assert_eq!(Country::BR.subdivisions().count(), 27);
+        assert_eq!(Country::US.subdivisions().count(), 57);
+        assert_eq!(Country::FR.subdivisions().count(), 144);
+        assert_eq!(Country::AQ.subdivisions().count(), 0);
+        assert_eq!("GB-ENG".parse::<Subdivision>(), Ok(Subdivision::GB_ENG));
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_subdivisions_are_always_valid(s in any::<Subdivision>()) {
+            prop_assert_eq!(s.to_string().parse::<Subdivision>(), Ok(s));
+            prop_assert!(s.code().starts_with(s.country().alpha2()));
+        }
+    }
+}
@@ -13,7 +13,7 @@ use proptest::{
 #[cfg(any(test, feature = "testing"))]
 use crate::testing::Rng;
 
-use crate::Error;
+use crate::{lang::Sanitizer, Error};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -22,6 +22,13 @@ use crate::Error;
 /// A piece of UTF-8 valid text
 pub struct Text(String);
 
+impl Text {
+    /// Returns the canonical form of this text, as defined by `sanitizer`
+    pub fn sanitized(&self, sanitizer: &Sanitizer) -> Self {
+        Self(sanitizer.sanitize(&self.0))
+    }
+}
+
 impl FromStr for Text {
     type Err = Error;
 
@@ -30,6 +37,12 @@ impl FromStr for Text {
     }
 }
 
+impl From<String> for Text {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
 impl Display for Text {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", &self.0)
@@ -74,5 +87,10 @@ mod tests {
         fn arbitrary_text_is_always_valid(a in any::<Text>()) {
             a.to_string().parse::<Text>().expect("Failed parsing");
         }
+
+        #[test]
+        fn sanitizing_arbitrary_text_is_lossless(a in any::<Text>()) {
+            prop_assert_eq!(a.sanitized(&Sanitizer::default()), a);
+        }
     }
 }
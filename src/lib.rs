@@ -21,7 +21,18 @@ pub enum Kind {
     Email,
     Username,
     Id,
+    CompositeId,
     Text,
+    Markdown,
+    LanguageTag,
+    Country,
+    Subdivision,
+    PostalAddress,
+    PostalCode,
+    PersonName,
+    Encrypted,
+    Currency,
+    Money,
 }
 
 impl std::fmt::Display for Kind {
@@ -30,7 +41,18 @@ impl std::fmt::Display for Kind {
             Kind::Email => write!(f, "email"),
             Kind::Username => write!(f, "username"),
             Kind::Id => write!(f, "id"),
+            Kind::CompositeId => write!(f, "composite id"),
             Kind::Text => write!(f, "text"),
+            Kind::Markdown => write!(f, "markdown"),
+            Kind::LanguageTag => write!(f, "language tag"),
+            Kind::Country => write!(f, "country"),
+            Kind::Subdivision => write!(f, "subdivision"),
+            Kind::PostalAddress => write!(f, "postal address"),
+            Kind::PostalCode => write!(f, "postal code"),
+            Kind::PersonName => write!(f, "person name"),
+            Kind::Encrypted => write!(f, "encrypted"),
+            Kind::Currency => write!(f, "currency"),
+            Kind::Money => write!(f, "money"),
         }
     }
 }
@@ -41,7 +63,53 @@ pub enum Error {
     #[error("Failed to parse `{0}` resource: {1}")]
     FailedParsing(Kind, String),
 
+    #[cfg(feature = "lang")]
+    #[error("Invalid `{kind}` prefix: expected `{expected}`, found `{found}`")]
+    InvalidPrefix {
+        kind: Kind,
+        expected: &'static str,
+        found: String,
+    },
+
+    #[cfg(feature = "lang")]
+    #[error("Invalid `{kind}` length: expected {}, found {actual}", length_bounds(.min, .max))]
+    InvalidLength {
+        kind: Kind,
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+
+    #[cfg(feature = "lang")]
+    #[error("Missing `{1}` on `{0}`")]
+    MissingField(Kind, &'static str),
+
     #[cfg(feature = "internet")]
     #[error("Password hashing error: {0}")]
     PasswordHashing(String),
+
+    #[cfg(feature = "mlock")]
+    #[error("Failed to lock memory: {0}")]
+    MemoryLock(String),
+
+    #[cfg(feature = "encryption")]
+    #[error("Unknown encryption key `{0}`")]
+    UnknownKey(String),
+
+    #[cfg(feature = "encryption")]
+    #[error("Invalid encryption key id `{0}`: must be non-empty and can't contain `:`")]
+    InvalidKeyId(String),
+
+    #[cfg(feature = "encryption")]
+    #[error("Encryption error: {0}")]
+    Encryption(String),
+}
+
+/// Describes the lengths accepted by `Error::InvalidLength`, which may have no maximum
+#[cfg(feature = "lang")]
+fn length_bounds(min: &usize, max: &usize) -> String {
+    match max {
+        &usize::MAX => format!("at least {min}"),
+        max => format!("between {min} and {max}"),
+    }
 }